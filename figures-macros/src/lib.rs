@@ -0,0 +1,305 @@
+//! Derive macros for the `figures` crate.
+//!
+//! This crate should not be used directly. Enable the `derive` feature of
+//! `figures` instead, which re-exports these macros alongside the traits they
+//! implement.
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::fold::Fold;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Type};
+
+/// Derives `figures::ScreenScale` for a struct generic over its unit type.
+///
+/// The struct must have exactly one type parameter, which is treated as the
+/// measurement unit. Each field is converted with its own [`ScreenScale`]
+/// implementation, and the associated `Px`/`UPx`/`Lp` types are the same
+/// struct instantiated with the corresponding unit.
+#[proc_macro_derive(ScreenScale)]
+pub fn derive_screen_scale(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    screen_scale(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn screen_scale(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let unit = unit_parameter(input)?;
+    let fields = struct_fields(input)?;
+
+    let field_names = member_names(&fields);
+    let constraints = fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            let px = substitute_unit(ty, &unit, &parse_quote!(figures::units::Px));
+            let upx = substitute_unit(ty, &unit, &parse_quote!(figures::units::UPx));
+            let lp = substitute_unit(ty, &unit, &parse_quote!(figures::units::Lp));
+            quote!(#ty: figures::ScreenScale<Px = #px, UPx = #upx, Lp = #lp>)
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl<#unit> figures::ScreenScale for #name<#unit>
+        where
+            #(#constraints,)*
+        {
+            type Px = #name<figures::units::Px>;
+            type UPx = #name<figures::units::UPx>;
+            type Lp = #name<figures::units::Lp>;
+
+            fn into_px(self, scale: figures::Fraction) -> Self::Px {
+                #name {
+                    #(#field_names: figures::ScreenScale::into_px(self.#field_names, scale),)*
+                }
+            }
+
+            fn from_px(px: Self::Px, scale: figures::Fraction) -> Self {
+                Self {
+                    #(#field_names: figures::ScreenScale::from_px(px.#field_names, scale),)*
+                }
+            }
+
+            fn into_upx(self, scale: figures::Fraction) -> Self::UPx {
+                #name {
+                    #(#field_names: figures::ScreenScale::into_upx(self.#field_names, scale),)*
+                }
+            }
+
+            fn from_upx(px: Self::UPx, scale: figures::Fraction) -> Self {
+                Self {
+                    #(#field_names: figures::ScreenScale::from_upx(px.#field_names, scale),)*
+                }
+            }
+
+            fn into_lp(self, scale: figures::Fraction) -> Self::Lp {
+                #name {
+                    #(#field_names: figures::ScreenScale::into_lp(self.#field_names, scale),)*
+                }
+            }
+
+            fn from_lp(lp: Self::Lp, scale: figures::Fraction) -> Self {
+                Self {
+                    #(#field_names: figures::ScreenScale::from_lp(lp.#field_names, scale),)*
+                }
+            }
+        }
+    })
+}
+
+/// Derives `figures::Zero` by delegating to each field's `Zero`
+/// implementation.
+#[proc_macro_derive(Zero)]
+pub fn derive_zero(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    zero(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn zero(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let fields = struct_fields(input)?;
+    let field_names = member_names(&fields);
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|clause| &clause.predicates);
+
+    Ok(quote! {
+        impl #impl_generics figures::Zero for #name #ty_generics
+        where
+            #(#field_types: figures::Zero,)*
+            #predicates
+        {
+            const ZERO: Self = Self {
+                #(#field_names: <#field_types as figures::Zero>::ZERO,)*
+            };
+
+            fn is_zero(&self) -> bool {
+                true #(&& self.#field_names.is_zero())*
+            }
+        }
+    })
+}
+
+/// Derives `figures::Ranged` by delegating to each field's `Ranged`
+/// implementation.
+#[proc_macro_derive(Ranged)]
+pub fn derive_ranged(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    ranged(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn ranged(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let fields = struct_fields(input)?;
+    let field_names = member_names(&fields);
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|clause| &clause.predicates);
+
+    Ok(quote! {
+        impl #impl_generics figures::Ranged for #name #ty_generics
+        where
+            #(#field_types: figures::Ranged,)*
+            #predicates
+        {
+            const MIN: Self = Self {
+                #(#field_names: <#field_types as figures::Ranged>::MIN,)*
+            };
+            const MAX: Self = Self {
+                #(#field_names: <#field_types as figures::Ranged>::MAX,)*
+            };
+        }
+    })
+}
+
+/// Derives `figures::IntoComponents` for a struct with exactly two fields of
+/// the same type.
+#[proc_macro_derive(IntoComponents)]
+pub fn derive_into_components(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_components(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn into_components(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (unit, fields) = two_components(input)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (first, second) = (&fields[0], &fields[1]);
+
+    Ok(quote! {
+        impl #impl_generics figures::IntoComponents<#unit> for #name #ty_generics #where_clause {
+            fn into_components(self) -> (#unit, #unit) {
+                (self.#first, self.#second)
+            }
+        }
+    })
+}
+
+/// Derives `figures::FromComponents` for a struct with exactly two fields of
+/// the same type.
+#[proc_macro_derive(FromComponents)]
+pub fn derive_from_components(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_components(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn from_components(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (unit, fields) = two_components(input)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (first, second) = (&fields[0], &fields[1]);
+
+    Ok(quote! {
+        impl #impl_generics figures::FromComponents<#unit> for #name #ty_generics #where_clause {
+            fn from_components(components: (#unit, #unit)) -> Self {
+                Self {
+                    #first: components.0,
+                    #second: components.1,
+                }
+            }
+        }
+    })
+}
+
+/// Returns the component type and field names for a struct with exactly two
+/// fields of the same type.
+fn two_components(input: &DeriveInput) -> syn::Result<(Type, Vec<syn::Member>)> {
+    let fields = struct_fields(input)?;
+    let [first, second] = fields.as_slice() else {
+        return Err(Error::new(
+            input.span(),
+            "this derive requires exactly two fields",
+        ));
+    };
+    // `Type` has no structural equality; compare the token streams.
+    let (first_ty, second_ty) = (&first.ty, &second.ty);
+    if quote!(#first_ty).to_string() != quote!(#second_ty).to_string() {
+        return Err(Error::new(
+            second.ty.span(),
+            "this derive requires both fields to have the same type",
+        ));
+    }
+    Ok((first.ty.clone(), member_names(&fields)))
+}
+
+/// Returns the member access names for `fields`.
+fn member_names(fields: &[&syn::Field]) -> Vec<syn::Member> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            field.ident.as_ref().map_or_else(
+                || syn::Member::from(index),
+                |ident| syn::Member::from(ident.clone()),
+            )
+        })
+        .collect()
+}
+
+/// Returns the single type parameter of `input`, or an error if the type has
+/// any other shape of generics.
+fn unit_parameter(input: &DeriveInput) -> syn::Result<Ident> {
+    let mut type_params = input.generics.type_params();
+    let (Some(unit), None) = (type_params.next(), type_params.next()) else {
+        return Err(Error::new(
+            input.generics.span(),
+            "this derive requires exactly one type parameter, used as the unit",
+        ));
+    };
+    if input.generics.lifetimes().next().is_some() || input.generics.const_params().next().is_some()
+    {
+        return Err(Error::new(
+            input.generics.span(),
+            "this derive does not support lifetime or const parameters",
+        ));
+    }
+    Ok(unit.ident.clone())
+}
+
+/// Returns the fields of `input`, or an error if it is not a struct.
+fn struct_fields(input: &DeriveInput) -> syn::Result<Vec<&syn::Field>> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "this derive only supports structs",
+        ));
+    };
+    match &data.fields {
+        Fields::Named(fields) => Ok(fields.named.iter().collect()),
+        Fields::Unnamed(fields) => Ok(fields.unnamed.iter().collect()),
+        Fields::Unit => Ok(Vec::new()),
+    }
+}
+
+/// Returns `ty` with every path referencing `unit` replaced by
+/// `replacement`.
+fn substitute_unit(ty: &Type, unit: &Ident, replacement: &Type) -> Type {
+    struct Substitute<'a> {
+        unit: &'a Ident,
+        replacement: &'a Type,
+    }
+
+    impl Fold for Substitute<'_> {
+        fn fold_type(&mut self, ty: Type) -> Type {
+            if let Type::Path(path) = &ty {
+                if path.qself.is_none() && path.path.is_ident(self.unit) {
+                    return self.replacement.clone();
+                }
+            }
+            syn::fold::fold_type(self, ty)
+        }
+    }
+
+    Substitute { unit, replacement }.fold_type(ty.clone())
+}
@@ -0,0 +1,32 @@
+use crate::traits::FloatConversion;
+use crate::Zero;
+
+/// The alignment of content along a single axis.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Alignment {
+    /// Aligns to the minimum edge of the axis: left or top.
+    #[default]
+    Start,
+    /// Centers within the available space.
+    Center,
+    /// Aligns to the maximum edge of the axis: right or bottom.
+    End,
+}
+
+impl Alignment {
+    /// Returns the offset from the start of the axis for content that leaves
+    /// `remaining` unoccupied space.
+    #[must_use]
+    pub fn offset<Unit>(self, remaining: Unit) -> Unit
+    where
+        Unit: FloatConversion<Float = f32> + Zero,
+    {
+        match self {
+            Self::Start => Unit::ZERO,
+            Self::Center => Unit::from_float(remaining.into_float() / 2.),
+            Self::End => remaining,
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use std::cmp::Ordering;
-use std::fmt::{Debug, Display, Write};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-use std::time::Duration;
+use alloc::string::ToString;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Write};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::time::Duration;
 
 use crate::tables::{approximate_via_lookup_table, COSINE_TABLE, SINE_TABLE, TANGENT_TABLE};
 use crate::{Fraction, Ranged, Zero};
@@ -32,11 +33,55 @@ use crate::{Fraction, Ranged, Zero};
 /// Because π is an irrational number, this type internally uses degrees for
 /// representation. Angles represented in radians can be converted using
 /// [`Angle::radians`]/[`Angle::radians_f`].
-#[derive(Eq, PartialEq, PartialOrd, Ord, Copy, Clone)]
+///
+/// # Equality and Hashing
+///
+/// Different code paths can produce internal fractions that name the same
+/// angle, such as an unreduced `1/2` versus `2/4` or a full rotation stored
+/// as `360°` instead of `0°`. Comparisons and hashing canonicalize with
+/// [`Angle::normalized`] first, so such values compare equal, hash
+/// identically, and can be used as keys for caching rotated assets.
+#[derive(Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Angle(Fraction);
 
+impl PartialEq for Angle {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized().0 == other.normalized().0
+    }
+}
+
+impl Eq for Angle {}
+
+impl Ord for Angle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.normalized().0.cmp(&other.normalized().0)
+    }
+}
+
+impl PartialOrd for Angle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::hash::Hash for Angle {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.normalized().0.hash(state);
+    }
+}
+
 impl Angle {
+    /// The sine and cosine of 0°.
+    pub const SINCOS_0: (Fraction, Fraction) = Self::degrees(0).sin_cos();
+    /// The sine and cosine of 180°.
+    pub const SINCOS_180: (Fraction, Fraction) = Self::degrees(180).sin_cos();
+    /// The sine and cosine of 270°.
+    pub const SINCOS_270: (Fraction, Fraction) = Self::degrees(270).sin_cos();
+    /// The sine and cosine of 90°.
+    pub const SINCOS_90: (Fraction, Fraction) = Self::degrees(90).sin_cos();
+
     /// Returns an angle for `degrees`, where 360 degrees is equal to one full
     /// rotation.
     ///
@@ -90,7 +135,77 @@ impl Angle {
     /// The value will be normalized to the range of `0..2π`.
     #[must_use]
     pub fn radians_f(radians: f32) -> Self {
-        Self::degrees_f(radians * 180. / std::f32::consts::PI)
+        Self::degrees_f(radians * 180. / core::f32::consts::PI)
+    }
+
+    /// Returns an angle for `turns`, where one turn is equal to one full
+    /// rotation.
+    ///
+    /// The value will be normalized to the range of `0..1`.
+    #[must_use]
+    pub fn turns(turns: Fraction) -> Self {
+        Self(turns * 360).clamped_to_360()
+    }
+
+    /// Returns an angle for `turns`, where one turn is equal to one full
+    /// rotation.
+    ///
+    /// The value will be normalized to the range of `0..1`.
+    #[must_use]
+    pub fn turns_f(turns: f32) -> Self {
+        Self::degrees_f(turns * 360.)
+    }
+
+    /// Returns an angle for `gradians`, where 400 gradians is equal to one
+    /// full rotation.
+    ///
+    /// The value will be normalized to the range of `0..400`.
+    #[must_use]
+    pub fn gradians(gradians: Fraction) -> Self {
+        Self(gradians * 9 / 10).clamped_to_360()
+    }
+
+    /// Returns an angle for `gradians`, where 400 gradians is equal to one
+    /// full rotation.
+    ///
+    /// The value will be normalized to the range of `0..400`.
+    #[must_use]
+    pub fn gradians_f(gradians: f32) -> Self {
+        Self::degrees_f(gradians * 0.9)
+    }
+
+    /// Returns this angle as represented in turns, where one turn is equal to
+    /// one full rotation.
+    #[must_use]
+    pub fn into_turns<Representation>(self) -> Representation
+    where
+        Representation: From<Fraction>,
+    {
+        Representation::from(self.0 / 360)
+    }
+
+    /// Returns this angle as represented in turns, where one turn is equal to
+    /// one full rotation.
+    #[must_use]
+    pub fn into_turns_f(self) -> f32 {
+        f32::from(self.0) / 360.
+    }
+
+    /// Returns this angle as represented in gradians, where 400 gradians is
+    /// equal to one full rotation.
+    #[must_use]
+    pub fn into_gradians<Representation>(self) -> Representation
+    where
+        Representation: From<Fraction>,
+    {
+        Representation::from(self.0 * 10 / 9)
+    }
+
+    /// Returns this angle as represented in gradians, where 400 gradians is
+    /// equal to one full rotation.
+    #[must_use]
+    pub fn into_gradians_f(self) -> f32 {
+        f32::from(self.0) / 0.9
     }
 
     /// Returns this angle as represented in radians, where `2π` is equal to one
@@ -107,7 +222,7 @@ impl Angle {
     /// full rotation.
     #[must_use]
     pub fn into_raidans_f(self) -> f32 {
-        f32::from(self.0) / 180. * std::f32::consts::PI
+        f32::from(self.0) / 180. * core::f32::consts::PI
     }
 
     /// Returns this angle as represented in degrees, where 360 degrees is equal
@@ -120,6 +235,23 @@ impl Angle {
         Representation::from(self.0)
     }
 
+    /// Returns this angle in a canonical form, reducing the internal
+    /// fraction and mapping a full rotation of `360°` to `0°`.
+    ///
+    /// Comparisons and hashing already canonicalize, so this is only needed
+    /// when the internal representation itself must be canonical, such as
+    /// before serializing.
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        const THREESIXTY: Fraction = Fraction::new_whole(360);
+        let reduced = self.0.reduced();
+        Self(if reduced == THREESIXTY {
+            Fraction::ZERO
+        } else {
+            reduced
+        })
+    }
+
     fn clamped_to_360(mut self) -> Self {
         self.clamp_to_360();
         self
@@ -164,6 +296,94 @@ impl Angle {
     pub fn tan(&self) -> Fraction {
         approximate_via_lookup_table(self.0, &TANGENT_TABLE)
     }
+
+    /// Returns the sine and cosine of this angle.
+    ///
+    /// Unlike [`sin`](Self::sin) and [`cos`](Self::cos), this function is
+    /// usable in const contexts, allowing rotation tables to be computed at
+    /// compile time. [`Angle::degrees`] is also const, so a table entry can
+    /// be written as `Angle::degrees(45).sin_cos()`.
+    ///
+    /// # Panics
+    ///
+    /// Only whole-degree angles can be looked up without interpolation, which
+    /// requires non-const [`Fraction`] math. This function panics if the
+    /// angle has a fractional component; in a const context, the panic is
+    /// reported at compile time.
+    #[must_use]
+    pub const fn sin_cos(self) -> (Fraction, Fraction) {
+        assert!(
+            self.0.denominator() == 1,
+            "sin_cos requires a whole number of degrees"
+        );
+        #[allow(clippy::cast_sign_loss)] // The angle is normalized to 0..=360.
+        let degrees = self.0.numerator() as usize % 360;
+        (SINE_TABLE[degrees], COSINE_TABLE[degrees])
+    }
+
+    /// Returns the shortest signed rotation from `other` to `self`, in
+    /// degrees within `-180..=180`.
+    ///
+    /// A positive result rotates in the direction of increasing degrees,
+    /// which is clockwise in this crate's y-down coordinate space. Because
+    /// angles are normalized to `0..360`, the result is returned as a
+    /// [`Fraction`] rather than an `Angle`, which cannot represent negative
+    /// rotations.
+    ///
+    /// ```rust
+    /// use figures::{Angle, Fraction};
+    ///
+    /// assert_eq!(
+    ///     Angle::degrees(10).signed_difference(Angle::degrees(350)),
+    ///     Fraction::new_whole(20)
+    /// );
+    /// assert_eq!(
+    ///     Angle::degrees(350).signed_difference(Angle::degrees(10)),
+    ///     Fraction::new_whole(-20)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn signed_difference(self, other: Self) -> Fraction {
+        const HALF_TURN: Fraction = Fraction::new_whole(180);
+        const FULL_TURN: Fraction = Fraction::new_whole(360);
+        let mut difference = self.0 - other.0;
+        if difference > HALF_TURN {
+            difference -= FULL_TURN;
+        } else if difference < -HALF_TURN {
+            difference += FULL_TURN;
+        }
+        difference
+    }
+
+    /// Returns true if the shortest rotation from `other` to `self` is
+    /// clockwise, treating increasing degrees as clockwise to match this
+    /// crate's y-down coordinate space.
+    ///
+    /// Equal angles are not clockwise from each other, and an exact half
+    /// turn is reported as clockwise.
+    #[must_use]
+    pub fn clockwise_from(self, other: Self) -> bool {
+        self.signed_difference(other).is_positive()
+    }
+
+    /// Returns the angle whose sine is `sin` and whose cosine is `cos`.
+    ///
+    /// This function is implemented using a lookup table and is an
+    /// approximation. The components do not need to describe a unit vector:
+    /// only their ratio and signs affect the result. If both components are
+    /// zero, the angle is undefined and 90 degrees is returned.
+    #[must_use]
+    pub fn from_sin_cos(sin: Fraction, cos: Fraction) -> Self {
+        if cos.is_zero() {
+            if sin.is_negative() {
+                Self::degrees(270)
+            } else {
+                Self::degrees(90)
+            }
+        } else {
+            sin.atan2(cos)
+        }
+    }
 }
 
 impl Ranged for Angle {
@@ -179,6 +399,36 @@ impl Zero for Angle {
     }
 }
 
+impl crate::traits::ScreenScale for Angle {
+    type Lp = Self;
+    type Px = Self;
+    type UPx = Self;
+
+    fn into_px(self, _scale: Fraction) -> Self::Px {
+        self
+    }
+
+    fn from_px(px: Self::Px, _scale: Fraction) -> Self {
+        px
+    }
+
+    fn into_upx(self, _scale: Fraction) -> Self::UPx {
+        self
+    }
+
+    fn from_upx(px: Self::UPx, _scale: Fraction) -> Self {
+        px
+    }
+
+    fn into_lp(self, _scale: Fraction) -> Self::Lp {
+        self
+    }
+
+    fn from_lp(lp: Self::Lp, _scale: Fraction) -> Self {
+        lp
+    }
+}
+
 impl From<f32> for Angle {
     fn from(value: f32) -> Self {
         Self::radians_f(value)
@@ -198,13 +448,13 @@ impl From<i16> for Angle {
 }
 
 impl Debug for Angle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:0.3}\u{B0}", self.0.into_f32())
     }
 }
 
 impl Display for Angle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let (whole, mut fraction) = self.0.into_compound();
         let is_non_negative = !whole.is_negative();
         let whole = whole.to_string();
@@ -373,6 +623,19 @@ macro_rules! impl_math_ops_for_std_type {
 impl_math_ops_for_std_type!(f32);
 impl_math_ops_for_std_type!(i16);
 
+#[cfg(feature = "euclid")]
+impl From<euclid::Angle<f32>> for Angle {
+    fn from(angle: euclid::Angle<f32>) -> Self {
+        Self::radians_f(angle.radians)
+    }
+}
+#[cfg(feature = "euclid")]
+impl From<Angle> for euclid::Angle<f32> {
+    fn from(angle: Angle) -> Self {
+        Self::radians(angle.into_raidans_f())
+    }
+}
+
 impl Neg for Angle {
     type Output = Self;
 
@@ -391,6 +654,8 @@ fn angle_clamp() {
 
 #[test]
 fn angle_display() {
+    use alloc::format;
+
     assert_eq!(format!("{}", Angle::degrees(10)), "10°");
     assert_eq!(format!("{}", Angle::degrees_f(10.1001)), "10.1°");
     assert_eq!(format!("{}", Angle::degrees_f(10.101)), "10.101°");
@@ -404,15 +669,39 @@ fn angle_display() {
 fn radians_to_deg() {
     assert_eq!(Angle::radians(Fraction::PI), Angle::degrees(180));
     assert_eq!(Angle::degrees(180).into_raidans::<Fraction>(), Fraction::PI);
-    assert_eq!(Angle::radians_f(std::f32::consts::PI), Angle::degrees(180));
+    assert_eq!(Angle::radians_f(core::f32::consts::PI), Angle::degrees(180));
+}
+
+#[test]
+fn turns_and_gradians() {
+    assert_eq!(Angle::turns(Fraction::new(1, 2)), Angle::degrees(180));
+    assert_eq!(Angle::turns_f(0.25), Angle::degrees(90));
+    assert_eq!(Angle::turns_f(1.5), Angle::degrees(180));
+    assert_eq!(
+        Angle::degrees(90).into_turns::<Fraction>(),
+        Fraction::new(1, 4)
+    );
+    assert!((Angle::degrees(180).into_turns_f() - 0.5).abs() < f32::EPSILON);
+
+    assert_eq!(
+        Angle::gradians(Fraction::new_whole(100)),
+        Angle::degrees(90)
+    );
+    assert_eq!(Angle::gradians_f(500.), Angle::degrees(90));
+    assert_eq!(
+        Angle::degrees(90).into_gradians::<Fraction>(),
+        Fraction::new_whole(100)
+    );
+    assert!((Angle::degrees(270).into_gradians_f() - 300.).abs() < 0.001);
 }
 
 #[test]
 fn trig_approximation() {
-    use std::f32::consts::PI;
+    use core::f32::consts::PI;
 
     #[track_caller]
     fn assert_close_enough(f1: Fraction, f2: f32) {
+        #[cfg(feature = "std")]
         println!("Comparing {f1} against {f2}");
         assert!(
             (f1.into_f32() - f2).abs() < 0.000_001,
@@ -432,3 +721,45 @@ fn trig_approximation() {
     assert_close_enough(Angle::degrees_f(359.5).sin(), (359.5 / 180. * PI).sin());
     assert_close_enough(Angle::degrees_f(359.75).sin(), (359.75 / 180. * PI).sin());
 }
+
+#[test]
+fn signed_difference() {
+    assert_eq!(
+        Angle::degrees(135).signed_difference(Angle::degrees(90)),
+        Fraction::new_whole(45)
+    );
+    assert_eq!(
+        Angle::degrees(90).signed_difference(Angle::degrees(135)),
+        Fraction::new_whole(-45)
+    );
+    // The difference wraps through zero when that path is shorter.
+    assert_eq!(
+        Angle::degrees(10).signed_difference(Angle::degrees(350)),
+        Fraction::new_whole(20)
+    );
+    assert_eq!(
+        Angle::degrees(0).signed_difference(Angle::degrees(0)),
+        Fraction::ZERO
+    );
+    assert!(Angle::degrees(10).clockwise_from(Angle::degrees(350)));
+    assert!(!Angle::degrees(350).clockwise_from(Angle::degrees(10)));
+    assert!(!Angle::degrees(90).clockwise_from(Angle::degrees(90)));
+}
+
+#[test]
+fn const_sin_cos() {
+    // Computed at compile time.
+    const ROTATIONS: [(Fraction, Fraction); 4] = [
+        Angle::SINCOS_0,
+        Angle::SINCOS_90,
+        Angle::SINCOS_180,
+        Angle::SINCOS_270,
+    ];
+    assert_eq!(ROTATIONS[0], (Fraction::ZERO, Fraction::ONE));
+    assert_eq!(ROTATIONS[1], (Fraction::ONE, Fraction::ZERO));
+    assert_eq!(ROTATIONS[2], (Fraction::ZERO, -Fraction::ONE));
+    assert_eq!(ROTATIONS[3], (-Fraction::ONE, Fraction::ZERO));
+    // The const path matches the runtime lookup for whole degrees.
+    let angle = Angle::degrees(45);
+    assert_eq!(angle.sin_cos(), (angle.sin(), angle.cos()));
+}
@@ -0,0 +1,194 @@
+use core::fmt;
+use core::ops::{Div, Mul};
+
+use intentional::{Cast, CastInto};
+
+use crate::traits::UnscaledUnit;
+use crate::{Fraction, Size, Zero};
+
+/// The exact ratio of a width to a height.
+///
+/// Aspect ratios are stored as a [`Fraction`], so common ratios like 16:9 are
+/// represented exactly rather than as an approximate float.
+///
+/// ```rust
+/// use figures::units::Px;
+/// use figures::{AspectRatio, Size};
+///
+/// let video = Size::new(Px::new(1920), Px::new(1080));
+/// assert_eq!(AspectRatio::of(video), AspectRatio::SIXTEEN_NINE);
+/// assert_eq!(
+///     AspectRatio::SIXTEEN_NINE.width_for_height(Px::new(720)),
+///     Px::new(1280)
+/// );
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AspectRatio(Fraction);
+
+impl AspectRatio {
+    /// The 4:3 ratio of standard-definition video.
+    pub const FOUR_THREE: Self = Self(Fraction::new_maybe_reduced(4, 3));
+    /// The 16:9 ratio of high-definition video.
+    pub const SIXTEEN_NINE: Self = Self(Fraction::new_maybe_reduced(16, 9));
+    /// The 1:1 ratio of a square.
+    pub const SQUARE: Self = Self(Fraction::ONE);
+    /// The 21:9 ratio of ultrawide displays.
+    pub const TWENTYONE_NINE: Self = Self(Fraction::new_maybe_reduced(7, 3));
+
+    /// Returns the ratio of `width` to `height`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics in debug builds when `height` is 0.
+    #[must_use]
+    pub fn new(width: i16, height: i16) -> Self {
+        Self(Fraction::new(width, height))
+    }
+
+    /// Returns the aspect ratio of `size`.
+    ///
+    /// Because the unit's scaling factor applies to both dimensions, it
+    /// cancels out of the ratio and the result is exact whenever the reduced
+    /// ratio fits within a [`Fraction`]'s components.
+    #[must_use]
+    pub fn of<Unit>(size: Size<Unit>) -> Self
+    where
+        Unit: UnscaledUnit,
+    {
+        let width: i32 = size.width.into_unscaled().cast_into();
+        let height: i32 = size.height.into_unscaled().cast_into();
+        let numerator = u64::from(width.unsigned_abs());
+        let denominator = u64::from(height.unsigned_abs());
+        if denominator == 0 {
+            return Self(Fraction::ZERO);
+        }
+        let divisor = crate::num::gcd(numerator, denominator);
+        let numerator = numerator / divisor;
+        let denominator = denominator / divisor;
+        if let (Ok(numerator), Ok(denominator)) =
+            (i16::try_from(numerator), i16::try_from(denominator))
+        {
+            Self(Fraction::new(numerator, denominator))
+        } else {
+            // The reduced ratio doesn't fit within a `Fraction`'s components,
+            // so approximate it with the nearest representable fraction.
+            #[allow(clippy::cast_precision_loss)]
+            Self(Fraction::from(
+                ((numerator as f64) / (denominator as f64)).cast::<f32>(),
+            ))
+        }
+    }
+
+    /// Returns the ratio of the width to the height.
+    #[must_use]
+    pub const fn ratio(self) -> Fraction {
+        self.0
+    }
+
+    /// Returns the width that maintains this aspect ratio for `height`.
+    #[must_use]
+    pub fn width_for_height<Unit>(self, height: Unit) -> Unit
+    where
+        Unit: Mul<Fraction, Output = Unit>,
+    {
+        height * self.0
+    }
+
+    /// Returns the height that maintains this aspect ratio for `width`.
+    #[must_use]
+    pub fn height_for_width<Unit>(self, width: Unit) -> Unit
+    where
+        Unit: Div<Fraction, Output = Unit>,
+    {
+        width / self.0
+    }
+
+    /// Returns the largest size with this aspect ratio that fits entirely
+    /// within `size`.
+    #[must_use]
+    pub fn constrain<Unit>(self, size: Size<Unit>) -> Size<Unit>
+    where
+        Unit: Mul<Fraction, Output = Unit> + Div<Fraction, Output = Unit> + Ord + Copy,
+    {
+        let width_constrained = Size::new(size.width, self.height_for_width(size.width));
+        if width_constrained.height <= size.height {
+            width_constrained
+        } else {
+            Size::new(self.width_for_height(size.height), size.height)
+        }
+    }
+}
+
+impl Default for AspectRatio {
+    fn default() -> Self {
+        Self::SQUARE
+    }
+}
+
+impl Zero for AspectRatio {
+    const ZERO: Self = Self(Fraction::ZERO);
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl From<AspectRatio> for Fraction {
+    fn from(ratio: AspectRatio) -> Self {
+        ratio.0
+    }
+}
+
+impl From<Fraction> for AspectRatio {
+    fn from(ratio: Fraction) -> Self {
+        Self(ratio)
+    }
+}
+
+impl fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.0.numerator(), self.0.denominator())
+    }
+}
+
+#[test]
+fn aspect_ratios() {
+    use alloc::string::ToString;
+
+    use crate::units::Px;
+
+    assert_eq!(
+        AspectRatio::of(Size::new(Px::new(1920), Px::new(1080))),
+        AspectRatio::SIXTEEN_NINE
+    );
+    assert_eq!(
+        AspectRatio::of(Size::new(Px::new(640), Px::new(480))),
+        AspectRatio::FOUR_THREE
+    );
+    assert_eq!(AspectRatio::new(21, 9), AspectRatio::TWENTYONE_NINE);
+    assert_eq!(AspectRatio::SIXTEEN_NINE.to_string(), "16:9");
+
+    assert_eq!(
+        AspectRatio::SIXTEEN_NINE.height_for_width(Px::new(1280)),
+        Px::new(720)
+    );
+    assert_eq!(
+        AspectRatio::SIXTEEN_NINE.width_for_height(Px::new(720)),
+        Px::new(1280)
+    );
+
+    // Constraining picks the largest fitting size, regardless of which
+    // dimension limits.
+    let wide = Size::new(Px::new(1000), Px::new(1000));
+    assert_eq!(
+        AspectRatio::SIXTEEN_NINE.constrain(wide),
+        Size::new(Px::new(1000), Px::from(562.5))
+    );
+    let tall = Size::new(Px::new(160), Px::new(1000));
+    assert_eq!(
+        AspectRatio::SIXTEEN_NINE.constrain(tall),
+        Size::new(Px::new(160), Px::new(90))
+    );
+}
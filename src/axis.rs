@@ -0,0 +1,25 @@
+/// One of the two axes of the 2d coordinate space.
+///
+/// Layout code that works along a main axis and a cross axis — rows versus
+/// columns, for example — can use this type to be written once instead of
+/// duplicated per axis.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Axis {
+    /// The horizontal axis: `x` for points, `width` for sizes.
+    X,
+    /// The vertical axis: `y` for points, `height` for sizes.
+    Y,
+}
+
+impl Axis {
+    /// Returns the axis perpendicular to `self`.
+    #[must_use]
+    pub const fn cross(self) -> Self {
+        match self {
+            Self::X => Self::Y,
+            Self::Y => Self::X,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::traits::{Bounded, IntoSigned, IntoUnsigned, ScreenScale, Zero};
+use crate::units::{Lp, Px, UPx};
+use crate::{Fraction, Point, Rect, Size};
+
+/// A 2d circle expressed as a center ([`Point`]) and a radius.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Circle<Unit> {
+    /// The center of the circle.
+    pub center: Point<Unit>,
+    /// The distance from the center to the circle's edge.
+    pub radius: Unit,
+}
+
+impl<Unit> Circle<Unit> {
+    /// Returns a new circle with the provided `center` and `radius`.
+    pub const fn new(center: Point<Unit>, radius: Unit) -> Self {
+        Self { center, radius }
+    }
+
+    /// Maps each component to `map` and returns a new value with the mapped
+    /// components.
+    pub fn map<NewUnit>(self, mut map: impl FnMut(Unit) -> NewUnit) -> Circle<NewUnit> {
+        Circle {
+            center: self.center.map(&mut map),
+            radius: map(self.radius),
+        }
+    }
+
+    /// Returns true if this circle contains `point`.
+    pub fn contains(&self, point: Point<Unit>) -> bool
+    where
+        Unit: crate::Unit,
+    {
+        let delta = abs_delta(point, self.center);
+        delta.x * delta.x + delta.y * delta.y <= self.radius * self.radius
+    }
+
+    /// Returns the smallest rectangle that fully contains this circle.
+    pub fn bounding_rect(&self) -> Rect<Unit>
+    where
+        Unit: Add<Output = Unit> + Sub<Output = Unit> + Copy,
+    {
+        Rect::new(
+            Point::new(self.center.x - self.radius, self.center.y - self.radius),
+            Size::squared(self.radius + self.radius),
+        )
+    }
+
+    /// Returns the area of this circle.
+    ///
+    /// Because this crate approximates π with a [`Fraction`], the result is an
+    /// approximation whose error is proportional to the magnitude of the
+    /// radius.
+    pub fn area(&self) -> Unit
+    where
+        Unit: Mul<Output = Unit> + Mul<Fraction, Output = Unit> + Copy,
+    {
+        self.radius * self.radius * Fraction::PI
+    }
+
+    /// Returns true if any part of this circle's area overlaps `rect`.
+    pub fn intersects_rect(&self, rect: &Rect<Unit>) -> bool
+    where
+        Unit: crate::Unit,
+    {
+        let (top_left, bottom_right) = rect.extents();
+        let closest = Point::new(
+            self.center.x.clamp(top_left.x, bottom_right.x),
+            self.center.y.clamp(top_left.y, bottom_right.y),
+        );
+        let delta = abs_delta(closest, self.center);
+        delta.x * delta.x + delta.y * delta.y < self.radius * self.radius
+    }
+}
+
+/// Returns the componentwise absolute difference of two points without
+/// underflowing for unsigned units.
+pub(crate) fn abs_delta<Unit>(a: Point<Unit>, b: Point<Unit>) -> Point<Unit>
+where
+    Unit: Sub<Output = Unit> + Ord + Copy,
+{
+    Point::new(a.x.max(b.x) - a.x.min(b.x), a.y.max(b.y) - a.y.min(b.y))
+}
+
+impl<Unit> Bounded<Unit> for Circle<Unit>
+where
+    Unit: Add<Output = Unit> + Sub<Output = Unit> + Copy,
+{
+    fn bounds(&self) -> Rect<Unit> {
+        self.bounding_rect()
+    }
+}
+
+impl<Unit> ScreenScale for Circle<Unit>
+where
+    Unit: ScreenScale<Lp = Lp, Px = Px, UPx = UPx>,
+{
+    type Lp = Circle<Lp>;
+    type Px = Circle<Px>;
+    type UPx = Circle<UPx>;
+
+    fn into_px(self, scale: Fraction) -> Self::Px {
+        Circle {
+            center: self.center.into_px(scale),
+            radius: self.radius.into_px(scale),
+        }
+    }
+
+    fn from_px(px: Self::Px, scale: Fraction) -> Self {
+        Self {
+            center: Point::from_px(px.center, scale),
+            radius: Unit::from_px(px.radius, scale),
+        }
+    }
+
+    fn into_upx(self, scale: Fraction) -> Self::UPx {
+        Circle {
+            center: self.center.into_upx(scale),
+            radius: self.radius.into_upx(scale),
+        }
+    }
+
+    fn from_upx(px: Self::UPx, scale: Fraction) -> Self {
+        Self {
+            center: Point::from_upx(px.center, scale),
+            radius: Unit::from_upx(px.radius, scale),
+        }
+    }
+
+    fn into_lp(self, scale: Fraction) -> Self::Lp {
+        Circle {
+            center: self.center.into_lp(scale),
+            radius: self.radius.into_lp(scale),
+        }
+    }
+
+    fn from_lp(lp: Self::Lp, scale: Fraction) -> Self {
+        Self {
+            center: Point::from_lp(lp.center, scale),
+            radius: Unit::from_lp(lp.radius, scale),
+        }
+    }
+}
+
+impl<Unit> IntoSigned for Circle<Unit>
+where
+    Unit: IntoSigned,
+{
+    type Signed = Circle<Unit::Signed>;
+
+    fn into_signed(self) -> Self::Signed {
+        self.map(Unit::into_signed)
+    }
+}
+
+impl<Unit> IntoUnsigned for Circle<Unit>
+where
+    Unit: IntoUnsigned,
+{
+    type Unsigned = Circle<Unit::Unsigned>;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        self.map(Unit::into_unsigned)
+    }
+}
+
+impl<Unit> Zero for Circle<Unit>
+where
+    Unit: Zero,
+{
+    const ZERO: Self = Self {
+        center: Point::ZERO,
+        radius: Unit::ZERO,
+    };
+
+    fn is_zero(&self) -> bool {
+        self.center.is_zero() && self.radius.is_zero()
+    }
+}
+
+#[test]
+fn circle_contains() {
+    let circle = Circle::new(Point::new(10, 10), 5);
+    assert!(circle.contains(Point::new(10, 10)));
+    assert!(circle.contains(Point::new(10, 15)));
+    assert!(circle.contains(Point::new(13, 13)));
+    assert!(!circle.contains(Point::new(14, 14)));
+    assert!(!circle.contains(Point::new(10, 16)));
+}
+
+#[test]
+fn circle_intersects_rect() {
+    let circle = Circle::new(Point::new(10, 10), 5);
+    assert!(circle.intersects_rect(&Rect::new(Point::new(0, 0), Size::new(7, 7))));
+    // The corner of this rect is more than 5 units from the center.
+    assert!(!circle.intersects_rect(&Rect::new(Point::new(0, 0), Size::new(6, 6))));
+    assert!(circle.intersects_rect(&Rect::new(Point::new(0, 0), Size::new(100, 100))));
+}
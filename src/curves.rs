@@ -0,0 +1,308 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+
+use crate::traits::{Bounded, FloatConversion};
+use crate::{Fraction, Point, Rect};
+
+/// A quadratic bezier curve defined by a start point, a single control point,
+/// and an end point.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct QuadraticBezier<Unit> {
+    /// The point the curve begins at.
+    pub start: Point<Unit>,
+    /// The control point of the curve.
+    pub control: Point<Unit>,
+    /// The point the curve ends at.
+    pub end: Point<Unit>,
+}
+
+impl<Unit> QuadraticBezier<Unit> {
+    /// Returns a new curve with the provided points.
+    pub const fn new(start: Point<Unit>, control: Point<Unit>, end: Point<Unit>) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+}
+
+impl<Unit> QuadraticBezier<Unit>
+where
+    Unit: crate::Unit + Mul<Fraction, Output = Unit>,
+{
+    /// Returns the location of the curve at `t`, where `t` ranges from 0
+    /// (`start`) to 1 (`end`).
+    #[must_use]
+    pub fn point_at(&self, t: Fraction) -> Point<Unit> {
+        let a = lerp_point(self.start, self.control, t);
+        let b = lerp_point(self.control, self.end, t);
+        lerp_point(a, b, t)
+    }
+
+    /// Returns a rectangle that contains this curve.
+    ///
+    /// The returned rectangle is the bounding rectangle of the curve's control
+    /// points. Because a bezier curve is always contained by its control
+    /// polygon, the result fully contains the curve, but it may be larger than
+    /// the tightest possible bounds.
+    #[must_use]
+    pub fn bounding_rect(&self) -> Rect<Unit> {
+        Rect::from_extents(
+            self.start.min(self.control).min(self.end),
+            self.start.max(self.control).max(self.end),
+        )
+    }
+
+    /// Splits this curve at `t`, returning the two curves that combine to
+    /// form the original curve.
+    #[must_use]
+    pub fn split(&self, t: Fraction) -> (Self, Self) {
+        let a = lerp_point(self.start, self.control, t);
+        let b = lerp_point(self.control, self.end, t);
+        let mid = lerp_point(a, b, t);
+        (Self::new(self.start, a, mid), Self::new(mid, b, self.end))
+    }
+
+    /// Returns an iterator of points approximating this curve with line
+    /// segments, adaptively subdividing until each segment is within
+    /// `tolerance` of the true curve.
+    ///
+    /// The iterator always includes both `start` and `end`.
+    pub fn flatten(self, tolerance: Unit) -> impl Iterator<Item = Point<Unit>> {
+        let mut points = vec![self.start];
+        let max_deviation_squared = max_deviation_squared(tolerance);
+        self.flatten_into(&mut points, max_deviation_squared, MAX_FLATTEN_DEPTH);
+        points.into_iter()
+    }
+
+    fn flatten_into(self, points: &mut Vec<Point<Unit>>, max_deviation_squared: f32, depth: u8) {
+        if depth == 0 || self.is_flat(max_deviation_squared) {
+            points.push(self.end);
+        } else {
+            let (first, second) = self.split(ONE_HALF);
+            first.flatten_into(points, max_deviation_squared, depth - 1);
+            second.flatten_into(points, max_deviation_squared, depth - 1);
+        }
+    }
+
+    fn is_flat(&self, max_deviation_squared: f32) -> bool {
+        // The deviation of a quadratic curve from its chord is at most half
+        // the distance from the control point to the chord's midpoint.
+        let start = self.start.into_float();
+        let control = self.control.into_float();
+        let end = self.end.into_float();
+        let dx = control.x - (start.x + end.x) / 2.;
+        let dy = control.y - (start.y + end.y) / 2.;
+        (dx * dx + dy * dy) / 4. <= max_deviation_squared
+    }
+}
+
+impl<Unit> Bounded<Unit> for QuadraticBezier<Unit>
+where
+    Unit: crate::Unit + Mul<Fraction, Output = Unit>,
+{
+    fn bounds(&self) -> Rect<Unit> {
+        self.bounding_rect()
+    }
+}
+
+/// A cubic bezier curve defined by a start point, two control points, and an
+/// end point.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CubicBezier<Unit> {
+    /// The point the curve begins at.
+    pub start: Point<Unit>,
+    /// The control point associated with `start`.
+    pub control1: Point<Unit>,
+    /// The control point associated with `end`.
+    pub control2: Point<Unit>,
+    /// The point the curve ends at.
+    pub end: Point<Unit>,
+}
+
+impl<Unit> CubicBezier<Unit> {
+    /// Returns a new curve with the provided points.
+    pub const fn new(
+        start: Point<Unit>,
+        control1: Point<Unit>,
+        control2: Point<Unit>,
+        end: Point<Unit>,
+    ) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+}
+
+impl<Unit> CubicBezier<Unit>
+where
+    Unit: crate::Unit + Mul<Fraction, Output = Unit>,
+{
+    /// Returns the location of the curve at `t`, where `t` ranges from 0
+    /// (`start`) to 1 (`end`).
+    #[must_use]
+    pub fn point_at(&self, t: Fraction) -> Point<Unit> {
+        let a = lerp_point(self.start, self.control1, t);
+        let b = lerp_point(self.control1, self.control2, t);
+        let c = lerp_point(self.control2, self.end, t);
+        let ab = lerp_point(a, b, t);
+        let bc = lerp_point(b, c, t);
+        lerp_point(ab, bc, t)
+    }
+
+    /// Returns a rectangle that contains this curve.
+    ///
+    /// The returned rectangle is the bounding rectangle of the curve's control
+    /// points. Because a bezier curve is always contained by its control
+    /// polygon, the result fully contains the curve, but it may be larger than
+    /// the tightest possible bounds.
+    #[must_use]
+    pub fn bounding_rect(&self) -> Rect<Unit> {
+        Rect::from_extents(
+            self.start
+                .min(self.control1)
+                .min(self.control2)
+                .min(self.end),
+            self.start
+                .max(self.control1)
+                .max(self.control2)
+                .max(self.end),
+        )
+    }
+
+    /// Splits this curve at `t`, returning the two curves that combine to
+    /// form the original curve.
+    #[must_use]
+    pub fn split(&self, t: Fraction) -> (Self, Self) {
+        let a = lerp_point(self.start, self.control1, t);
+        let b = lerp_point(self.control1, self.control2, t);
+        let c = lerp_point(self.control2, self.end, t);
+        let ab = lerp_point(a, b, t);
+        let bc = lerp_point(b, c, t);
+        let mid = lerp_point(ab, bc, t);
+        (
+            Self::new(self.start, a, ab, mid),
+            Self::new(mid, bc, c, self.end),
+        )
+    }
+
+    /// Returns an iterator of points approximating this curve with line
+    /// segments, adaptively subdividing until each segment is within
+    /// `tolerance` of the true curve.
+    ///
+    /// The iterator always includes both `start` and `end`.
+    pub fn flatten(self, tolerance: Unit) -> impl Iterator<Item = Point<Unit>> {
+        let mut points = vec![self.start];
+        let max_deviation_squared = max_deviation_squared(tolerance);
+        self.flatten_into(&mut points, max_deviation_squared, MAX_FLATTEN_DEPTH);
+        points.into_iter()
+    }
+
+    fn flatten_into(self, points: &mut Vec<Point<Unit>>, max_deviation_squared: f32, depth: u8) {
+        if depth == 0 || self.is_flat(max_deviation_squared) {
+            points.push(self.end);
+        } else {
+            let (first, second) = self.split(ONE_HALF);
+            first.flatten_into(points, max_deviation_squared, depth - 1);
+            second.flatten_into(points, max_deviation_squared, depth - 1);
+        }
+    }
+
+    fn is_flat(&self, max_deviation_squared: f32) -> bool {
+        // This is the flatness test described by Kaspar Fischer in "Piecewise
+        // Linear Approximation of Bezier Curves": the deviation is bounded by
+        // 1/16th of the maximum squared distance of the control points from
+        // the corresponding points on the chord.
+        let start = self.start.into_float();
+        let control1 = self.control1.into_float();
+        let control2 = self.control2.into_float();
+        let end = self.end.into_float();
+        let d1x = 3. * control1.x - 2. * start.x - end.x;
+        let d1y = 3. * control1.y - 2. * start.y - end.y;
+        let d2x = 3. * control2.x - start.x - 2. * end.x;
+        let d2y = 3. * control2.y - start.y - 2. * end.y;
+        let deviation_squared = (d1x * d1x).max(d2x * d2x) + (d1y * d1y).max(d2y * d2y);
+        deviation_squared <= 16. * max_deviation_squared
+    }
+}
+
+impl<Unit> Bounded<Unit> for CubicBezier<Unit>
+where
+    Unit: crate::Unit + Mul<Fraction, Output = Unit>,
+{
+    fn bounds(&self) -> Rect<Unit> {
+        self.bounding_rect()
+    }
+}
+
+const MAX_FLATTEN_DEPTH: u8 = 16;
+const ONE_HALF: Fraction = Fraction::new_maybe_reduced(1, 2);
+
+fn max_deviation_squared<Unit>(tolerance: Unit) -> f32
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    let tolerance = tolerance.into_float();
+    tolerance * tolerance
+}
+
+/// Interpolates between two points without requiring subtraction, keeping the
+/// math safe for unsigned units.
+fn lerp_point<Unit>(a: Point<Unit>, b: Point<Unit>, t: Fraction) -> Point<Unit>
+where
+    Unit: Add<Output = Unit> + Mul<Fraction, Output = Unit> + Copy,
+{
+    let inverse = Fraction::ONE - t;
+    Point::new(a.x * inverse + b.x * t, a.y * inverse + b.y * t)
+}
+
+#[test]
+fn quadratic_point_at() {
+    // These control points are chosen so that each interpolation step divides
+    // evenly, keeping the integer math exact.
+    let curve = QuadraticBezier::new(
+        Point::<i32>::new(0, 0),
+        Point::new(48, 96),
+        Point::new(96, 0),
+    );
+    assert_eq!(curve.point_at(Fraction::ZERO), Point::new(0, 0));
+    assert_eq!(curve.point_at(Fraction::ONE), Point::new(96, 0));
+    assert_eq!(curve.point_at(ONE_HALF), Point::new(48, 48));
+}
+
+#[test]
+fn cubic_split() {
+    let curve = CubicBezier::new(
+        Point::<i32>::new(0, 0),
+        Point::new(0, 100),
+        Point::new(100, 100),
+        Point::new(100, 0),
+    );
+    let (first, second) = curve.split(ONE_HALF);
+    assert_eq!(first.start, curve.start);
+    assert_eq!(second.end, curve.end);
+    assert_eq!(first.end, curve.point_at(ONE_HALF));
+    assert_eq!(first.end, second.start);
+}
+
+#[test]
+fn flatten_ends() {
+    let curve = QuadraticBezier::new(
+        Point::<i32>::new(0, 0),
+        Point::new(50, 100),
+        Point::new(100, 0),
+    );
+    let points = curve.flatten(1).collect::<Vec<_>>();
+    assert_eq!(points.first(), Some(&Point::new(0, 0)));
+    assert_eq!(points.last(), Some(&Point::new(100, 0)));
+    assert!(points.len() > 2);
+}
@@ -0,0 +1,235 @@
+use core::ops::{Add, Sub};
+
+use crate::traits::{IntoSigned, IntoUnsigned, ScreenScale, Zero};
+use crate::units::{Lp, Px, UPx};
+use crate::{Point, Rect, Size};
+
+/// A measurement for each edge of a rectangle.
+///
+/// This type describes CSS-style margins or padding: an amount to apply to
+/// each of the four edges independently.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Edges<Unit> {
+    /// The measurement of the top edge.
+    pub top: Unit,
+    /// The measurement of the right edge.
+    pub right: Unit,
+    /// The measurement of the bottom edge.
+    pub bottom: Unit,
+    /// The measurement of the left edge.
+    pub left: Unit,
+}
+
+impl<Unit> Edges<Unit> {
+    /// Returns a new set of edges with the provided measurements.
+    pub const fn new(top: Unit, right: Unit, bottom: Unit, left: Unit) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Returns a new set of edges using `value` for all four edges.
+    pub fn all(value: Unit) -> Self
+    where
+        Unit: Clone,
+    {
+        Self {
+            top: value.clone(),
+            right: value.clone(),
+            bottom: value.clone(),
+            left: value,
+        }
+    }
+
+    /// Maps each edge to `map` and returns a new value with the mapped edges.
+    pub fn map<NewUnit>(self, mut map: impl FnMut(Unit) -> NewUnit) -> Edges<NewUnit> {
+        Edges {
+            top: map(self.top),
+            right: map(self.right),
+            bottom: map(self.bottom),
+            left: map(self.left),
+        }
+    }
+
+    /// Converts the contents of this set of edges to `NewUnit` using [`From`].
+    pub fn cast<NewUnit>(self) -> Edges<NewUnit>
+    where
+        NewUnit: From<Unit>,
+    {
+        self.map(NewUnit::from)
+    }
+
+    /// Returns the total amount these edges occupy along the horizontal axis.
+    pub fn width(self) -> Unit
+    where
+        Unit: Add<Output = Unit>,
+    {
+        self.left + self.right
+    }
+
+    /// Returns the total amount these edges occupy along the vertical axis.
+    pub fn height(self) -> Unit
+    where
+        Unit: Add<Output = Unit>,
+    {
+        self.top + self.bottom
+    }
+
+    /// Returns the total size these edges occupy.
+    pub fn size(self) -> Size<Unit>
+    where
+        Unit: Add<Output = Unit> + Copy,
+    {
+        Size::new(self.width(), self.height())
+    }
+}
+
+impl<Unit> From<Unit> for Edges<Unit>
+where
+    Unit: Clone,
+{
+    fn from(value: Unit) -> Self {
+        Self::all(value)
+    }
+}
+
+impl<Unit> Add for Edges<Unit>
+where
+    Unit: Add<Output = Unit>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            top: self.top + other.top,
+            right: self.right + other.right,
+            bottom: self.bottom + other.bottom,
+            left: self.left + other.left,
+        }
+    }
+}
+
+impl<Unit> Sub for Edges<Unit>
+where
+    Unit: Sub<Output = Unit>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            top: self.top - other.top,
+            right: self.right - other.right,
+            bottom: self.bottom - other.bottom,
+            left: self.left - other.left,
+        }
+    }
+}
+
+impl<Unit> Add<Edges<Unit>> for Rect<Unit>
+where
+    Unit: Add<Output = Unit> + Sub<Output = Unit> + Copy,
+{
+    type Output = Self;
+
+    /// Returns this rectangle outset by `edges`: the origin moves up and to
+    /// the left, and the size grows by the edges' totals.
+    fn add(self, edges: Edges<Unit>) -> Self {
+        Self::new(
+            Point::new(self.origin.x - edges.left, self.origin.y - edges.top),
+            Size::new(
+                self.size.width + edges.width(),
+                self.size.height + edges.height(),
+            ),
+        )
+    }
+}
+
+impl<Unit> Sub<Edges<Unit>> for Rect<Unit>
+where
+    Unit: Add<Output = Unit> + Sub<Output = Unit> + Copy,
+{
+    type Output = Self;
+
+    /// Returns this rectangle inset by `edges`, equivalent to
+    /// [`Rect::inset_by`].
+    fn sub(self, edges: Edges<Unit>) -> Self {
+        Self::new(
+            Point::new(self.origin.x + edges.left, self.origin.y + edges.top),
+            Size::new(
+                self.size.width - edges.width(),
+                self.size.height - edges.height(),
+            ),
+        )
+    }
+}
+
+impl<Unit> Zero for Edges<Unit>
+where
+    Unit: Zero,
+{
+    const ZERO: Self = Self::new(Unit::ZERO, Unit::ZERO, Unit::ZERO, Unit::ZERO);
+
+    fn is_zero(&self) -> bool {
+        self.top.is_zero() && self.right.is_zero() && self.bottom.is_zero() && self.left.is_zero()
+    }
+}
+
+impl<Unit> ScreenScale for Edges<Unit>
+where
+    Unit: ScreenScale<Lp = Lp, Px = Px, UPx = UPx>,
+{
+    type Lp = Edges<Lp>;
+    type Px = Edges<Px>;
+    type UPx = Edges<UPx>;
+
+    fn into_px(self, scale: crate::Fraction) -> Self::Px {
+        self.map(|edge| edge.into_px(scale))
+    }
+
+    fn from_px(px: Self::Px, scale: crate::Fraction) -> Self {
+        px.map(|edge| Unit::from_px(edge, scale))
+    }
+
+    fn into_upx(self, scale: crate::Fraction) -> Self::UPx {
+        self.map(|edge| edge.into_upx(scale))
+    }
+
+    fn from_upx(px: Self::UPx, scale: crate::Fraction) -> Self {
+        px.map(|edge| Unit::from_upx(edge, scale))
+    }
+
+    fn into_lp(self, scale: crate::Fraction) -> Self::Lp {
+        self.map(|edge| edge.into_lp(scale))
+    }
+
+    fn from_lp(lp: Self::Lp, scale: crate::Fraction) -> Self {
+        lp.map(|edge| Unit::from_lp(edge, scale))
+    }
+}
+
+impl<Unit> IntoSigned for Edges<Unit>
+where
+    Unit: IntoSigned,
+{
+    type Signed = Edges<Unit::Signed>;
+
+    fn into_signed(self) -> Self::Signed {
+        self.map(Unit::into_signed)
+    }
+}
+
+impl<Unit> IntoUnsigned for Edges<Unit>
+where
+    Unit: IntoUnsigned,
+{
+    type Unsigned = Edges<Unit::Unsigned>;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        self.map(Unit::into_unsigned)
+    }
+}
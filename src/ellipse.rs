@@ -0,0 +1,226 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::circle::abs_delta;
+use crate::traits::{Bounded, FloatConversion, IntoSigned, IntoUnsigned, ScreenScale, Zero};
+use crate::units::{Lp, Px, UPx};
+use crate::{Fraction, Point, Rect, Size};
+
+/// A 2d ellipse expressed as a center ([`Point`]) and a pair of radii
+/// ([`Size`]).
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Ellipse<Unit> {
+    /// The center of the ellipse.
+    pub center: Point<Unit>,
+    /// The distances from the center to the ellipse's edge along each axis.
+    pub radii: Size<Unit>,
+}
+
+impl<Unit> Ellipse<Unit> {
+    /// Returns a new ellipse with the provided `center` and `radii`.
+    pub const fn new(center: Point<Unit>, radii: Size<Unit>) -> Self {
+        Self { center, radii }
+    }
+
+    /// Maps each component to `map` and returns a new value with the mapped
+    /// components.
+    pub fn map<NewUnit>(self, mut map: impl FnMut(Unit) -> NewUnit) -> Ellipse<NewUnit> {
+        Ellipse {
+            center: self.center.map(&mut map),
+            radii: self.radii.map(map),
+        }
+    }
+
+    /// Returns true if this ellipse contains `point`.
+    ///
+    /// This check is performed using floating point math.
+    pub fn contains(&self, point: Point<Unit>) -> bool
+    where
+        Unit: crate::Unit,
+    {
+        let delta = abs_delta(point, self.center).into_float();
+        let radii = self.radii.into_float();
+        normalized_magnitude_squared(delta, radii) <= 1.
+    }
+
+    /// Returns the smallest rectangle that fully contains this ellipse.
+    pub fn bounding_rect(&self) -> Rect<Unit>
+    where
+        Unit: Add<Output = Unit> + Sub<Output = Unit> + Copy,
+    {
+        Rect::new(
+            Point::new(
+                self.center.x - self.radii.width,
+                self.center.y - self.radii.height,
+            ),
+            Size::new(
+                self.radii.width + self.radii.width,
+                self.radii.height + self.radii.height,
+            ),
+        )
+    }
+
+    /// Returns the area of this ellipse.
+    ///
+    /// Because this crate approximates π with a [`Fraction`], the result is an
+    /// approximation whose error is proportional to the magnitude of the
+    /// radii.
+    pub fn area(&self) -> Unit
+    where
+        Unit: Mul<Output = Unit> + Mul<Fraction, Output = Unit> + Copy,
+    {
+        self.radii.width * self.radii.height * Fraction::PI
+    }
+
+    /// Returns true if any part of this ellipse's area overlaps `rect`.
+    ///
+    /// This check is performed using floating point math.
+    pub fn intersects_rect(&self, rect: &Rect<Unit>) -> bool
+    where
+        Unit: crate::Unit,
+    {
+        let (top_left, bottom_right) = rect.extents();
+        let closest = Point::new(
+            self.center.x.clamp(top_left.x, bottom_right.x),
+            self.center.y.clamp(top_left.y, bottom_right.y),
+        );
+        let delta = abs_delta(closest, self.center).into_float();
+        let radii = self.radii.into_float();
+        normalized_magnitude_squared(delta, radii) < 1.
+    }
+}
+
+/// Returns the squared magnitude of `delta` scaled into a space where the
+/// ellipse described by `radii` is a unit circle.
+fn normalized_magnitude_squared(delta: Point<f32>, radii: Size<f32>) -> f32 {
+    let x = delta.x / radii.width;
+    let y = delta.y / radii.height;
+    x * x + y * y
+}
+
+impl<Unit> Bounded<Unit> for Ellipse<Unit>
+where
+    Unit: Add<Output = Unit> + Sub<Output = Unit> + Copy,
+{
+    fn bounds(&self) -> Rect<Unit> {
+        self.bounding_rect()
+    }
+}
+
+impl<Unit> From<crate::Circle<Unit>> for Ellipse<Unit>
+where
+    Unit: Copy,
+{
+    fn from(circle: crate::Circle<Unit>) -> Self {
+        Self {
+            center: circle.center,
+            radii: Size::squared(circle.radius),
+        }
+    }
+}
+
+impl<Unit> ScreenScale for Ellipse<Unit>
+where
+    Unit: ScreenScale<Lp = Lp, Px = Px, UPx = UPx>,
+{
+    type Lp = Ellipse<Lp>;
+    type Px = Ellipse<Px>;
+    type UPx = Ellipse<UPx>;
+
+    fn into_px(self, scale: Fraction) -> Self::Px {
+        Ellipse {
+            center: self.center.into_px(scale),
+            radii: self.radii.into_px(scale),
+        }
+    }
+
+    fn from_px(px: Self::Px, scale: Fraction) -> Self {
+        Self {
+            center: Point::from_px(px.center, scale),
+            radii: Size::from_px(px.radii, scale),
+        }
+    }
+
+    fn into_upx(self, scale: Fraction) -> Self::UPx {
+        Ellipse {
+            center: self.center.into_upx(scale),
+            radii: self.radii.into_upx(scale),
+        }
+    }
+
+    fn from_upx(px: Self::UPx, scale: Fraction) -> Self {
+        Self {
+            center: Point::from_upx(px.center, scale),
+            radii: Size::from_upx(px.radii, scale),
+        }
+    }
+
+    fn into_lp(self, scale: Fraction) -> Self::Lp {
+        Ellipse {
+            center: self.center.into_lp(scale),
+            radii: self.radii.into_lp(scale),
+        }
+    }
+
+    fn from_lp(lp: Self::Lp, scale: Fraction) -> Self {
+        Self {
+            center: Point::from_lp(lp.center, scale),
+            radii: Size::from_lp(lp.radii, scale),
+        }
+    }
+}
+
+impl<Unit> IntoSigned for Ellipse<Unit>
+where
+    Unit: IntoSigned,
+{
+    type Signed = Ellipse<Unit::Signed>;
+
+    fn into_signed(self) -> Self::Signed {
+        self.map(Unit::into_signed)
+    }
+}
+
+impl<Unit> IntoUnsigned for Ellipse<Unit>
+where
+    Unit: IntoUnsigned,
+{
+    type Unsigned = Ellipse<Unit::Unsigned>;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        self.map(Unit::into_unsigned)
+    }
+}
+
+impl<Unit> Zero for Ellipse<Unit>
+where
+    Unit: Zero,
+{
+    const ZERO: Self = Self {
+        center: Point::ZERO,
+        radii: Size::ZERO,
+    };
+
+    fn is_zero(&self) -> bool {
+        self.center.is_zero() && self.radii.is_zero()
+    }
+}
+
+#[test]
+fn ellipse_contains() {
+    let ellipse = Ellipse::new(Point::new(10, 10), Size::new(10, 5));
+    assert!(ellipse.contains(Point::new(10, 10)));
+    assert!(ellipse.contains(Point::new(20, 10)));
+    assert!(ellipse.contains(Point::new(10, 15)));
+    assert!(!ellipse.contains(Point::new(10, 16)));
+    assert!(!ellipse.contains(Point::new(19, 14)));
+}
+
+#[test]
+fn ellipse_intersects_rect() {
+    let ellipse = Ellipse::new(Point::new(10, 10), Size::new(10, 5));
+    assert!(ellipse.intersects_rect(&Rect::new(Point::new(0, 0), Size::new(100, 100))));
+    assert!(ellipse.intersects_rect(&Rect::new(Point::new(0, 8), Size::new(1, 1))));
+    assert!(!ellipse.intersects_rect(&Rect::new(Point::new(0, 0), Size::new(2, 2))));
+}
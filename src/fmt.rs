@@ -0,0 +1,87 @@
+//! Formatting helpers for logging geometry types.
+
+use core::fmt::{Display, Formatter, Result};
+
+use crate::traits::FloatConversion;
+use crate::{Point, Rect, Size};
+
+/// Wraps a geometry type to format it without unit suffixes.
+///
+/// The [`Display`] implementations for [`Point`], [`Size`], and [`Rect`]
+/// include each component's unit suffix. This wrapper formats the same values
+/// tersely for logs where the unit is understood from context:
+///
+/// ```rust
+/// use figures::fmt::Compact;
+/// use figures::units::Px;
+/// use figures::{Point, Size};
+///
+/// let point = Point::new(Px::new(10), Px::new(20));
+/// assert_eq!(point.to_string(), "10px,20px");
+/// assert_eq!(Compact(point).to_string(), "10,20");
+/// ```
+pub struct Compact<T>(pub T);
+
+impl<Unit> Display for Compact<Point<Unit>>
+where
+    Unit: FloatConversion<Float = f32> + Copy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{},{}", self.0.x.into_float(), self.0.y.into_float())
+    }
+}
+
+impl<Unit> Display for Compact<Size<Unit>>
+where
+    Unit: FloatConversion<Float = f32> + Copy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{}x{}",
+            self.0.width.into_float(),
+            self.0.height.into_float()
+        )
+    }
+}
+
+impl<Unit> Display for Compact<Rect<Unit>>
+where
+    Unit: FloatConversion<Float = f32> + Copy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}@{}", Compact(self.0.size), Compact(self.0.origin))
+    }
+}
+
+#[test]
+fn compact_formatting() {
+    use alloc::string::ToString;
+
+    use crate::units::Px;
+
+    let rect = Rect::<Px>::new(
+        Point::new(Px::new(10), Px::new(20)),
+        Size::new(Px::new(100), Px::new(50)),
+    );
+    assert_eq!(Compact(rect).to_string(), "100x50@10,20");
+    assert_eq!(
+        Compact(Point::new(Px::from(2.5), Px::from(7.))).to_string(),
+        "2.5,7"
+    );
+}
+
+#[test]
+fn display_formatting() {
+    use alloc::string::ToString;
+
+    use crate::units::Px;
+
+    let rect = Rect::<Px>::new(
+        Point::new(Px::new(10), Px::new(20)),
+        Size::new(Px::new(100), Px::new(50)),
+    );
+    assert_eq!(rect.to_string(), "100px x 50px @ 10px,20px");
+    assert_eq!(rect.origin.to_string(), "10px,20px");
+    assert_eq!(rect.size.to_string(), "100px x 50px");
+}
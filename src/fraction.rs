@@ -1,14 +1,21 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::iter::Peekable;
-use std::num::TryFromIntError;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Peekable;
+use core::num::TryFromIntError;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
 use intentional::Cast;
 
 use crate::primes::{FactorsOf, PRIMES};
-use crate::tables::{approximate_via_lookup_table, ARCTAN_SUBDIVISIONS, ARCTAN_TABLE};
+use crate::tables::{
+    approximate_via_lookup_table, ARCSIN_SUBDIVISIONS, ARCSIN_TABLE, ARCTAN_SUBDIVISIONS,
+    ARCTAN_TABLE,
+};
 use crate::traits::{Abs, Zero};
+// The test harness links `std`, which makes the inherent float methods
+// available even without the `std` feature enabled.
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::utils::FloatExt;
 use crate::Angle;
 
 /// Returns a new fraction.
@@ -70,6 +77,7 @@ macro_rules! fraction {
 /// numbers outside of angles represented in radians.
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[repr(C)]
 pub struct Fraction {
     numerator: i16,
@@ -79,36 +87,8 @@ pub struct Fraction {
 const MIN_VALUE: i16 = -i16::MAX;
 
 impl From<f32> for Fraction {
-    #[allow(clippy::cast_possible_truncation)] // truncation desired
     fn from(scale: f32) -> Self {
-        if scale < f32::from(MIN_VALUE) {
-            Self::MIN
-        } else if scale > f32::from(i16::MAX) {
-            Self::MAX
-        } else {
-            let mut best = Fraction {
-                numerator: 0,
-                denominator: 0,
-            };
-            let mut best_diff = f32::MAX;
-            for denominator in 1..=i16::MAX {
-                let numerator = (f32::from(denominator) * scale).round() as i16;
-                let ratio = Fraction {
-                    numerator,
-                    denominator,
-                };
-                let delta = (ratio.into_f32() - scale).abs();
-                if delta < best_diff {
-                    best = ratio;
-                    best_diff = delta;
-                    if delta <= f32::EPSILON {
-                        break;
-                    }
-                }
-            }
-
-            best
-        }
+        Self::approximate(scale, i16::MAX)
     }
 }
 
@@ -130,7 +110,10 @@ impl From<Fraction32> for Fraction {
 
         // Reducing didn't yield a fraction that we can represent perfectly.
         // Hunt for the largest prime divisor that yields a usable fraction
-        // and the smallest remainder.
+        // and the smallest remainder. The hunt operates on the magnitude so
+        // that the divisor filter works for negative numerators.
+        let negative = numerator.is_negative();
+        let numerator = numerator.saturating_abs();
         let mut best_numerator = i16::MAX;
         let mut best_denominator = i16::MAX;
         let mut best_remainder = i32::MAX;
@@ -162,7 +145,11 @@ impl From<Fraction32> for Fraction {
             }
         }
         Self {
-            numerator: best_numerator,
+            numerator: if negative {
+                -best_numerator
+            } else {
+                best_numerator
+            },
             denominator: best_denominator,
         }
     }
@@ -244,7 +231,7 @@ impl Fraction {
     pub fn new(numerator: i16, denominator: i16) -> Self {
         debug_assert!(denominator != 0);
 
-        Self::new_maybe_reduced(numerator.max(MIN_VALUE), denominator).reduce()
+        Self::new_maybe_reduced(numerator.max(MIN_VALUE), denominator).reduced()
     }
 
     /// Returns the numerator of the fraction.
@@ -281,6 +268,151 @@ impl Fraction {
         self.numerator.is_negative()
     }
 
+    /// Returns the best fractional approximation of `value` whose
+    /// denominator is no larger than `max_denominator`.
+    ///
+    /// The approximation is computed by expanding `value` into a continued
+    /// fraction, which converges in a few dozen iterations at most. Values
+    /// outside of the representable range are clamped to
+    /// [`Self::MIN`]/[`Self::MAX`], and non-finite values return
+    /// [`Self::ZERO`].
+    ///
+    /// ```rust
+    /// use figures::Fraction;
+    ///
+    /// assert_eq!(
+    ///     Fraction::approximate(core::f32::consts::PI, 100),
+    ///     Fraction::new(22, 7)
+    /// );
+    /// assert_eq!(
+    ///     Fraction::approximate(core::f32::consts::PI, i16::MAX),
+    ///     Fraction::PI
+    /// );
+    /// ```
+    #[must_use]
+    pub fn approximate(value: f32, max_denominator: i16) -> Self {
+        if !value.is_finite() {
+            return Self::ZERO;
+        } else if value < f32::from(MIN_VALUE) {
+            return Self::MIN;
+        } else if value > f32::from(i16::MAX) {
+            return Self::MAX;
+        }
+        let negative = value < 0.;
+        let mut remainder = f64::from(value.abs());
+        let max_denominator = i32::from(max_denominator.max(1));
+        // Track the two most recent convergents of the continued fraction.
+        let (mut previous_numerator, mut previous_denominator) = (0_i32, 1_i32);
+        let (mut numerator, mut denominator) = (1_i32, 0_i32);
+        loop {
+            #[allow(clippy::cast_possible_truncation)] // bounded by i16::MAX
+            let whole = remainder.floor() as i32;
+            let Some(next_numerator) = whole
+                .checked_mul(numerator)
+                .and_then(|scaled| scaled.checked_add(previous_numerator))
+            else {
+                break;
+            };
+            let Some(next_denominator) = whole
+                .checked_mul(denominator)
+                .and_then(|scaled| scaled.checked_add(previous_denominator))
+            else {
+                break;
+            };
+            if next_denominator > max_denominator || next_numerator > i32::from(i16::MAX) {
+                break;
+            }
+            (previous_numerator, previous_denominator) = (numerator, denominator);
+            (numerator, denominator) = (next_numerator, next_denominator);
+            let fractional = remainder - remainder.floor();
+            if fractional < 1e-9 {
+                break;
+            }
+            remainder = 1. / fractional;
+        }
+        let numerator = if negative { -numerator } else { numerator };
+        Self::new_maybe_reduced(numerator.cast(), denominator.cast())
+    }
+
+    /// Returns the sum of `self` and `other`, or `None` if the result cannot
+    /// be represented exactly.
+    ///
+    /// Unlike [`Add`], which reduces inexpressible results to a nearby
+    /// fraction, the `checked_*` operations never lose precision. This lets
+    /// tests assert exactness, and lets callers fall back to floating point
+    /// math knowingly:
+    ///
+    /// ```rust
+    /// use figures::fraction;
+    ///
+    /// assert_eq!(
+    ///     fraction!(1 / 3).checked_add(fraction!(1 / 6)),
+    ///     Some(fraction!(1 / 2))
+    /// );
+    /// assert_eq!(fraction!(1 / 32719).checked_add(fraction!(1 / 32749)), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::exact_from_i64(
+            i64::from(self.numerator) * i64::from(other.denominator)
+                + i64::from(other.numerator) * i64::from(self.denominator),
+            i64::from(self.denominator) * i64::from(other.denominator),
+        )
+    }
+
+    /// Returns the difference of `self` and `other`, or `None` if the result
+    /// cannot be represented exactly.
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::exact_from_i64(
+            i64::from(self.numerator) * i64::from(other.denominator)
+                - i64::from(other.numerator) * i64::from(self.denominator),
+            i64::from(self.denominator) * i64::from(other.denominator),
+        )
+    }
+
+    /// Returns the product of `self` and `other`, or `None` if the result
+    /// cannot be represented exactly.
+    #[must_use]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Self::exact_from_i64(
+            i64::from(self.numerator) * i64::from(other.numerator),
+            i64::from(self.denominator) * i64::from(other.denominator),
+        )
+    }
+
+    /// Returns the quotient of `self` and `other`, or `None` if `other` is
+    /// zero or the result cannot be represented exactly.
+    #[must_use]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            None
+        } else {
+            self.checked_mul(other.inverse())
+        }
+    }
+
+    /// Returns the exact reduced form of `numerator / denominator`, or
+    /// `None` if it does not fit in this type.
+    fn exact_from_i64(mut numerator: i64, mut denominator: i64) -> Option<Self> {
+        let common = i64::try_from(crate::num::gcd(
+            numerator.unsigned_abs(),
+            denominator.unsigned_abs(),
+        ))
+        .ok()?
+        .max(1);
+        numerator /= common;
+        denominator /= common;
+        let numerator = i16::try_from(numerator).ok()?;
+        if numerator < MIN_VALUE {
+            return None;
+        }
+        Some(Self::new_maybe_reduced(
+            numerator,
+            i16::try_from(denominator).ok()?,
+        ))
+    }
+
     /// Simplifies the fraction into a compound number.
     ///
     /// ```rust
@@ -381,7 +513,15 @@ impl Fraction {
         }
     }
 
-    fn reduce(mut self) -> Self {
+    /// Returns this fraction reduced to its simplest form.
+    ///
+    /// ```rust
+    /// use figures::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(4, 6), Fraction::new(2, 3));
+    /// ```
+    #[must_use]
+    pub fn reduced(mut self) -> Self {
         reduce(&mut self.numerator, &mut self.denominator);
         self
     }
@@ -435,6 +575,35 @@ impl Fraction {
         }
     }
 
+    /// Returns the arcsine of this fraction as an angle between -90 and 90
+    /// degrees.
+    ///
+    /// This function is implemented using a lookup table and is an
+    /// approximation. Values outside of `-1..=1` are clamped before looking
+    /// up the angle.
+    #[must_use]
+    pub fn asin(self) -> Angle {
+        let clamped = self.clamp(Self::new_whole(-1), Self::new_whole(1));
+        let index = clamped.abs() * Self::new_whole(ARCSIN_SUBDIVISIONS);
+        let result = approximate_via_lookup_table(index, &ARCSIN_TABLE);
+        if clamped.is_negative() {
+            Angle::radians(-result)
+        } else {
+            Angle::radians(result)
+        }
+    }
+
+    /// Returns the arccosine of this fraction as an angle between 0 and 180
+    /// degrees.
+    ///
+    /// This function is implemented using a lookup table and is an
+    /// approximation. Values outside of `-1..=1` are clamped before looking
+    /// up the angle.
+    #[must_use]
+    pub fn acos(self) -> Angle {
+        Angle::degrees(90) - self.asin()
+    }
+
     fn fast_atan(self) -> Angle {
         let index = self * Self::new_whole(ARCTAN_SUBDIVISIONS);
         let result = if index.is_negative() {
@@ -473,6 +642,35 @@ fn atan2() {
     );
 }
 
+#[test]
+fn asin_acos() {
+    assert_eq!(Fraction::new_whole(0).asin(), Angle::degrees(0));
+    assert_eq!(Fraction::new_whole(1).asin(), Angle::degrees(90));
+    assert_eq!(Fraction::new_whole(-1).asin(), Angle::degrees(270));
+    assert_eq!(Fraction::new_whole(1).acos(), Angle::degrees(0));
+    assert_eq!(Fraction::new_whole(0).acos(), Angle::degrees(90));
+    assert_eq!(Fraction::new_whole(-1).acos(), Angle::degrees(180));
+    let thirty = Fraction::new(1, 2).asin();
+    assert!((thirty.into_degrees::<f32>() - 30.).abs() < 0.1);
+    let sixty = Fraction::new(1, 2).acos();
+    assert!((sixty.into_degrees::<f32>() - 60.).abs() < 0.1);
+    // Out-of-domain values are clamped.
+    assert_eq!(Fraction::new_whole(2).asin(), Angle::degrees(90));
+    assert_eq!(Fraction::new_whole(-2).acos(), Angle::degrees(180));
+}
+
+#[test]
+fn from_sin_cos() {
+    for degrees in [0_i16, 30, 90, 135, 180, 215, 270, 315] {
+        let angle = Angle::degrees(degrees);
+        let recovered = Angle::from_sin_cos(angle.sin(), angle.cos());
+        assert!(
+            (recovered.into_degrees::<f32>() - f32::from(degrees)).abs() < 1.,
+            "{recovered} != {angle}"
+        );
+    }
+}
+
 pub fn reduce<T>(numerator: &mut T, denominator: &mut T)
 where
     T: Abs + Zero + Copy + From<i16> + Ord + Rem<Output = T> + DivAssign,
@@ -480,20 +678,20 @@ where
     let one = T::from(1);
     if numerator.is_zero() {
         *denominator = one;
-    } else if *denominator > one {
-        for prime in PRIMES {
-            let prime = T::from(prime);
-            if prime > numerator.abs() || prime > *denominator {
-                break;
-            }
-            while (*numerator % prime).is_zero() && (*denominator % prime).is_zero() {
-                *numerator /= prime;
-                *denominator /= prime;
-                if *denominator == one {
-                    break;
-                }
-            }
-        }
+        return;
+    }
+    // Euclid's algorithm; the types reduced here don't expose the shifts the
+    // binary GCD in `crate::num` relies on.
+    let mut a = numerator.abs();
+    let mut b = denominator.abs();
+    while !b.is_zero() {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    if a > one {
+        *numerator /= a;
+        *denominator /= a;
     }
 }
 
@@ -686,6 +884,431 @@ impl PartialOrd for Fraction {
     }
 }
 
+impl Zero for Fraction {
+    const ZERO: Self = Self::ZERO;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl crate::traits::One for Fraction {
+    const ONE: Self = Self::ONE;
+
+    fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+}
+
+impl crate::traits::Ranged for Fraction {
+    const MAX: Self = Self::MAX;
+    const MIN: Self = Self::MIN;
+}
+
+impl crate::traits::ScreenScale for Fraction {
+    type Lp = Self;
+    type Px = Self;
+    type UPx = Self;
+
+    fn into_px(self, _scale: Fraction) -> Self::Px {
+        self
+    }
+
+    fn from_px(px: Self::Px, _scale: Fraction) -> Self {
+        px
+    }
+
+    fn into_upx(self, _scale: Fraction) -> Self::UPx {
+        self
+    }
+
+    fn from_upx(px: Self::UPx, _scale: Fraction) -> Self {
+        px
+    }
+
+    fn into_lp(self, _scale: Fraction) -> Self::Lp {
+        self
+    }
+
+    fn from_lp(lp: Self::Lp, _scale: Fraction) -> Self {
+        lp
+    }
+}
+
+impl crate::traits::CheckedNumOps for Fraction {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_add(other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_sub(other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        self.checked_mul(other)
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_div(other)
+    }
+}
+
+/// A fraction with an `i32` numerator and denominator.
+///
+/// This type trades [`Fraction`]'s compactness for precision: with 31 bits
+/// of numerator and denominator, scale factors for very high-DPI or deeply
+/// zoomed canvases can be represented without degrading. All math is
+/// performed with 64-bit intermediates, and results are reduced before being
+/// stored. Results whose reduced form still does not fit are approximated by
+/// discarding low bits from both components.
+///
+/// ```rust
+/// use figures::{Fraction, Fraction64};
+///
+/// // These two additions overflow `Fraction`, but not `Fraction64`.
+/// let sum = Fraction64::new(1, 32_719) + Fraction64::new(1, 32_749);
+/// assert_eq!(sum, Fraction64::new(65_468, 1_071_514_531));
+///
+/// // Lossless conversions exist from `Fraction`, and fallible ones back.
+/// let half = Fraction64::from(Fraction::new(1, 2));
+/// assert_eq!(Fraction::try_from(half), Ok(Fraction::new(1, 2)));
+/// assert!(Fraction::try_from(sum).is_err());
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[repr(C)]
+pub struct Fraction64 {
+    numerator: i32,
+    denominator: i32,
+}
+
+impl Fraction64 {
+    /// The maximum value representable by this type.
+    pub const MAX: Self = Self::new_whole(i32::MAX);
+    /// The minimum value representable by this type.
+    pub const MIN: Self = Self::new_whole(i32::MIN);
+    /// A fraction equivalent to 1.
+    pub const ONE: Self = Self::new_whole(1);
+    /// A fraction equivalent to 0.
+    pub const ZERO: Self = Self::new_whole(0);
+
+    /// Returns a new fraction for a whole number.
+    #[must_use]
+    pub const fn new_whole(whole_number: i32) -> Self {
+        Self {
+            numerator: whole_number,
+            denominator: 1,
+        }
+    }
+
+    /// Returns a new fraction using the components provided, reduced to its
+    /// simplest form.
+    #[must_use]
+    pub fn new(numerator: i32, denominator: i32) -> Self {
+        debug_assert!(denominator != 0);
+
+        Self::from_i64(i64::from(numerator), i64::from(denominator))
+    }
+
+    /// Returns a fraction approximating `numerator / denominator`.
+    ///
+    /// The fraction is reduced, and if the reduced components still do not
+    /// fit in 32 bits, low bits are discarded from both until they do.
+    fn from_i64(mut numerator: i64, mut denominator: i64) -> Self {
+        if denominator.is_negative() {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        if numerator == 0 {
+            return Self::ZERO;
+        }
+        let common = crate::num::gcd(numerator.unsigned_abs(), denominator.unsigned_abs()).max(1);
+        numerator /= i64::try_from(common).expect("gcd of i64s fits");
+        denominator /= i64::try_from(common).expect("gcd of i64s fits");
+        while i32::try_from(numerator).is_err() || i32::try_from(denominator).is_err() {
+            numerator /= 2;
+            denominator = (denominator / 2).max(1);
+        }
+        Self {
+            numerator: numerator.cast(),
+            denominator: denominator.cast(),
+        }
+    }
+
+    /// Returns the numerator of the fraction.
+    #[must_use]
+    pub const fn numerator(&self) -> i32 {
+        self.numerator
+    }
+
+    /// Returns the denominator of the fraction.
+    #[must_use]
+    pub const fn denominator(&self) -> i32 {
+        self.denominator
+    }
+
+    /// Returns true if the fraction is positive (greater than zero).
+    ///
+    /// Note: Zero is neither negative nor positive.
+    #[must_use]
+    pub const fn is_positive(&self) -> bool {
+        self.numerator > 0
+    }
+
+    /// Returns true if the fraction is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// Returns true if the fraction is negative (less than zero).
+    ///
+    /// Note: Zero is neither negative nor positive.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    /// Returns this fraction as a floating point number.
+    #[must_use]
+    pub fn into_f32(self) -> f32 {
+        (f64::from(self.numerator) / f64::from(self.denominator)).cast()
+    }
+
+    /// Returns the inverse of this fraction.
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        if self.numerator >= 0 {
+            Self {
+                numerator: self.denominator,
+                denominator: self.numerator,
+            }
+        } else {
+            Self {
+                numerator: -self.denominator,
+                denominator: -self.numerator,
+            }
+        }
+    }
+
+    /// Returns the absolute value of this fraction.
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        if self.numerator >= 0 {
+            self
+        } else {
+            Self {
+                numerator: -self.numerator,
+                denominator: self.denominator,
+            }
+        }
+    }
+}
+
+impl From<Fraction> for Fraction64 {
+    fn from(value: Fraction) -> Self {
+        Self {
+            numerator: i32::from(value.numerator),
+            denominator: i32::from(value.denominator),
+        }
+    }
+}
+
+impl Zero for Fraction64 {
+    const ZERO: Self = Self::ZERO;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl crate::traits::One for Fraction64 {
+    const ONE: Self = Self::ONE;
+
+    fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+}
+
+impl crate::traits::Ranged for Fraction64 {
+    const MAX: Self = Self::MAX;
+    const MIN: Self = Self::MIN;
+}
+
+impl TryFrom<Fraction64> for Fraction {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Fraction64) -> Result<Self, Self::Error> {
+        Ok(Self::new_maybe_reduced(
+            i16::try_from(value.numerator)?,
+            i16::try_from(value.denominator)?,
+        ))
+    }
+}
+
+impl From<i32> for Fraction64 {
+    fn from(numerator: i32) -> Self {
+        Self::new_whole(numerator)
+    }
+}
+
+impl From<Fraction64> for f32 {
+    fn from(value: Fraction64) -> Self {
+        value.into_f32()
+    }
+}
+
+impl fmt::Debug for Fraction64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fraction64({self})")
+    }
+}
+
+impl fmt::Display for Fraction64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl Ord for Fraction64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Cross-multiplying two i32 products cannot overflow an i64, so no
+        // common denominator search is needed.
+        let lhs = i64::from(self.numerator) * i64::from(other.denominator);
+        let rhs = i64::from(other.numerator) * i64::from(self.denominator);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl PartialOrd for Fraction64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Fraction64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Add for Fraction64 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_i64(
+            i64::from(self.numerator) * i64::from(rhs.denominator)
+                + i64::from(rhs.numerator) * i64::from(self.denominator),
+            i64::from(self.denominator) * i64::from(rhs.denominator),
+        )
+    }
+}
+
+impl AddAssign for Fraction64 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Fraction64 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl SubAssign for Fraction64 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Fraction64 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_i64(
+            i64::from(self.numerator) * i64::from(rhs.numerator),
+            i64::from(self.denominator) * i64::from(rhs.denominator),
+        )
+    }
+}
+
+impl MulAssign for Fraction64 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Fraction64 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl DivAssign for Fraction64 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<Fraction64> for i32 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Fraction64) -> Self::Output {
+        let temporary = i64::from(self) * i64::from(rhs.numerator) / i64::from(rhs.denominator);
+        temporary
+            .clamp(i64::from(i32::MIN), i64::from(i32::MAX))
+            .cast()
+    }
+}
+
+impl Div<Fraction64> for i32 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Fraction64) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+#[test]
+fn fraction64_precision() {
+    // The denominators are prime, so `Fraction` must approximate while
+    // `Fraction64` holds the exact result.
+    let exact = Fraction64::new(1, 32_719) + Fraction64::new(1, 32_749);
+    assert_eq!(exact, Fraction64::new(65_468, 1_071_514_531));
+    assert_eq!(
+        Fraction64::new(2, 6),
+        Fraction64 {
+            numerator: 1,
+            denominator: 3
+        }
+    );
+    assert!(Fraction64::new(2, 3) > Fraction64::new(1, 2));
+    assert_eq!(100 * Fraction64::new(1, 4), 25);
+}
+
+#[test]
+fn fraction64_conversions() {
+    let half = Fraction64::from(Fraction::new(1, 2));
+    assert_eq!(half.numerator(), 1);
+    assert_eq!(half.denominator(), 2);
+    assert_eq!(Fraction::try_from(half), Ok(Fraction::new(1, 2)));
+    assert!(Fraction::try_from(Fraction64::new(1, 100_000)).is_err());
+}
+
 impl Neg for Fraction {
     type Output = Self;
 
@@ -810,6 +1433,52 @@ impl Div<Fraction> for u32 {
     }
 }
 
+impl Mul<Fraction> for i64 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Fraction) -> Self::Output {
+        let temporary = i128::from(self) * i128::from(rhs.numerator) / i128::from(rhs.denominator);
+        temporary
+            .clamp(i128::from(i64::MIN), i128::from(i64::MAX))
+            .cast()
+    }
+}
+
+impl Div<Fraction> for i64 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)] // I guess it is suspicious, lol.
+    fn div(self, rhs: Fraction) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl Mul<Fraction> for u64 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Fraction) -> Self::Output {
+        if let (Ok(numerator), Ok(denominator)) =
+            (u64::try_from(rhs.numerator), u64::try_from(rhs.denominator))
+        {
+            let temporary = u128::from(self) * u128::from(numerator) / u128::from(denominator);
+            temporary.min(u128::from(u64::MAX)).cast()
+        } else {
+            0
+        }
+    }
+}
+
+impl Div<Fraction> for u64 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)] // I guess it is suspicious, lol.
+    fn div(self, rhs: Fraction) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
 macro_rules! impl_math_ops_for_std_type {
     ($type:ident) => {
         impl Add<$type> for Fraction {
@@ -886,12 +1555,14 @@ fn ratio_ord() {
 
 #[test]
 fn ratio_debug() {
+    use alloc::format;
+
     assert_eq!(format!("{:?}", Fraction::from(1. / 3.)), "Fraction(1/3)");
 }
 
 #[test]
 fn pi() {
-    assert_eq!(Fraction::from(std::f32::consts::PI), Fraction::PI);
+    assert_eq!(Fraction::from(core::f32::consts::PI), Fraction::PI);
 }
 
 #[test]
@@ -940,3 +1611,36 @@ fn negative_denominator() {
         }
     );
 }
+
+#[test]
+fn approximate() {
+    assert_eq!(Fraction::approximate(1.5, i16::MAX), Fraction::new(3, 2));
+    assert_eq!(Fraction::approximate(-0.25, i16::MAX), Fraction::new(-1, 4));
+    assert_eq!(Fraction::approximate(0.333_334, 10), Fraction::new(1, 3));
+    assert_eq!(Fraction::approximate(f32::NAN, i16::MAX), Fraction::ZERO);
+    assert_eq!(Fraction::approximate(1e30, i16::MAX), Fraction::MAX);
+    // Window scale factors convert exactly.
+    assert_eq!(Fraction::from(1.25), Fraction::new(5, 4));
+    assert_eq!(Fraction::from(2.), Fraction::new_whole(2));
+}
+
+#[test]
+fn checked_math() {
+    assert_eq!(
+        fraction!(2 / 3).checked_mul(fraction!(3 / 4)),
+        Some(fraction!(1 / 2))
+    );
+    assert_eq!(
+        fraction!(1 / 3).checked_div(fraction!(2 / 1)),
+        Some(fraction!(1 / 6))
+    );
+    assert_eq!(fraction!(1 / 3).checked_div(Fraction::ZERO), None);
+    // The lossy example from the type documentation is reported as
+    // inexpressible instead of being approximated.
+    assert_eq!(fraction!(1 / 32719).checked_add(fraction!(1 / 32749)), None);
+    assert_eq!(Fraction::MAX.checked_add(Fraction::ONE), None);
+    assert_eq!(
+        Fraction::MAX.checked_sub(Fraction::ONE),
+        Some(Fraction::new_whole(i16::MAX - 1))
+    );
+}
@@ -0,0 +1,161 @@
+use crate::{Ranged, Size, Zero};
+
+/// Minimum, preferred, and maximum sizes for laying out an element.
+///
+/// Constraints are the vocabulary retained-mode UIs use to negotiate space:
+/// a parent offers an amount of space, and each child reports the range of
+/// sizes it can be laid out in. All comparisons are componentwise.
+///
+/// ```rust
+/// use figures::layout::Constraint;
+/// use figures::units::Px;
+/// use figures::Size;
+///
+/// let label = Constraint::new(
+///     Size::new(Px::new(50), Px::new(20)),
+///     Size::new(Px::new(100), Px::new(20)),
+///     Size::new(Px::new(200), Px::new(20)),
+/// );
+/// assert_eq!(
+///     label.clamp(Size::new(Px::new(300), Px::new(300))),
+///     Size::new(Px::new(200), Px::new(20))
+/// );
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Constraint<Unit> {
+    /// The smallest size the element can be laid out in.
+    pub minimum: Size<Unit>,
+    /// The size the element would choose given unlimited space.
+    pub preferred: Size<Unit>,
+    /// The largest size the element can make use of.
+    pub maximum: Size<Unit>,
+}
+
+impl<Unit> Constraint<Unit> {
+    /// Returns a new constraint from the provided sizes.
+    pub const fn new(minimum: Size<Unit>, preferred: Size<Unit>, maximum: Size<Unit>) -> Self {
+        Self {
+            minimum,
+            preferred,
+            maximum,
+        }
+    }
+
+    /// Returns a constraint that only permits `size`.
+    pub fn exact(size: Size<Unit>) -> Self
+    where
+        Unit: Copy,
+    {
+        Self::new(size, size, size)
+    }
+
+    /// Returns a constraint ranging from `minimum` to `maximum`, preferring
+    /// `minimum`.
+    pub fn between(minimum: Size<Unit>, maximum: Size<Unit>) -> Self
+    where
+        Unit: Copy,
+    {
+        Self::new(minimum, minimum, maximum)
+    }
+
+    /// Returns `size` limited componentwise to this constraint's minimum and
+    /// maximum.
+    pub fn clamp(&self, size: Size<Unit>) -> Size<Unit>
+    where
+        Unit: Ord + Copy,
+    {
+        Size::new(
+            size.width.clamp(self.minimum.width, self.maximum.width),
+            size.height.clamp(self.minimum.height, self.maximum.height),
+        )
+    }
+
+    /// Returns true if this constraint's minimum size fits within
+    /// `available`.
+    pub fn fits_within(&self, available: Size<Unit>) -> bool
+    where
+        Unit: Ord + Copy,
+    {
+        self.minimum.width <= available.width && self.minimum.height <= available.height
+    }
+
+    /// Returns the intersection of `self` and `other`: the constraint
+    /// satisfying both sets of limits.
+    ///
+    /// The result's minimum is the componentwise larger of the two minimums,
+    /// and its maximum the componentwise smaller of the two maximums. If the
+    /// ranges do not overlap, the minimum wins and the range collapses to it.
+    /// The preferred size is `self`'s, clamped to the new range.
+    #[must_use]
+    pub fn intersect(self, other: Self) -> Self
+    where
+        Unit: Ord + Copy,
+    {
+        let (_, minimum) = self.minimum.min_max(other.minimum);
+        let (maximum, _) = self.maximum.min_max(other.maximum);
+        let (_, maximum) = minimum.min_max(maximum);
+        let mut intersection = Self::new(minimum, self.preferred, maximum);
+        intersection.preferred = intersection.clamp(self.preferred);
+        intersection
+    }
+}
+
+impl<Unit> Default for Constraint<Unit>
+where
+    Unit: Zero + Ranged,
+{
+    /// Returns a fully unconstrained constraint: any size from zero to the
+    /// unit's maximum, preferring zero.
+    fn default() -> Self {
+        Self::new(Size::ZERO, Size::ZERO, Size::MAX)
+    }
+}
+
+#[test]
+fn constraint_clamping() {
+    use crate::units::Px;
+
+    let constraint = Constraint::new(
+        Size::new(Px::new(10), Px::new(10)),
+        Size::new(Px::new(50), Px::new(20)),
+        Size::new(Px::new(100), Px::new(40)),
+    );
+    assert_eq!(
+        constraint.clamp(Size::new(Px::new(5), Px::new(500))),
+        Size::new(Px::new(10), Px::new(40))
+    );
+    assert!(constraint.fits_within(Size::new(Px::new(10), Px::new(10))));
+    assert!(!constraint.fits_within(Size::new(Px::new(9), Px::new(10))));
+
+    assert_eq!(
+        Constraint::exact(Size::squared(Px::new(16))).clamp(Size::ZERO),
+        Size::squared(Px::new(16))
+    );
+}
+
+#[test]
+fn constraint_intersection() {
+    use crate::units::Px;
+
+    let a = Constraint::new(
+        Size::new(Px::new(10), Px::new(10)),
+        Size::new(Px::new(80), Px::new(30)),
+        Size::new(Px::new(100), Px::new(40)),
+    );
+    let b = Constraint::between(
+        Size::new(Px::new(20), Px::new(0)),
+        Size::new(Px::new(60), Px::new(60)),
+    );
+    let intersection = a.intersect(b);
+    assert_eq!(intersection.minimum, Size::new(Px::new(20), Px::new(10)));
+    assert_eq!(intersection.maximum, Size::new(Px::new(60), Px::new(40)));
+    assert_eq!(intersection.preferred, Size::new(Px::new(60), Px::new(30)));
+
+    // Disjoint ranges collapse to the larger minimum.
+    let narrow = Constraint::exact(Size::new(Px::new(5), Px::new(5)));
+    let collapsed = a.intersect(narrow);
+    assert_eq!(collapsed.minimum, a.minimum);
+    assert_eq!(collapsed.maximum, a.minimum);
+}
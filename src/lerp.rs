@@ -0,0 +1,328 @@
+//! Interpolation and easing helpers for animating geometry.
+
+use core::time::Duration;
+
+use intentional::Cast;
+
+use crate::units::{Lp, Px, UPx};
+use crate::{Angle, Fraction, Point, Rect, Size};
+
+/// Linear interpolation between two values.
+pub trait Lerp {
+    /// Returns the value `t` of the way from `self` to `target`, where `t`
+    /// ranges from 0 (`self`) to 1 (`target`).
+    #[must_use]
+    fn lerp(self, target: Self, t: Fraction) -> Self;
+}
+
+impl Lerp for Fraction {
+    fn lerp(self, target: Self, t: Fraction) -> Self {
+        self + (target - self) * t
+    }
+}
+
+macro_rules! impl_lerp_signed {
+    ($type:ty) => {
+        impl Lerp for $type {
+            fn lerp(self, target: Self, t: Fraction) -> Self {
+                self + (target - self) * t
+            }
+        }
+    };
+}
+
+impl_lerp_signed!(Px);
+impl_lerp_signed!(Lp);
+
+impl Lerp for UPx {
+    fn lerp(self, target: Self, t: Fraction) -> Self {
+        // Computed as a weighted sum of both endpoints, keeping the
+        // intermediate math from underflowing when `target < self`.
+        self * (Fraction::ONE - t) + target * t
+    }
+}
+
+impl Lerp for Angle {
+    /// Interpolates along the shortest arc between the two angles, wrapping
+    /// through zero when that path is shorter. See
+    /// [`Angle::signed_difference`].
+    fn lerp(self, target: Self, t: Fraction) -> Self {
+        let difference = target.signed_difference(self);
+        Self::degrees_fraction(self.into_degrees::<Fraction>() + difference * t)
+    }
+}
+
+impl<Unit> Lerp for Point<Unit>
+where
+    Unit: Lerp,
+{
+    fn lerp(self, target: Self, t: Fraction) -> Self {
+        Self::new(self.x.lerp(target.x, t), self.y.lerp(target.y, t))
+    }
+}
+
+impl<Unit> Lerp for Size<Unit>
+where
+    Unit: Lerp,
+{
+    fn lerp(self, target: Self, t: Fraction) -> Self {
+        Self::new(
+            self.width.lerp(target.width, t),
+            self.height.lerp(target.height, t),
+        )
+    }
+}
+
+impl<Unit> Lerp for Rect<Unit>
+where
+    Unit: Lerp,
+{
+    fn lerp(self, target: Self, t: Fraction) -> Self {
+        Self::new(
+            self.origin.lerp(target.origin, t),
+            self.size.lerp(target.size, t),
+        )
+    }
+}
+
+/// Returns the progress of `elapsed` through `duration` as a value from 0
+/// to 1, for driving [`Lerp::lerp`] from animation timers.
+///
+/// A zero `duration` returns 1, treating the animation as complete.
+#[must_use]
+pub fn progress(elapsed: Duration, duration: Duration) -> Fraction {
+    if duration.is_zero() {
+        Fraction::ONE
+    } else {
+        Fraction::from(
+            (elapsed.as_secs_f64() / duration.as_secs_f64())
+                .clamp(0., 1.)
+                .cast::<f32>(),
+        )
+    }
+}
+
+/// Smooths frame-to-frame time deltas for stable animation velocities.
+///
+/// Raw frame deltas spike whenever the system hiccups, which makes
+/// velocity-based animations jump. This type clamps each sample to a maximum
+/// delta and blends it into an exponential moving average using a
+/// [`Fraction`] weight, keeping the result deterministic across platforms.
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use figures::lerp::DeltaSmoother;
+/// use figures::Fraction;
+///
+/// let mut smoother = DeltaSmoother::new(Fraction::new(1, 4), Duration::from_millis(100));
+/// // The first sample is used as-is.
+/// assert_eq!(
+///     smoother.sample(Duration::from_millis(16)),
+///     Duration::from_millis(16)
+/// );
+/// // A spike is clamped to the maximum delta before being blended in.
+/// assert_eq!(
+///     smoother.sample(Duration::from_secs(5)),
+///     Duration::from_millis(37)
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeltaSmoother {
+    weight: Fraction,
+    max_delta: Duration,
+    average: Option<Duration>,
+}
+
+impl DeltaSmoother {
+    /// Returns a smoother blending each new sample in with `weight`, after
+    /// clamping it to `max_delta`.
+    ///
+    /// A weight of 1 disables smoothing, while smaller weights respond more
+    /// slowly to changes in frame time. Weights outside of `0..=1` are
+    /// clamped.
+    #[must_use]
+    pub fn new(weight: Fraction, max_delta: Duration) -> Self {
+        Self {
+            weight: weight.clamp(Fraction::ZERO, Fraction::ONE),
+            max_delta,
+            average: None,
+        }
+    }
+
+    /// Records a frame delta and returns the smoothed delta.
+    pub fn sample(&mut self, delta: Duration) -> Duration {
+        let delta = delta.min(self.max_delta);
+        let average = match self.average {
+            Some(average) => {
+                let numerator = u128::from(self.weight.numerator().unsigned_abs());
+                let denominator = u128::from(self.weight.denominator().unsigned_abs());
+                let blended = (average.as_nanos() * (denominator - numerator)
+                    + delta.as_nanos() * numerator)
+                    / denominator;
+                Duration::from_nanos(u64::try_from(blended).unwrap_or(u64::MAX))
+            }
+            None => delta,
+        };
+        self.average = Some(average);
+        average
+    }
+
+    /// Returns the current smoothed delta, or None if no samples have been
+    /// recorded.
+    #[must_use]
+    pub const fn current(&self) -> Option<Duration> {
+        self.average
+    }
+
+    /// Forgets all recorded samples, such as when an animation loop resumes
+    /// after being paused.
+    pub fn reset(&mut self) {
+        self.average = None;
+    }
+}
+
+impl Default for DeltaSmoother {
+    /// Returns a smoother weighting each new sample by 1/4 and clamping
+    /// deltas to 100 milliseconds.
+    fn default() -> Self {
+        Self::new(Fraction::new(1, 4), Duration::from_millis(100))
+    }
+}
+
+/// Returns `t` eased so that changes start slowly and accelerate.
+#[must_use]
+pub fn ease_in(t: Fraction) -> Fraction {
+    t * t
+}
+
+/// Returns `t` eased so that changes start quickly and decelerate.
+#[must_use]
+pub fn ease_out(t: Fraction) -> Fraction {
+    let inverse = Fraction::ONE - t;
+    Fraction::ONE - inverse * inverse
+}
+
+/// Returns `t` eased by a CSS-style cubic bezier curve.
+///
+/// The curve begins at (0, 0) and ends at (1, 1) with control points
+/// (`x1`, `y1`) and (`x2`, `y2`). `x1` and `x2` must be within `0..=1` for
+/// the curve to describe a function of time.
+#[must_use]
+pub fn cubic_bezier_ease(x1: f32, y1: f32, x2: f32, y2: f32, t: Fraction) -> Fraction {
+    let progress = t.into_f32().clamp(0., 1.);
+    // With x1 and x2 within 0..=1, x is monotonic over the curve parameter,
+    // allowing a binary search for the parameter whose x matches `progress`.
+    let mut low = 0.0_f32;
+    let mut high = 1.0_f32;
+    for _ in 0..32 {
+        let mid = (low + high) / 2.;
+        if sample_axis(x1, x2, mid) < progress {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Fraction::from(sample_axis(y1, y2, (low + high) / 2.))
+}
+
+/// Evaluates one axis of a cubic bezier curve from 0 to 1 with control
+/// values `c1` and `c2`.
+fn sample_axis(c1: f32, c2: f32, t: f32) -> f32 {
+    let inverse = 1. - t;
+    3. * inverse * inverse * t * c1 + 3. * inverse * t * t * c2 + t * t * t
+}
+
+#[test]
+fn lerp_units() {
+    let half = Fraction::new(1, 2);
+    assert_eq!(Px::new(10).lerp(Px::new(20), half), Px::new(15));
+    assert_eq!(Px::new(10).lerp(Px::new(20), Fraction::ZERO), Px::new(10));
+    assert_eq!(Px::new(10).lerp(Px::new(20), Fraction::ONE), Px::new(20));
+    // UPx interpolates downwards without underflowing.
+    assert_eq!(UPx::new(20).lerp(UPx::new(10), half), UPx::new(15));
+    assert_eq!(
+        Angle::degrees(90).lerp(Angle::degrees(180), half),
+        Angle::degrees(135)
+    );
+    // Angles interpolate along the shortest arc, wrapping through zero.
+    assert_eq!(
+        Angle::degrees(350).lerp(Angle::degrees(30), half),
+        Angle::degrees(10)
+    );
+    assert_eq!(
+        Angle::degrees(30).lerp(Angle::degrees(350), half),
+        Angle::degrees(10)
+    );
+}
+
+#[test]
+fn lerp_containers() {
+    let half = Fraction::new(1, 2);
+    let start = Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(10), Px::new(10)),
+    );
+    let end = Rect::new(
+        Point::new(Px::new(10), Px::new(20)),
+        Size::new(Px::new(30), Px::new(50)),
+    );
+    assert_eq!(
+        start.lerp(end, half),
+        Rect::new(
+            Point::new(Px::new(5), Px::new(10)),
+            Size::new(Px::new(20), Px::new(30)),
+        )
+    );
+}
+
+#[test]
+fn easing() {
+    let half = Fraction::new(1, 2);
+    assert_eq!(ease_in(half), Fraction::new(1, 4));
+    assert_eq!(ease_out(half), Fraction::new(3, 4));
+    assert_eq!(ease_in(Fraction::ONE), Fraction::ONE);
+    assert_eq!(ease_out(Fraction::ZERO), Fraction::ZERO);
+
+    // A linear curve leaves `t` unchanged, up to the f32 round trip.
+    let eased = cubic_bezier_ease(1. / 3., 1. / 3., 2. / 3., 2. / 3., half);
+    assert!((eased.into_f32() - 0.5).abs() < 0.01);
+}
+
+#[test]
+fn duration_progress() {
+    assert_eq!(
+        progress(Duration::from_millis(500), Duration::from_secs(1)),
+        Fraction::new(1, 2)
+    );
+    assert_eq!(
+        progress(Duration::from_secs(2), Duration::from_secs(1)),
+        Fraction::ONE
+    );
+    assert_eq!(progress(Duration::ZERO, Duration::ZERO), Fraction::ONE);
+}
+
+#[test]
+fn delta_smoothing() {
+    let mut smoother = DeltaSmoother::default();
+    assert_eq!(smoother.current(), None);
+    assert_eq!(
+        smoother.sample(Duration::from_millis(16)),
+        Duration::from_millis(16)
+    );
+    // Steady input stays steady.
+    assert_eq!(
+        smoother.sample(Duration::from_millis(16)),
+        Duration::from_millis(16)
+    );
+    // A hiccup is clamped and only partially blended in.
+    let blended = smoother.sample(Duration::from_secs(1));
+    assert_eq!(blended, Duration::from_millis(37));
+    assert_eq!(smoother.current(), Some(blended));
+    smoother.reset();
+    assert_eq!(smoother.current(), None);
+    assert_eq!(
+        smoother.sample(Duration::from_millis(8)),
+        Duration::from_millis(8)
+    );
+}
@@ -4,36 +4,109 @@
 #![deny(unsafe_code)]
 #![warn(missing_docs, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!("figures requires either the `std` or the `libm` feature");
+
+extern crate alloc;
 
 #[macro_use]
 mod fraction;
 #[macro_use]
 mod twod;
+mod alignment;
+mod aspect_ratio;
+mod axis;
+/// Batch transforms for slices of points.
+#[cfg(feature = "simd")]
+pub mod batch;
+mod circle;
+mod curves;
+mod edges;
+mod ellipse;
+/// Formatting helpers for logging geometry types.
+pub mod fmt;
+/// Size constraints and other layout negotiation vocabulary.
+pub mod layout;
+/// Interpolation and easing helpers for animating geometry.
+pub mod lerp;
+mod nudge;
+/// Integer numeric utilities shared by the fraction types.
+pub mod num;
+/// Rectangle packing for building texture atlases.
+pub mod pack;
+/// Parsing for unit strings.
+pub mod parse;
+mod path;
+mod physics;
 #[cfg(feature = "bytemuck")]
 mod pod;
 mod point;
 mod primes;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rand")]
+mod random;
 mod rect;
+mod region;
+mod rounded_rect;
+mod scaling;
+/// Serialization helpers for the unit types.
+#[cfg(feature = "serde")]
+pub mod serde;
 mod size;
+mod stroke;
 mod tables;
+/// Helpers for mapping text hit locations to caret indexes.
+pub mod text;
 mod traits;
+mod transform;
+// The derive macros expand to paths rooted at `figures`, which this alias
+// makes resolvable from within this crate's own tests.
+#[cfg(feature = "derive")]
+extern crate self as figures;
+
+#[cfg(feature = "derive")]
+pub use figures_macros::{FromComponents, IntoComponents, Ranged, ScreenScale, Zero};
 pub use traits::{
-    Abs, FloatConversion, FloatOrInt, FromComponents, IntoComponents, IntoSigned, IntoUnsigned,
-    Lp2D, PixelScaling, Pow, Px2D, Ranged, Roots, Round, ScreenScale, ScreenUnit, StdNumOps, UPx2D,
-    Unit, UnscaledUnit, Zero,
+    Abs, ApproxEq, Bounded, CheckedNumOps, ConvertUnit, FloatConversion, FloatOrInt,
+    FromComponents, FromComponents4, IntoComponents, IntoComponents4, IntoSigned, IntoUnsigned,
+    Lp2D, One, PixelScaling, Pow, Px2D, Ranged, Roots, Round, ScreenScale, ScreenUnit, StdNumOps,
+    UPx2D, Unit, UnscaledUnit, WideMul, Zero,
 };
 /// The measurement units supported by figures.
 pub mod units;
 /// Utility functions to aide in warning-free development for users of
 /// `clippy::pedantic`.
 mod utils;
+mod uv;
 
 mod angle;
 #[cfg(test)]
 mod tests;
 
+pub use alignment::Alignment;
 pub use angle::Angle;
-pub use fraction::Fraction;
-pub use point::Point;
+pub use aspect_ratio::AspectRatio;
+pub use axis::Axis;
+pub use circle::Circle;
+pub use curves::{CubicBezier, QuadraticBezier};
+pub use edges::Edges;
+pub use ellipse::Ellipse;
+pub use fraction::{Fraction, Fraction64};
+pub use lerp::Lerp;
+pub use nudge::{nudge, nudge_scaled, Direction4, NudgeStep};
+pub use path::{Path, PathSegment};
+pub use physics::{Acceleration, AngularVelocity, Velocity};
+pub use point::{centroid, fit_line, normalize_all, Orientation, Point};
 pub use rect::Rect;
+pub use region::{diff_rects, RectDiff, Region};
+pub use rounded_rect::{CornerRadii, RoundedRect};
+pub use scaling::{Scaling, ScalingContext, ScalingRegistry};
 pub use size::Size;
+#[cfg(feature = "winit")]
+pub use size::SizeOverflow;
+pub use stroke::{stroke_polyline, Cap, Join};
+pub use transform::TransformStack;
+pub use uv::UvCoord;
@@ -0,0 +1,105 @@
+use crate::units::Lp;
+use crate::Point;
+
+/// A cardinal direction on screen.
+///
+/// This crate uses screen coordinates, where the y axis points down, so
+/// [`Direction4::Up`] moves towards smaller y values.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Direction4 {
+    /// Towards the top of the screen.
+    Up,
+    /// Towards the bottom of the screen.
+    Down,
+    /// Towards the left of the screen.
+    Left,
+    /// Towards the right of the screen.
+    Right,
+}
+
+impl Direction4 {
+    /// Returns the unit vector pointing in this direction.
+    #[must_use]
+    pub const fn unit_vector(self) -> Point<i32> {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::Right => Point::new(1, 0),
+        }
+    }
+}
+
+/// The distance a [`nudge`] operation moves.
+///
+/// The steps are defined in [`Lp`] so that nudging behaves consistently
+/// across displays with different DPI settings.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum NudgeStep {
+    /// A one logical pixel step, for fine adjustments.
+    Small,
+    /// A five logical pixel step.
+    Medium,
+    /// A ten logical pixel step, typically bound to a held modifier key.
+    Large,
+}
+
+impl NudgeStep {
+    /// Returns the distance this step moves.
+    #[must_use]
+    pub const fn amount(self) -> Lp {
+        match self {
+            Self::Small => Lp::new(1),
+            Self::Medium => Lp::new(5),
+            Self::Large => Lp::new(10),
+        }
+    }
+}
+
+/// Returns `point` moved one `step` in `direction`.
+#[must_use]
+pub fn nudge(point: Point<Lp>, direction: Direction4, step: NudgeStep) -> Point<Lp> {
+    nudge_scaled(point, direction, step, 1)
+}
+
+/// Returns `point` moved `multiplier` `step`s in `direction`.
+///
+/// This is a convenience for modifier keys that scale the nudge distance,
+/// e.g., doubling or halving the step while a modifier is held.
+#[must_use]
+pub fn nudge_scaled(
+    point: Point<Lp>,
+    direction: Direction4,
+    step: NudgeStep,
+    multiplier: i32,
+) -> Point<Lp> {
+    let vector = direction.unit_vector();
+    let distance = step.amount() * multiplier;
+    point + Point::new(distance * vector.x, distance * vector.y)
+}
+
+#[test]
+fn nudge_directions() {
+    let start = Point::new(Lp::new(10), Lp::new(10));
+    assert_eq!(
+        nudge(start, Direction4::Up, NudgeStep::Small),
+        Point::new(Lp::new(10), Lp::new(9))
+    );
+    assert_eq!(
+        nudge(start, Direction4::Right, NudgeStep::Large),
+        Point::new(Lp::new(20), Lp::new(10))
+    );
+}
+
+#[test]
+fn nudge_scaling() {
+    let start = Point::new(Lp::new(0), Lp::new(0));
+    assert_eq!(
+        nudge_scaled(start, Direction4::Down, NudgeStep::Medium, 3),
+        Point::new(Lp::new(0), Lp::new(15))
+    );
+    assert_eq!(
+        nudge_scaled(start, Direction4::Left, NudgeStep::Small, 2),
+        Point::new(Lp::new(-2), Lp::new(0))
+    );
+}
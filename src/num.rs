@@ -0,0 +1,68 @@
+//! Integer numeric utilities shared by the fraction types.
+
+/// Returns the greatest common divisor of `a` and `b`.
+///
+/// This function uses the binary GCD algorithm, which replaces division with
+/// shifts and subtraction. The GCD of zero and any value is the other value,
+/// and `gcd(0, 0)` is zero.
+///
+/// ```rust
+/// use figures::num::gcd;
+///
+/// assert_eq!(gcd(1920, 1080), 120);
+/// assert_eq!(gcd(17, 4), 1);
+/// assert_eq!(gcd(0, 42), 42);
+/// ```
+#[must_use]
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    } else if b == 0 {
+        return a;
+    }
+    let common_twos = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            let swap = a;
+            a = b;
+            b = swap;
+        }
+        b -= a;
+        if b == 0 {
+            break;
+        }
+    }
+    a << common_twos
+}
+
+/// Returns the least common multiple of `a` and `b`.
+///
+/// The least common multiple of zero and any value is zero. Results that
+/// overflow are saturated to `u64::MAX`.
+///
+/// ```rust
+/// use figures::num::lcm;
+///
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(0, 42), 0);
+/// ```
+#[must_use]
+pub const fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b)).saturating_mul(b)
+}
+
+#[test]
+fn gcd_lcm() {
+    assert_eq!(gcd(0, 0), 0);
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(18, 12), 6);
+    // Aspect-ratio simplification.
+    assert_eq!(3840 / gcd(3840, 2160), 16);
+    assert_eq!(2160 / gcd(3840, 2160), 9);
+    assert_eq!(lcm(u64::MAX, 2), u64::MAX);
+}
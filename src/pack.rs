@@ -0,0 +1,222 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::units::UPx;
+use crate::{Point, Rect, Size, Zero};
+
+/// A rectangle packer for building texture atlases.
+///
+/// The packer uses the skyline bottom-left heuristic: each rectangle is
+/// placed at the lowest position it fits, preferring the leftmost position
+/// when several are equally low. Placements never overlap and always lie
+/// within the bin.
+///
+/// ```rust
+/// use figures::pack::Packer;
+/// use figures::units::UPx;
+/// use figures::Size;
+///
+/// let mut packer = Packer::new(Size::squared(UPx::new(64)));
+/// let a = packer.pack(Size::new(UPx::new(32), UPx::new(16))).unwrap();
+/// let b = packer.pack(Size::new(UPx::new(32), UPx::new(16))).unwrap();
+/// assert!(a.intersection(&b).is_none());
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Packer {
+    size: Size<UPx>,
+    skyline: Vec<Segment>,
+}
+
+impl Packer {
+    /// Returns an empty packer for a bin of `size`.
+    #[must_use]
+    pub fn new(size: Size<UPx>) -> Self {
+        Self {
+            size,
+            skyline: vec![Segment {
+                x: UPx::ZERO,
+                y: UPx::ZERO,
+                width: size.width,
+            }],
+        }
+    }
+
+    /// Returns the size of the bin being packed into.
+    #[must_use]
+    pub const fn size(&self) -> Size<UPx> {
+        self.size
+    }
+
+    /// Places a rectangle of `size` in the bin, returning its location.
+    ///
+    /// Returns None if no remaining space fits `size`.
+    pub fn pack(&mut self, size: Size<UPx>) -> Option<Rect<UPx>> {
+        let mut best: Option<Point<UPx>> = None;
+        for index in 0..self.skyline.len() {
+            let Some(y) = self.fit(index, size.width) else {
+                continue;
+            };
+            if y + size.height > self.size.height {
+                continue;
+            }
+            let position = Point::new(self.skyline[index].x, y);
+            if best.map_or(true, |best| {
+                position.y < best.y || (position.y == best.y && position.x < best.x)
+            }) {
+                best = Some(position);
+            }
+        }
+
+        let placement = Rect::new(best?, size);
+        self.place(placement);
+        Some(placement)
+    }
+
+    /// Grows the bin to `new_size`, keeping all existing placements.
+    ///
+    /// Returns false without modifying the bin if either dimension of
+    /// `new_size` is smaller than the current bin size.
+    pub fn grow(&mut self, new_size: Size<UPx>) -> bool {
+        if new_size.width < self.size.width || new_size.height < self.size.height {
+            return false;
+        }
+
+        if new_size.width > self.size.width {
+            self.skyline.push(Segment {
+                x: self.size.width,
+                y: UPx::ZERO,
+                width: new_size.width - self.size.width,
+            });
+            self.merge();
+        }
+        self.size = new_size;
+        true
+    }
+
+    /// Returns the y coordinate at which a rectangle `width` wide can be
+    /// placed with its left edge at the skyline segment at `index`.
+    fn fit(&self, index: usize, width: UPx) -> Option<UPx> {
+        let x = self.skyline[index].x;
+        if x + width > self.size.width {
+            return None;
+        }
+
+        let mut y = UPx::ZERO;
+        let mut remaining = width;
+        for segment in &self.skyline[index..] {
+            y = y.max(segment.y);
+            if remaining <= segment.width {
+                return Some(y);
+            }
+            remaining -= segment.width;
+        }
+        None
+    }
+
+    /// Raises the skyline over the width of `placement` to its top edge.
+    fn place(&mut self, placement: Rect<UPx>) {
+        let (left, right) = placement.extents();
+        let mut updated = Vec::with_capacity(self.skyline.len() + 2);
+        for segment in self.skyline.drain(..) {
+            let end = segment.x + segment.width;
+            if end <= left.x || segment.x >= right.x {
+                updated.push(segment);
+                continue;
+            }
+            if segment.x < left.x {
+                updated.push(Segment {
+                    x: segment.x,
+                    y: segment.y,
+                    width: left.x - segment.x,
+                });
+            }
+            if end > right.x {
+                updated.push(Segment {
+                    x: right.x,
+                    y: segment.y,
+                    width: end - right.x,
+                });
+            }
+        }
+        let index = updated.partition_point(|segment| segment.x < left.x);
+        updated.insert(
+            index,
+            Segment {
+                x: left.x,
+                y: right.y,
+                width: placement.size.width,
+            },
+        );
+        self.skyline = updated;
+        self.merge();
+    }
+
+    /// Merges adjacent skyline segments of equal height.
+    fn merge(&mut self) {
+        let mut index = 0;
+        while index + 1 < self.skyline.len() {
+            if self.skyline[index].y == self.skyline[index + 1].y {
+                let removed = self.skyline.remove(index + 1);
+                self.skyline[index].width += removed.width;
+            } else {
+                index += 1;
+            }
+        }
+    }
+}
+
+/// A horizontal span of the skyline: the region from `x` to `x + width` is
+/// filled up to `y`.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+struct Segment {
+    x: UPx,
+    y: UPx,
+    width: UPx,
+}
+
+#[test]
+fn packing() {
+    let mut packer = Packer::new(Size::squared(UPx::new(64)));
+    let mut placements = Vec::new();
+    for size in [
+        Size::new(UPx::new(32), UPx::new(16)),
+        Size::new(UPx::new(32), UPx::new(16)),
+        Size::new(UPx::new(64), UPx::new(16)),
+        Size::new(UPx::new(16), UPx::new(32)),
+    ] {
+        let placement = packer.pack(size).expect("fits");
+        assert_eq!(placement.size, size);
+        let bin = Rect::new(Point::ORIGIN, packer.size());
+        assert_eq!(bin.intersection(&placement), Some(placement));
+        for existing in &placements {
+            assert_eq!(placement.intersection(existing), None);
+        }
+        placements.push(placement);
+    }
+
+    // The first two rectangles share the bottom row.
+    assert_eq!(placements[0].origin, Point::ORIGIN);
+    assert_eq!(placements[1].origin, Point::new(UPx::new(32), UPx::ZERO));
+    // The full-width rectangle sits on top of them.
+    assert_eq!(placements[2].origin, Point::new(UPx::ZERO, UPx::new(16)));
+}
+
+#[test]
+fn pack_full() {
+    let mut packer = Packer::new(Size::squared(UPx::new(16)));
+    assert!(packer.pack(Size::squared(UPx::new(16))).is_some());
+    assert_eq!(packer.pack(Size::squared(UPx::new(1))), None);
+}
+
+#[test]
+fn pack_grow() {
+    let mut packer = Packer::new(Size::squared(UPx::new(16)));
+    let first = packer.pack(Size::squared(UPx::new(16))).expect("fits");
+    assert_eq!(packer.pack(Size::squared(UPx::new(16))), None);
+
+    assert!(!packer.grow(Size::squared(UPx::new(8))));
+    assert!(packer.grow(Size::new(UPx::new(32), UPx::new(16))));
+    let second = packer.pack(Size::squared(UPx::new(16))).expect("fits");
+    assert_eq!(second.origin, Point::new(UPx::new(16), UPx::ZERO));
+    assert_eq!(first.intersection(&second), None);
+}
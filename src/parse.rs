@@ -0,0 +1,244 @@
+//! Parsing for unit strings.
+//!
+//! This module implements [`FromStr`] for the measurement types, accepting the
+//! suffixes commonly found in configuration files and style sheets:
+//!
+//! | Type | Accepted suffixes |
+//! |------|-------------------|
+//! | [`Px`] | `px`, or no suffix |
+//! | [`UPx`] | `px`, or no suffix |
+//! | [`Lp`] | `lp`, `px` (CSS pixels), `in`, `pt`, `pc`, `cm`, `mm`, or no suffix |
+//! | [`Angle`] | `deg`, `°`, `rad`, `turn`, `grad`, or no suffix (degrees) |
+//! | [`Fraction`] | `numerator/denominator`, a whole number, or a decimal |
+//!
+//! ```rust
+//! use figures::units::{Lp, Px};
+//! use figures::{Angle, Fraction};
+//!
+//! assert_eq!("3.5px".parse(), Ok(Px::new(3) + Px::from(0.5)));
+//! assert_eq!("1in".parse(), Ok(Lp::inches(1)));
+//! assert_eq!("45deg".parse(), Ok(Angle::degrees(45)));
+//! assert_eq!("1/3".parse(), Ok(Fraction::new(1, 3)));
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::units::{Lp, Px, UPx};
+use crate::{Angle, Fraction};
+
+/// An error from parsing a unit string.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ParseError {
+    /// The string contained no number to parse.
+    Empty,
+    /// The numeric portion could not be parsed, or is out of range for the
+    /// type being parsed.
+    InvalidNumber,
+    /// The unit suffix is not recognized for the type being parsed.
+    UnknownUnit(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("no number to parse"),
+            Self::InvalidNumber => f.write_str("invalid number"),
+            Self::UnknownUnit(unit) => write!(f, "unknown unit {unit:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Splits `s` into its numeric portion and its unit suffix.
+fn split_number(s: &str) -> Result<(f32, &str), ParseError> {
+    let s = s.trim();
+    let suffix_start = s
+        .find(|ch: char| !matches!(ch, '0'..='9' | '.' | '-' | '+'))
+        .unwrap_or(s.len());
+    let (number, suffix) = s.split_at(suffix_start);
+    if number.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let number = number
+        .parse::<f32>()
+        .map_err(|_| ParseError::InvalidNumber)?;
+    if number.is_finite() {
+        Ok((number, suffix.trim()))
+    } else {
+        Err(ParseError::InvalidNumber)
+    }
+}
+
+impl FromStr for Px {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_number(s)?;
+        match unit {
+            "" | "px" => Ok(Self::from(value)),
+            other => Err(ParseError::UnknownUnit(other.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Px {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for UPx {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_number(s)?;
+        if value < 0. {
+            return Err(ParseError::InvalidNumber);
+        }
+        match unit {
+            "" | "px" => Ok(Self::from(value)),
+            other => Err(ParseError::UnknownUnit(other.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for UPx {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Lp {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_number(s)?;
+        match unit {
+            // A CSS pixel is the same size as one `Lp`.
+            "" | "lp" | "px" => Ok(Self::css_px_f(value)),
+            "in" => Ok(Self::inches_f(value)),
+            "pt" => Ok(Self::points_f(value)),
+            "pc" => Ok(Self::picas_f(value)),
+            "cm" => Ok(Self::cm_f(value)),
+            "mm" => Ok(Self::mm_f(value)),
+            other => Err(ParseError::UnknownUnit(other.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Lp {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Angle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_number(s)?;
+        match unit {
+            "" | "deg" | "\u{b0}" => Ok(Self::degrees_f(value)),
+            "rad" => Ok(Self::radians_f(value)),
+            "turn" => Ok(Self::degrees_f(value * 360.)),
+            "grad" => Ok(Self::degrees_f(value * 0.9)),
+            other => Err(ParseError::UnknownUnit(other.to_string())),
+        }
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        if let Some((numerator, denominator)) = s.split_once('/') {
+            let numerator = numerator
+                .trim()
+                .parse::<i16>()
+                .map_err(|_| ParseError::InvalidNumber)?;
+            let denominator = denominator
+                .trim()
+                .parse::<i16>()
+                .map_err(|_| ParseError::InvalidNumber)?;
+            if denominator == 0 {
+                return Err(ParseError::InvalidNumber);
+            }
+            Ok(Self::new(numerator, denominator))
+        } else if let Ok(whole) = s.parse::<i16>() {
+            Ok(Self::new_whole(whole))
+        } else {
+            let value = s.parse::<f32>().map_err(|_| ParseError::InvalidNumber)?;
+            if value.is_finite() {
+                Ok(Self::from(value))
+            } else {
+                Err(ParseError::InvalidNumber)
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_px() {
+    assert_eq!("3.5px".parse(), Ok(Px::new(3) + Px::from(0.5)));
+    assert_eq!("-2".parse(), Ok(Px::new(-2)));
+    assert_eq!(" 4 px ".parse(), Ok(Px::new(4)));
+    assert_eq!("16px".parse(), Ok(UPx::new(16)));
+    assert_eq!("-1px".parse::<UPx>(), Err(ParseError::InvalidNumber));
+}
+
+#[test]
+fn parse_lp() {
+    assert_eq!("96px".parse(), Ok(Lp::inches(1)));
+    assert_eq!("1in".parse(), Ok(Lp::inches(1)));
+    assert_eq!("12pt".parse(), Ok(Lp::points(12)));
+    assert_eq!("6pc".parse(), Ok(Lp::inches(1)));
+    assert_eq!("2.5cm".parse(), Ok(Lp::mm(25)));
+    assert_eq!("10mm".parse(), Ok(Lp::cm(1)));
+    assert_eq!("42".parse(), Ok(Lp::new(42)));
+}
+
+#[test]
+fn parse_angle() {
+    assert_eq!("45deg".parse(), Ok(Angle::degrees(45)));
+    assert_eq!("45\u{b0}".parse(), Ok(Angle::degrees(45)));
+    assert_eq!("0.5turn".parse(), Ok(Angle::degrees(180)));
+    assert_eq!("100grad".parse(), Ok(Angle::degrees(90)));
+    assert_eq!(
+        "3.14159265rad".parse::<Angle>(),
+        Ok(Angle::radians_f(core::f32::consts::PI))
+    );
+}
+
+#[test]
+fn parse_fraction() {
+    assert_eq!("1/3".parse(), Ok(Fraction::new(1, 3)));
+    assert_eq!("-2 / 4".parse(), Ok(Fraction::new(-1, 2)));
+    assert_eq!("3".parse(), Ok(Fraction::new_whole(3)));
+    assert_eq!("0.5".parse(), Ok(Fraction::new(1, 2)));
+    assert_eq!("1/0".parse::<Fraction>(), Err(ParseError::InvalidNumber));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!("".parse::<Px>(), Err(ParseError::Empty));
+    assert_eq!("px".parse::<Px>(), Err(ParseError::Empty));
+    assert_eq!(
+        "1em".parse::<Px>(),
+        Err(ParseError::UnknownUnit(String::from("em")))
+    );
+    assert_eq!("--1".parse::<Px>(), Err(ParseError::InvalidNumber));
+}
@@ -0,0 +1,291 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::slice;
+
+use crate::{CubicBezier, Point, QuadraticBezier, Rect};
+
+/// A single command in a [`Path`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PathSegment<Unit> {
+    /// Begins a new subpath at the contained point.
+    MoveTo(Point<Unit>),
+    /// Draws a line from the current location to the contained point.
+    LineTo(Point<Unit>),
+    /// Draws a quadratic bezier curve from the current location to `end`.
+    QuadTo {
+        /// The control point of the curve.
+        control: Point<Unit>,
+        /// The point the curve ends at.
+        end: Point<Unit>,
+    },
+    /// Draws a cubic bezier curve from the current location to `end`.
+    CubicTo {
+        /// The control point associated with the current location.
+        control1: Point<Unit>,
+        /// The control point associated with `end`.
+        control2: Point<Unit>,
+        /// The point the curve ends at.
+        end: Point<Unit>,
+    },
+    /// Closes the current subpath, connecting the current location to the
+    /// subpath's starting point.
+    Close,
+}
+
+/// A series of line and curve segments in a 2d space.
+///
+/// A path is built by chaining drawing commands:
+///
+/// ```rust
+/// use figures::units::Px;
+/// use figures::{Path, Point};
+///
+/// let path = Path::default()
+///     .move_to(Point::new(Px::new(0), Px::new(0)))
+///     .line_to(Point::new(Px::new(10), Px::new(0)))
+///     .line_to(Point::new(Px::new(10), Px::new(10)))
+///     .close();
+/// assert_eq!(path.segments().count(), 4);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Path<Unit> {
+    segments: Vec<PathSegment<Unit>>,
+}
+
+impl<Unit> Default for Path<Unit> {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+}
+
+impl<Unit> Path<Unit> {
+    /// Begins a new subpath at `location`.
+    #[must_use]
+    pub fn move_to(mut self, location: Point<Unit>) -> Self {
+        self.segments.push(PathSegment::MoveTo(location));
+        self
+    }
+
+    /// Draws a line from the current location to `end`.
+    #[must_use]
+    pub fn line_to(mut self, end: Point<Unit>) -> Self {
+        self.segments.push(PathSegment::LineTo(end));
+        self
+    }
+
+    /// Draws a quadratic bezier curve from the current location to `end`.
+    #[must_use]
+    pub fn quad_to(mut self, control: Point<Unit>, end: Point<Unit>) -> Self {
+        self.segments.push(PathSegment::QuadTo { control, end });
+        self
+    }
+
+    /// Draws a cubic bezier curve from the current location to `end`.
+    #[must_use]
+    pub fn cubic_to(
+        mut self,
+        control1: Point<Unit>,
+        control2: Point<Unit>,
+        end: Point<Unit>,
+    ) -> Self {
+        self.segments.push(PathSegment::CubicTo {
+            control1,
+            control2,
+            end,
+        });
+        self
+    }
+
+    /// Closes the current subpath.
+    #[must_use]
+    pub fn close(mut self) -> Self {
+        self.segments.push(PathSegment::Close);
+        self
+    }
+
+    /// Returns an iterator over the segments of this path.
+    pub fn segments(&self) -> slice::Iter<'_, PathSegment<Unit>> {
+        self.segments.iter()
+    }
+
+    /// Returns an iterator over the segments of this path.
+    pub fn iter(&self) -> slice::Iter<'_, PathSegment<Unit>> {
+        self.segments.iter()
+    }
+
+    /// Returns a new path with each point transformed by `map`.
+    ///
+    /// This is the extension point for applying transformations to a path:
+    /// any affine transform can be applied by mapping each point through it.
+    #[must_use]
+    pub fn map_points<NewUnit>(
+        self,
+        mut map: impl FnMut(Point<Unit>) -> Point<NewUnit>,
+    ) -> Path<NewUnit> {
+        Path {
+            segments: self
+                .segments
+                .into_iter()
+                .map(|segment| match segment {
+                    PathSegment::MoveTo(point) => PathSegment::MoveTo(map(point)),
+                    PathSegment::LineTo(point) => PathSegment::LineTo(map(point)),
+                    PathSegment::QuadTo { control, end } => PathSegment::QuadTo {
+                        control: map(control),
+                        end: map(end),
+                    },
+                    PathSegment::CubicTo {
+                        control1,
+                        control2,
+                        end,
+                    } => PathSegment::CubicTo {
+                        control1: map(control1),
+                        control2: map(control2),
+                        end: map(end),
+                    },
+                    PathSegment::Close => PathSegment::Close,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<Unit> Path<Unit>
+where
+    Unit: crate::Unit,
+{
+    /// Returns a rectangle that contains every segment of this path, or None
+    /// if the path contains no points.
+    ///
+    /// Curve segments are measured by their control points, so the result
+    /// fully contains the path but may be larger than the tightest possible
+    /// bounds.
+    #[must_use]
+    pub fn bounding_rect(&self) -> Option<Rect<Unit>> {
+        let mut points = self.segments.iter().flat_map(PathSegment::points);
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (min.min(point), max.max(point))
+        });
+        Some(Rect::from_extents(min, max))
+    }
+}
+
+impl<Unit> PathSegment<Unit>
+where
+    Unit: Copy,
+{
+    /// Returns the points this segment contains, including control points.
+    pub fn points(&self) -> impl Iterator<Item = Point<Unit>> {
+        let points = match *self {
+            Self::MoveTo(point) | Self::LineTo(point) => vec![point],
+            Self::QuadTo { control, end } => vec![control, end],
+            Self::CubicTo {
+                control1,
+                control2,
+                end,
+            } => vec![control1, control2, end],
+            Self::Close => Vec::new(),
+        };
+        points.into_iter()
+    }
+}
+
+#[cfg(feature = "lyon")]
+impl<Unit> Path<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32> + Copy,
+{
+    /// Returns this path as a [`lyon_path::Path`], ready for tessellation.
+    #[must_use]
+    pub fn to_lyon(&self) -> lyon_path::Path {
+        let mut builder = lyon_path::Path::builder().with_svg();
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo(point) => {
+                    builder.move_to(point.into());
+                }
+                PathSegment::LineTo(point) => {
+                    builder.line_to(point.into());
+                }
+                PathSegment::QuadTo { control, end } => {
+                    builder.quadratic_bezier_to(control.into(), end.into());
+                }
+                PathSegment::CubicTo {
+                    control1,
+                    control2,
+                    end,
+                } => {
+                    builder.cubic_bezier_to(control1.into(), control2.into(), end.into());
+                }
+                PathSegment::Close => builder.close(),
+            }
+        }
+        builder.build()
+    }
+}
+
+impl<'a, Unit> IntoIterator for &'a Path<Unit> {
+    type IntoIter = slice::Iter<'a, PathSegment<Unit>>;
+    type Item = &'a PathSegment<Unit>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.segments()
+    }
+}
+
+impl<Unit> IntoIterator for Path<Unit> {
+    type IntoIter = alloc::vec::IntoIter<PathSegment<Unit>>;
+    type Item = PathSegment<Unit>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.segments.into_iter()
+    }
+}
+
+impl<Unit> From<QuadraticBezier<Unit>> for Path<Unit> {
+    fn from(curve: QuadraticBezier<Unit>) -> Self {
+        Self::default()
+            .move_to(curve.start)
+            .quad_to(curve.control, curve.end)
+    }
+}
+
+impl<Unit> From<CubicBezier<Unit>> for Path<Unit> {
+    fn from(curve: CubicBezier<Unit>) -> Self {
+        Self::default()
+            .move_to(curve.start)
+            .cubic_to(curve.control1, curve.control2, curve.end)
+    }
+}
+
+#[test]
+fn path_bounding_rect() {
+    let path = Path::default()
+        .move_to(Point::new(10, 10))
+        .line_to(Point::new(30, 10))
+        .quad_to(Point::new(40, 20), Point::new(30, 30))
+        .close();
+    assert_eq!(
+        path.bounding_rect(),
+        Some(Rect::from_extents(Point::new(10, 10), Point::new(40, 30)))
+    );
+    assert_eq!(Path::<i32>::default().bounding_rect(), None);
+}
+
+#[test]
+fn path_map_points() {
+    let path = Path::default()
+        .move_to(Point::new(0, 0))
+        .line_to(Point::new(10, 0));
+    let translated = path.map_points(|point| point + Point::new(5, 5));
+    assert_eq!(
+        translated.bounding_rect(),
+        Some(Rect::from_extents(Point::new(5, 5), Point::new(15, 5)))
+    );
+}
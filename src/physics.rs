@@ -0,0 +1,209 @@
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use core::time::Duration;
+
+use crate::traits::FloatConversion;
+use crate::{Angle, Fraction, Point, Zero};
+
+/// A rate of change, measured in `Unit` per second.
+///
+/// Multiplying a velocity by a [`Duration`] produces the displacement covered
+/// over that duration, making scroll and fling animations read like the
+/// kinematics they model:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use figures::units::Px;
+/// use figures::Velocity;
+///
+/// let velocity = Velocity::new(Px::new(100));
+/// assert_eq!(velocity * Duration::from_millis(500), Px::new(50));
+/// ```
+#[derive(Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Velocity<Unit> {
+    /// The change in position per second.
+    pub per_second: Unit,
+}
+
+impl<Unit> Velocity<Unit> {
+    /// Returns a new velocity of `per_second` units per second.
+    pub const fn new(per_second: Unit) -> Self {
+        Self { per_second }
+    }
+
+    /// Returns this velocity after `acceleration` has been applied for
+    /// `elapsed`.
+    #[must_use]
+    pub fn accelerate(self, acceleration: Acceleration<Unit>, elapsed: Duration) -> Self
+    where
+        Unit: FloatConversion<Float = f32> + Add<Output = Unit>,
+    {
+        self + acceleration * elapsed
+    }
+}
+
+impl<Unit> Mul<Duration> for Velocity<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    type Output = Unit;
+
+    fn mul(self, rhs: Duration) -> Self::Output {
+        Unit::from_float(self.per_second.into_float() * rhs.as_secs_f32())
+    }
+}
+
+impl<Unit> Add for Velocity<Unit>
+where
+    Unit: Add<Output = Unit>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.per_second + rhs.per_second)
+    }
+}
+
+impl<Unit> AddAssign for Velocity<Unit>
+where
+    Unit: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.per_second += rhs.per_second;
+    }
+}
+
+impl<Unit> Sub for Velocity<Unit>
+where
+    Unit: Sub<Output = Unit>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.per_second - rhs.per_second)
+    }
+}
+
+impl<Unit> SubAssign for Velocity<Unit>
+where
+    Unit: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.per_second -= rhs.per_second;
+    }
+}
+
+impl<Unit> Neg for Velocity<Unit>
+where
+    Unit: Neg<Output = Unit>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.per_second)
+    }
+}
+
+impl<Unit> Zero for Velocity<Unit>
+where
+    Unit: Zero,
+{
+    const ZERO: Self = Self::new(Unit::ZERO);
+
+    fn is_zero(&self) -> bool {
+        self.per_second.is_zero()
+    }
+}
+
+/// A change in [`Velocity`], measured in `Unit` per second squared.
+///
+/// Multiplying an acceleration by a [`Duration`] produces the change in
+/// velocity over that duration.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Acceleration<Unit> {
+    /// The change in velocity per second.
+    pub per_second_squared: Unit,
+}
+
+impl<Unit> Acceleration<Unit> {
+    /// Returns a new acceleration of `per_second_squared` units per second
+    /// squared.
+    pub const fn new(per_second_squared: Unit) -> Self {
+        Self { per_second_squared }
+    }
+}
+
+impl<Unit> Mul<Duration> for Acceleration<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    type Output = Velocity<Unit>;
+
+    fn mul(self, rhs: Duration) -> Self::Output {
+        Velocity::new(Unit::from_float(
+            self.per_second_squared.into_float() * rhs.as_secs_f32(),
+        ))
+    }
+}
+
+impl<Unit> Point<Unit>
+where
+    Unit: FloatConversion<Float = f32> + Add<Output = Unit> + Copy,
+{
+    /// Returns this point displaced by `velocity` applied for `elapsed`.
+    #[must_use]
+    pub fn integrate(self, velocity: Point<Velocity<Unit>>, elapsed: Duration) -> Self {
+        Self::new(self.x + velocity.x * elapsed, self.y + velocity.y * elapsed)
+    }
+}
+
+/// A rate of rotation, measured in [`Angle`] per second.
+///
+/// Multiplying an angular velocity by a [`Duration`] produces the rotation
+/// covered over that duration, normalized the same way [`Angle`] always is:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use figures::{Angle, AngularVelocity};
+///
+/// let spinner = AngularVelocity::new(Angle::degrees(90));
+/// assert_eq!(spinner * Duration::from_secs(1), Angle::degrees(90));
+/// assert_eq!(spinner * Duration::from_secs(5), Angle::degrees(90));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AngularVelocity {
+    /// The rotation per second.
+    pub per_second: Angle,
+}
+
+impl AngularVelocity {
+    /// Returns a new angular velocity of `per_second` per second.
+    #[must_use]
+    pub const fn new(per_second: Angle) -> Self {
+        Self { per_second }
+    }
+
+    /// Returns a new angular velocity of `rpm` revolutions per minute.
+    #[must_use]
+    pub fn rpm(rpm: impl Into<Fraction>) -> Self {
+        Self::new(Angle::turns(rpm.into() / 60))
+    }
+
+    /// Returns a new angular velocity of `turns` revolutions per second.
+    #[must_use]
+    pub fn turns_per_second(turns: impl Into<Fraction>) -> Self {
+        Self::new(Angle::turns(turns.into()))
+    }
+}
+
+impl Mul<Duration> for AngularVelocity {
+    type Output = Angle;
+
+    fn mul(self, rhs: Duration) -> Self::Output {
+        self.per_second * rhs
+    }
+}
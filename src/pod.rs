@@ -6,13 +6,18 @@
 //! it can't ensure that the generic types are tagged `repr(c)`. These
 //! implementations are all safe because the types being wrapped all are
 //! `repr(c)` and only contain u32/f32/i32.
+//!
+//! [`bytemuck::NoUninit`] and [`bytemuck::AnyBitPattern`] are covered by
+//! bytemuck's blanket implementations for `Pod` types.
 #![allow(unsafe_code)]
 
-use crate::units::{Lp, Px};
-use crate::{Point, Size};
+use crate::units::{FixedPx, Lp, Px, UPx};
+use crate::{Point, Rect, Size};
 
 unsafe impl bytemuck::Pod for Point<Px> {}
 unsafe impl bytemuck::Zeroable for Point<Px> {}
+unsafe impl bytemuck::Pod for Point<UPx> {}
+unsafe impl bytemuck::Zeroable for Point<UPx> {}
 unsafe impl bytemuck::Pod for Point<Lp> {}
 unsafe impl bytemuck::Zeroable for Point<Lp> {}
 unsafe impl bytemuck::Pod for Point<i32> {}
@@ -24,6 +29,8 @@ unsafe impl bytemuck::Zeroable for Point<f32> {}
 
 unsafe impl bytemuck::Pod for Size<Px> {}
 unsafe impl bytemuck::Zeroable for Size<Px> {}
+unsafe impl bytemuck::Pod for Size<UPx> {}
+unsafe impl bytemuck::Zeroable for Size<UPx> {}
 unsafe impl bytemuck::Pod for Size<Lp> {}
 unsafe impl bytemuck::Zeroable for Size<Lp> {}
 unsafe impl bytemuck::Pod for Size<i32> {}
@@ -32,3 +39,19 @@ unsafe impl bytemuck::Pod for Size<u32> {}
 unsafe impl bytemuck::Zeroable for Size<u32> {}
 unsafe impl bytemuck::Pod for Size<f32> {}
 unsafe impl bytemuck::Zeroable for Size<f32> {}
+
+unsafe impl bytemuck::Pod for Rect<Px> {}
+unsafe impl bytemuck::Zeroable for Rect<Px> {}
+unsafe impl bytemuck::Pod for Rect<UPx> {}
+unsafe impl bytemuck::Zeroable for Rect<UPx> {}
+unsafe impl bytemuck::Pod for Rect<Lp> {}
+unsafe impl bytemuck::Zeroable for Rect<Lp> {}
+unsafe impl bytemuck::Pod for Rect<i32> {}
+unsafe impl bytemuck::Zeroable for Rect<i32> {}
+unsafe impl bytemuck::Pod for Rect<u32> {}
+unsafe impl bytemuck::Zeroable for Rect<u32> {}
+unsafe impl bytemuck::Pod for Rect<f32> {}
+unsafe impl bytemuck::Zeroable for Rect<f32> {}
+
+unsafe impl<const SCALE: u32> bytemuck::Pod for FixedPx<SCALE> {}
+unsafe impl<const SCALE: u32> bytemuck::Zeroable for FixedPx<SCALE> {}
@@ -1,12 +1,37 @@
-use std::ops::{Add, Mul, Sub};
+use core::cmp::Ordering;
+use core::ops::{Add, Mul, Neg, Sub};
 
-use crate::traits::{IntoComponents, Roots, StdNumOps};
+use intentional::{Cast, CastFrom, CastInto};
+
+use crate::traits::{
+    CheckedNumOps, FloatConversion, IntoComponents, Roots, StdNumOps, UnscaledUnit, WideMul,
+};
 use crate::utils::vec_ord;
+// The test harness links `std`, which makes the inherent float methods
+// available even without the `std` feature enabled.
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::utils::FloatExt;
 use crate::{Angle, Fraction, Zero};
 
+/// The direction a sequence of three points turns.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Orientation {
+    /// The points turn clockwise.
+    ///
+    /// This crate uses screen coordinates, where the y axis points down. A
+    /// positive cross product is a clockwise turn in this coordinate space.
+    Clockwise,
+    /// The points turn counter-clockwise.
+    CounterClockwise,
+    /// The points lie on a single line.
+    Collinear,
+}
+
 /// A coordinate in a 2d space.
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[repr(C)]
 pub struct Point<Unit> {
     /// The x-axis component.
     pub x: Unit,
@@ -14,6 +39,14 @@ pub struct Point<Unit> {
     pub y: Unit,
 }
 
+impl<Unit> Point<Unit>
+where
+    Unit: Zero,
+{
+    /// A point at 0,0, the top-left corner of the coordinate space.
+    pub const ORIGIN: Self = Self::new(Unit::ZERO, Unit::ZERO);
+}
+
 impl<Unit> Point<Unit> {
     /// Returns a new point with the provided `x` and `y` components.
     pub const fn new(x: Unit, y: Unit) -> Self {
@@ -45,7 +78,7 @@ impl<Unit> Point<Unit> {
     ///
     /// Returns `<NewUnit as TryFrom>::Error` when the inner type cannot be
     /// converted. For this crate's types, this genenerally will be
-    /// [`TryFromIntError`](std::num::TryFromIntError).
+    /// [`TryFromIntError`](core::num::TryFromIntError).
     pub fn try_cast<NewUnit>(self) -> Result<Point<NewUnit>, Unit::Error>
     where
         Unit: TryInto<NewUnit>,
@@ -56,6 +89,62 @@ impl<Unit> Point<Unit> {
         })
     }
 
+    /// Returns a new point with the `x` and `y` components swapped.
+    #[must_use]
+    pub fn swap_axes(self) -> Self {
+        Self::new(self.y, self.x)
+    }
+
+    /// Returns a new point with the `x` component negated.
+    #[must_use]
+    pub fn flip_x(self) -> Self
+    where
+        Unit: Neg<Output = Unit>,
+    {
+        Self::new(-self.x, self.y)
+    }
+
+    /// Returns a new point with the `y` component negated.
+    #[must_use]
+    pub fn flip_y(self) -> Self
+    where
+        Unit: Neg<Output = Unit>,
+    {
+        Self::new(self.x, -self.y)
+    }
+
+    /// Returns this point converted between a top-left-origin coordinate
+    /// space and a bottom-left-origin coordinate space, given the `height` of
+    /// the containing area.
+    ///
+    /// The `y` component becomes `height - y`, while the `x` component is
+    /// unchanged. Applying this conversion twice returns the original point.
+    /// This is useful when interoperating with bottom-left-origin systems
+    /// such as OpenGL or PDF.
+    #[must_use]
+    pub fn flip_vertically_within(self, height: Unit) -> Self
+    where
+        Unit: Sub<Output = Unit>,
+    {
+        Self::new(self.x, height - self.y)
+    }
+
+    /// Returns this point clamped so that it lies within `rect`.
+    ///
+    /// Components are clamped to the rectangle's edges inclusively, so the
+    /// result may lie on the rectangle's boundary.
+    #[must_use]
+    pub fn clamp_within(self, rect: crate::Rect<Unit>) -> Self
+    where
+        Unit: Add<Output = Unit> + Ord + Copy,
+    {
+        let (top_left, bottom_right) = rect.extents();
+        Self::new(
+            self.x.clamp(top_left.x, bottom_right.x),
+            self.y.clamp(top_left.y, bottom_right.y),
+        )
+    }
+
     /// Maps each component to `map` and returns a new value with the mapped
     /// components.
     pub fn map<NewUnit>(self, mut map: impl FnMut(Unit) -> NewUnit) -> Point<NewUnit> {
@@ -83,6 +172,58 @@ impl<Unit> Point<Unit> {
         (self.x * self.x + self.y * self.y).sqrt()
     }
 
+    /// Returns the componentwise minimum and maximum of `self` and `other` as
+    /// a `(minimum, maximum)` pair.
+    ///
+    /// This is useful for normalizing two corners of a selection or drag
+    /// gesture into the top-left and bottom-right of a rectangle.
+    #[must_use]
+    pub fn min_max(self, other: Self) -> (Self, Self)
+    where
+        Unit: Ord + Copy,
+    {
+        (
+            Self {
+                x: self.x.min(other.x),
+                y: self.y.min(other.y),
+            },
+            Self {
+                x: self.x.max(other.x),
+                y: self.y.max(other.y),
+            },
+        )
+    }
+
+    /// Returns the distance between `self` and `other`.
+    ///
+    /// The calculation is performed using 64-bit floats, which avoids the
+    /// overflow that squaring large integer units with [`Point::magnitude`]
+    /// can cause.
+    #[must_use]
+    pub fn distance_to(self, other: Self) -> Unit
+    where
+        Unit: FloatConversion<Float64 = f64> + Copy,
+    {
+        let dx = self.x.into_float64() - other.x.into_float64();
+        let dy = self.y.into_float64() - other.y.into_float64();
+        Unit::from_float64(dx.hypot(dy))
+    }
+
+    /// Returns the squared distance between `self` and `other`, measured in
+    /// squared logical units.
+    ///
+    /// The squares are computed as 64-bit floats, so this function will not
+    /// overflow for large integer units.
+    #[must_use]
+    pub fn distance_squared_to(self, other: Self) -> f64
+    where
+        Unit: FloatConversion<Float64 = f64> + Copy,
+    {
+        let dx = self.x.into_float64() - other.x.into_float64();
+        let dy = self.y.into_float64() - other.y.into_float64();
+        dx * dx + dy * dy
+    }
+
     /// Returns `self` rotated around `origin` by `angle`.
     #[must_use]
     pub fn rotate_around(self, origin: Point<Unit>, angle: Angle) -> Point<Unit>
@@ -105,11 +246,306 @@ impl<Unit> Point<Unit> {
     }
 }
 
+impl<Unit> Point<Unit>
+where
+    Unit: UnscaledUnit + Copy,
+{
+    /// Returns the turn formed by traveling from `a` through `b` to `c`.
+    ///
+    /// This predicate is computed exactly by widening the unscaled integer
+    /// representations to 64 bits, making it safe to use for convex hulls,
+    /// polygon tests, and segment intersection without worrying about
+    /// overflow or fixed-point precision loss.
+    ///
+    /// ```rust
+    /// use figures::units::Px;
+    /// use figures::{Orientation, Point};
+    ///
+    /// let a = Point::new(Px::new(0), Px::new(0));
+    /// let b = Point::new(Px::new(10), Px::new(0));
+    /// let c = Point::new(Px::new(10), Px::new(10));
+    /// assert_eq!(Point::orientation(a, b, c), Orientation::Clockwise);
+    /// assert_eq!(Point::orientation(c, b, a), Orientation::CounterClockwise);
+    /// assert_eq!(
+    ///     Point::orientation(a, b, Point::new(Px::new(20), Px::new(0))),
+    ///     Orientation::Collinear
+    /// );
+    /// ```
+    #[must_use]
+    pub fn orientation(a: Self, b: Self, c: Self) -> Orientation {
+        let cross = (widen(b.x) - widen(a.x)) * (widen(c.y) - widen(a.y))
+            - (widen(b.y) - widen(a.y)) * (widen(c.x) - widen(a.x));
+        match cross.cmp(&0) {
+            Ordering::Greater => Orientation::Clockwise,
+            Ordering::Less => Orientation::CounterClockwise,
+            Ordering::Equal => Orientation::Collinear,
+        }
+    }
+
+    /// Returns the point halfway between `self` and `other`.
+    ///
+    /// The math is performed with widened integers, so coordinates near the
+    /// limits of the unit's range cannot overflow.
+    #[must_use]
+    pub fn midpoint(self, other: Self) -> Self
+    where
+        Unit::Representation: CastFrom<i32>,
+    {
+        Self::new(
+            unwiden((widen(self.x) + widen(other.x)) / 2),
+            unwiden((widen(self.y) + widen(other.y)) / 2),
+        )
+    }
+}
+
+/// Returns the centroid of `points`, or None if `points` is empty.
+///
+/// The coordinates are summed with widened integers, so large collections of
+/// large coordinates cannot overflow the unit's internal representation.
+pub fn centroid<Unit>(points: &[Point<Unit>]) -> Option<Point<Unit>>
+where
+    Unit: UnscaledUnit + Copy,
+    Unit::Representation: CastFrom<i32>,
+{
+    if points.is_empty() {
+        return None;
+    }
+    let mut x = 0_i64;
+    let mut y = 0_i64;
+    for point in points {
+        x += widen(point.x);
+        y += widen(point.y);
+    }
+    let count = i64::from_cast(points.len());
+    Some(Point::new(unwiden(x / count), unwiden(y / count)))
+}
+
+/// Returns the line best fitting `points` by orthogonal least squares, or
+/// None if fewer than two points are provided.
+///
+/// The line is described by the angle of its dominant direction, normalized
+/// to `0..180` degrees, and its signed perpendicular offset from the origin.
+/// The covariance terms are accumulated with widened integers, so large
+/// collections of large coordinates cannot overflow.
+///
+/// ```rust
+/// use figures::units::Px;
+/// use figures::{fit_line, Angle, Point};
+///
+/// let swipe = [
+///     Point::new(Px::new(0), Px::new(10)),
+///     Point::new(Px::new(50), Px::new(10)),
+///     Point::new(Px::new(100), Px::new(10)),
+/// ];
+/// let (angle, offset) = fit_line(&swipe).expect("enough points");
+/// assert_eq!(angle, Angle::degrees(0));
+/// assert_eq!(offset, Px::new(10));
+/// ```
+pub fn fit_line<Unit>(points: &[Point<Unit>]) -> Option<(Angle, Unit)>
+where
+    Unit: UnscaledUnit + FloatConversion<Float = f32> + Copy,
+    Unit::Representation: CastFrom<i32>,
+{
+    if points.len() < 2 {
+        return None;
+    }
+    let center = centroid(points)?;
+    let mut xx = 0_i128;
+    let mut xy = 0_i128;
+    let mut yy = 0_i128;
+    for point in points {
+        let dx = i128::from(widen(point.x) - widen(center.x));
+        let dy = i128::from(widen(point.y) - widen(center.y));
+        xx += dx * dx;
+        xy += dx * dy;
+        yy += dy * dy;
+    }
+    // The dominant direction of the covariance matrix is half the angle of
+    // the doubled covariance vector.
+    #[allow(clippy::cast_precision_loss)]
+    let radians = (2. * xy as f64).atan2((xx - yy) as f64) / 2.;
+    let mut degrees = radians.to_degrees().cast::<f32>();
+    if degrees < 0. {
+        degrees += 180.;
+    }
+    let angle = Angle::degrees_f(degrees);
+    let normal = Point::new(-angle.sin(), angle.cos());
+    let offset =
+        center.x.into_float() * normal.x.into_f32() + center.y.into_float() * normal.y.into_f32();
+    Some((angle, Unit::from_float(offset)))
+}
+
+fn widen<Unit>(value: Unit) -> i64
+where
+    Unit: UnscaledUnit,
+{
+    i64::from(value.into_unscaled().cast_into())
+}
+
+fn unwiden<Unit>(value: i64) -> Unit
+where
+    Unit: UnscaledUnit,
+    Unit::Representation: CastFrom<i32>,
+{
+    Unit::from_unscaled(Unit::Representation::from_cast(value.cast::<i32>()))
+}
+
+impl Point<f32> {
+    /// Returns true if both components are neither infinite nor NaN.
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns true if either component is NaN.
+    #[must_use]
+    pub fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Returns this point with any NaN component replaced with `default`.
+    #[must_use]
+    pub fn replace_nan(self, default: f32) -> Self {
+        self.map(|value| if value.is_nan() { default } else { value })
+    }
+
+    /// Returns this point's components as canonical bit patterns, suitable
+    /// for hashing.
+    ///
+    /// `Point<f32>` cannot implement `Hash` directly because equal floats
+    /// can have different bit patterns. The returned bits normalize negative
+    /// zero to positive zero and collapse every NaN to one bit pattern, so
+    /// points that compare equal produce equal bits, and any two NaN
+    /// components are treated as equal. This allows float geometry to key
+    /// render caches.
+    ///
+    /// ```rust
+    /// use figures::Point;
+    ///
+    /// assert_eq!(
+    ///     Point::new(-0.0, f32::NAN).canonical_bits(),
+    ///     Point::new(0.0, -f32::NAN).canonical_bits(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn canonical_bits(self) -> [u32; 2] {
+        [
+            crate::utils::canonical_f32_bits(self.x),
+            crate::utils::canonical_f32_bits(self.y),
+        ]
+    }
+
+    /// Returns this vector scaled to a magnitude of 1.
+    ///
+    /// The zero vector has no direction and is returned unchanged. The result
+    /// is accurate to within a few [ULP]s of the infinitely-precise answer:
+    /// the magnitude is computed with `f32` math, which rounds each of the
+    /// three operations involved.
+    ///
+    /// [ULP]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude == 0. {
+            self
+        } else {
+            self / magnitude
+        }
+    }
+}
+
+/// Normalizes each point in `vectors` to a magnitude of 1 using
+/// [`Point::normalized`].
+///
+/// The loop is written to allow the compiler to vectorize it, making this
+/// the preferred way to normalize large batches of vectors, such as the edge
+/// normals computed when extruding a stroked polyline. The accuracy of each
+/// result matches [`Point::normalized`].
+pub fn normalize_all(vectors: &mut [Point<f32>]) {
+    for vector in vectors {
+        *vector = vector.normalized();
+    }
+}
+
+impl Point<crate::units::Em> {
+    /// Resolves this font-relative point against `font_size`.
+    ///
+    /// See [`Em::resolve`](crate::units::Em::resolve) for the resolution
+    /// rules.
+    #[must_use]
+    pub fn resolve(self, font_size: crate::units::Lp) -> Point<crate::units::Lp> {
+        self.map(|component| component.resolve(font_size))
+    }
+}
+
+impl Point<crate::units::UPx> {
+    /// Returns the componentwise positive difference between `self` and
+    /// `other`.
+    ///
+    /// Unlike subtraction, this cannot overflow when a component of `other`
+    /// is larger than the matching component of `self`.
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Self {
+        Self::new(self.x.abs_diff(other.x), self.y.abs_diff(other.y))
+    }
+
+    /// Returns `self - other` with signed components.
+    ///
+    /// Deltas too large for [`Px`](crate::units::Px) are saturated to its
+    /// range.
+    #[must_use]
+    pub fn signed_delta(self, other: Self) -> Point<crate::units::Px> {
+        Point::new(self.x.signed_delta(other.x), self.y.signed_delta(other.y))
+    }
+}
+
+impl Point<crate::units::Lp> {
+    /// Converts this point into device pixels using the provided `scale`
+    /// factor, returning the converted point and the maximum per-component
+    /// rounding error as a [`Px`](crate::units::Px) value.
+    #[must_use]
+    pub fn into_px_with_error(
+        self,
+        scale: Fraction,
+    ) -> (Point<crate::units::Px>, crate::units::Px) {
+        let (x, x_error) = self.x.into_px_with_error(scale);
+        let (y, y_error) = self.y.into_px_with_error(scale);
+        (Point::new(x, y), x_error.max(y_error))
+    }
+}
+
+impl<Unit> core::iter::Sum for Point<Unit>
+where
+    Unit: Zero + Add<Output = Unit>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, Unit> core::iter::Sum<&'a Point<Unit>> for Point<Unit>
+where
+    Unit: Zero + Add<Output = Unit> + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<Unit> core::fmt::Display for Point<Unit>
+where
+    Unit: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
 impl<Unit> Ord for Point<Unit>
 where
-    Unit: Ord + Copy + Mul<Output = Unit>,
+    Unit: Ord + Copy + WideMul,
 {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         vec_ord::<Unit>((*self).into_components(), (*other).into_components())
     }
 
@@ -146,9 +582,9 @@ where
 
 impl<Unit> PartialOrd for Point<Unit>
 where
-    Unit: Ord + Copy + Mul<Output = Unit>,
+    Unit: Ord + Copy + WideMul,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -175,6 +611,55 @@ where
     }
 }
 
+// `lyon_path::math::Point` is a re-exported `euclid::Point2D`, so when the
+// `euclid` feature is enabled, its impls already cover lyon interop.
+#[cfg(all(feature = "lyon", not(feature = "euclid")))]
+impl<Unit> From<lyon_path::math::Point> for Point<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(point: lyon_path::math::Point) -> Self {
+        Self {
+            x: Unit::from_float(point.x),
+            y: Unit::from_float(point.y),
+        }
+    }
+}
+#[cfg(all(feature = "lyon", not(feature = "euclid")))]
+impl<Unit> From<Point<Unit>> for lyon_path::math::Point
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(point: Point<Unit>) -> Self {
+        Self::new(point.x.into_float(), point.y.into_float())
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<Unit> From<mint::Point2<f32>> for Point<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(point: mint::Point2<f32>) -> Self {
+        Self {
+            x: Unit::from_float(point.x),
+            y: Unit::from_float(point.y),
+        }
+    }
+}
+#[cfg(feature = "mint")]
+impl<Unit> From<Point<Unit>> for mint::Point2<f32>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(point: Point<Unit>) -> Self {
+        Self {
+            x: point.x.into_float(),
+            y: point.y.into_float(),
+        }
+    }
+}
+
 #[cfg(feature = "winit")]
 impl<Unit> From<winit::dpi::PhysicalPosition<f64>> for Point<Unit>
 where
@@ -208,6 +693,44 @@ impl From<winit::dpi::PhysicalPosition<u32>> for Point<crate::units::UPx> {
     }
 }
 
+#[cfg(feature = "winit")]
+impl<Unit> Point<Unit>
+where
+    Unit: crate::traits::ScreenScale<
+        Lp = crate::units::Lp,
+        Px = crate::units::Px,
+        UPx = crate::units::UPx,
+    >,
+{
+    /// Returns `position` converted into this unit using the provided `scale`
+    /// factor.
+    ///
+    /// Winit's logical units are device-independent pixels, the same size as
+    /// [`Lp`](crate::units::Lp)'s logical pixels.
+    #[must_use]
+    pub fn from_logical(position: winit::dpi::LogicalPosition<f64>, scale: Fraction) -> Self {
+        Self {
+            x: Unit::from_lp(
+                crate::units::Lp::from_float(intentional::CastFrom::from_cast(position.x)),
+                scale,
+            ),
+            y: Unit::from_lp(
+                crate::units::Lp::from_float(intentional::CastFrom::from_cast(position.y)),
+                scale,
+            ),
+        }
+    }
+
+    /// Returns this point converted into a winit logical position using the
+    /// provided `scale` factor.
+    pub fn to_logical(self, scale: Fraction) -> winit::dpi::LogicalPosition<f64> {
+        winit::dpi::LogicalPosition::new(
+            f64::from(self.x.into_lp(scale).into_float()),
+            f64::from(self.y.into_lp(scale).into_float()),
+        )
+    }
+}
+
 #[cfg(feature = "winit")]
 impl From<Point<crate::units::Px>> for winit::dpi::PhysicalPosition<i32> {
     fn from(point: Point<crate::units::Px>) -> Self {
@@ -229,6 +752,9 @@ impl From<Point<crate::units::UPx>> for winit::dpi::PhysicalPosition<u32> {
 }
 
 impl_2d_math!(Point, x, y);
+impl_2d_const_math!(Point, x, y, Px);
+impl_2d_const_math!(Point, x, y, UPx);
+impl_2d_const_math!(Point, x, y, Lp);
 
 #[cfg(feature = "wgpu")]
 impl From<Point<crate::units::UPx>> for wgpu::Origin3d {
@@ -241,6 +767,39 @@ impl From<Point<crate::units::UPx>> for wgpu::Origin3d {
     }
 }
 
+impl<T> CheckedNumOps for Point<T>
+where
+    T: CheckedNumOps,
+{
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(other.x)?,
+            self.y.checked_mul(other.y)?,
+        ))
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_div(other.x)?,
+            self.y.checked_div(other.y)?,
+        ))
+    }
+}
+
 impl<T> StdNumOps for Point<T>
 where
     T: StdNumOps,
@@ -1,5 +1,5 @@
-use std::iter::Peekable;
-use std::slice;
+use core::iter::Peekable;
+use core::slice;
 
 pub struct FactorsOf {
     factoring: i32,
@@ -297,8 +297,9 @@ pub static PRIMES: [i16; 3512] = [
 /// This test just finds all primes (in a dumb fashion) and prints each out on
 /// its own line with a comma. The above list of primes was generated using this
 /// test.
+#[cfg(feature = "std")]
 #[test]
-#[ignore]
+#[ignore = "generator for the PRIMES table"]
 fn gen_primes() {
     let mut primes = vec![];
     for i in 2..i16::MAX.unsigned_abs() {
@@ -311,6 +312,8 @@ fn gen_primes() {
 
 #[test]
 fn factors() {
+    use alloc::vec::Vec;
+
     // Non-repeating factors
     assert_eq!(FactorsOf::new(42_i32).collect::<Vec<_>>(), &[2, 3, 7]);
     // Repeating factors
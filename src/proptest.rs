@@ -0,0 +1,106 @@
+//! Proptest strategies for the geometry types.
+//!
+//! With the `proptest` feature enabled, the unit types and the 2d types
+//! implement [`Arbitrary`]. The unit strategies accept an optional inclusive
+//! range to constrain the generated values, and the 2d strategies forward
+//! their unit's parameters to each component.
+
+use core::ops::RangeInclusive;
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::traits::UnscaledUnit;
+use crate::units::{Lp, Px, UPx};
+use crate::{Angle, Fraction, Point, Rect, Size};
+
+macro_rules! impl_unit_arbitrary {
+    ($unit:ident, $inner:ty) => {
+        impl Arbitrary for $unit {
+            type Parameters = Option<RangeInclusive<$unit>>;
+            type Strategy = BoxedStrategy<$unit>;
+
+            fn arbitrary_with(range: Self::Parameters) -> Self::Strategy {
+                let (start, end) = range.map_or((<$inner>::MIN, <$inner>::MAX), |range| {
+                    (range.start().into_unscaled(), range.end().into_unscaled())
+                });
+                (start..=end).prop_map($unit::from_unscaled).boxed()
+            }
+        }
+    };
+}
+
+impl_unit_arbitrary!(Px, i32);
+impl_unit_arbitrary!(UPx, u32);
+impl_unit_arbitrary!(Lp, i32);
+
+impl Arbitrary for Fraction {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Fraction>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (proptest::num::i16::ANY, 1..=i16::MAX)
+            .prop_map(|(numerator, denominator)| Fraction::new(numerator, denominator))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Angle {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Angle>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (0f32..360f32).prop_map(Angle::degrees_f).boxed()
+    }
+}
+
+impl<Unit> Arbitrary for Point<Unit>
+where
+    Unit: Arbitrary + 'static,
+    Unit::Parameters: Clone,
+    Unit::Strategy: 'static,
+{
+    type Parameters = Unit::Parameters;
+    type Strategy = BoxedStrategy<Point<Unit>>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (any_with::<Unit>(args.clone()), any_with::<Unit>(args))
+            .prop_map(|(x, y)| Point::new(x, y))
+            .boxed()
+    }
+}
+
+impl<Unit> Arbitrary for Size<Unit>
+where
+    Unit: Arbitrary + 'static,
+    Unit::Parameters: Clone,
+    Unit::Strategy: 'static,
+{
+    type Parameters = Unit::Parameters;
+    type Strategy = BoxedStrategy<Size<Unit>>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (any_with::<Unit>(args.clone()), any_with::<Unit>(args))
+            .prop_map(|(width, height)| Size::new(width, height))
+            .boxed()
+    }
+}
+
+impl<Unit> Arbitrary for Rect<Unit>
+where
+    Unit: Arbitrary + 'static,
+    Unit::Parameters: Clone,
+    Unit::Strategy: 'static,
+{
+    type Parameters = Unit::Parameters;
+    type Strategy = BoxedStrategy<Rect<Unit>>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (
+            any_with::<Point<Unit>>(args.clone()),
+            any_with::<Size<Unit>>(args),
+        )
+            .prop_map(|(origin, size)| Rect::new(origin, size))
+            .boxed()
+    }
+}
@@ -0,0 +1,68 @@
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::traits::UnscaledUnit;
+use crate::units::{Lp, Px, UPx};
+use crate::{Angle, Point, Rect, Size};
+
+macro_rules! impl_unit_distribution {
+    ($unit:ident) => {
+        impl Distribution<$unit> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $unit {
+                $unit::from_unscaled(rng.gen())
+            }
+        }
+    };
+}
+
+impl_unit_distribution!(Px);
+impl_unit_distribution!(UPx);
+impl_unit_distribution!(Lp);
+
+impl Distribution<Angle> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle {
+        Angle::degrees_f(rng.gen::<f32>() * 360.)
+    }
+}
+
+impl<Unit> Distribution<Point<Unit>> for Standard
+where
+    Standard: Distribution<Unit>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point<Unit> {
+        Point::new(rng.gen(), rng.gen())
+    }
+}
+
+impl<Unit> Distribution<Size<Unit>> for Standard
+where
+    Standard: Distribution<Unit>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Size<Unit> {
+        Size::new(rng.gen(), rng.gen())
+    }
+}
+
+impl<Unit> Rect<Unit>
+where
+    Unit: UnscaledUnit + core::ops::Add<Output = Unit> + Ord + Copy,
+    Unit::Representation: SampleUniform + PartialOrd,
+{
+    /// Returns a point sampled uniformly from within this rectangle.
+    ///
+    /// The sampling is performed on the units' unscaled representations, so
+    /// every representable location inside the rectangle, including subpixel
+    /// locations, is equally likely. Both edges are inclusive.
+    pub fn random_point_inside<R: Rng + ?Sized>(&self, rng: &mut R) -> Point<Unit> {
+        let (top_left, bottom_right) = self.extents();
+        Point::new(
+            Unit::from_unscaled(
+                rng.gen_range(top_left.x.into_unscaled()..=bottom_right.x.into_unscaled()),
+            ),
+            Unit::from_unscaled(
+                rng.gen_range(top_left.y.into_unscaled()..=bottom_right.y.into_unscaled()),
+            ),
+        )
+    }
+}
@@ -1,11 +1,23 @@
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 
-use crate::traits::{IntoSigned, IntoUnsigned, Ranged, StdNumOps};
-use crate::{FloatConversion, IntoComponents, Point, Round, Size, Zero};
+use intentional::{Cast, CastInto};
+
+use crate::traits::{
+    CheckedNumOps, ConvertUnit, IntoSigned, IntoUnsigned, Ranged, ScreenScale, StdNumOps,
+    UnscaledUnit,
+};
+use crate::units::{Lp, Px, UPx};
+use crate::{
+    Alignment, Angle, FloatConversion, Fraction, IntoComponents, Point, Round, Size, Zero,
+};
 
 /// A 2d area expressed as an origin ([`Point`]) and a [`Size`].
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[repr(C)]
 pub struct Rect<Unit> {
     /// The origin of the rectangle
     pub origin: Point<Unit>,
@@ -13,6 +25,23 @@ pub struct Rect<Unit> {
     pub size: Size<Unit>,
 }
 
+impl<Unit> Rect<Unit>
+where
+    Unit: Zero,
+{
+    /// A rectangle at the origin with no area.
+    pub const EMPTY: Self = Self::new(Point::ORIGIN, Size::EMPTY);
+
+    /// Returns true if this rectangle has no area.
+    ///
+    /// Unlike [`Zero::is_zero`], which requires every component to be zero,
+    /// this function only considers the size: a rectangle with a non-zero
+    /// origin and a zero width or height is empty, but not zero.
+    pub fn is_empty(&self) -> bool {
+        self.size.width.is_zero() || self.size.height.is_zero()
+    }
+}
+
 impl<Unit> Rect<Unit> {
     /// Returns a new rectangle.
     pub const fn new(origin: Point<Unit>, size: Size<Unit>) -> Self {
@@ -41,6 +70,61 @@ impl<Unit> Rect<Unit> {
         }
     }
 
+    /// Returns a new rectangle of `size` positioned so that `center` is its
+    /// center.
+    ///
+    /// For integer units, odd dimensions place the extra unit below and to
+    /// the right of the center.
+    ///
+    /// ```rust
+    /// use figures::{Point, Rect, Size};
+    ///
+    /// assert_eq!(
+    ///     Rect::from_center(Point::new(10, 10), Size::new(5, 4)),
+    ///     Rect::new(Point::new(8, 8), Size::new(5, 4))
+    /// );
+    /// ```
+    pub fn from_center(center: Point<Unit>, size: Size<Unit>) -> Self
+    where
+        Unit: crate::Unit + core::ops::Mul<Fraction, Output = Unit>,
+    {
+        Self::new(
+            center - Point::new(size.width, size.height) * Fraction::new(1, 2),
+            size,
+        )
+    }
+
+    /// Returns the point at the center of this rectangle.
+    #[must_use]
+    pub fn center(self) -> Point<Unit>
+    where
+        Unit: crate::Unit + core::ops::Mul<Fraction, Output = Unit>,
+    {
+        self.origin + Point::new(self.size.width, self.size.height) * Fraction::new(1, 2)
+    }
+
+    /// Returns this rectangle expanded by `amount` in each dimension while
+    /// preserving its center.
+    #[must_use]
+    pub fn centered_expand(self, amount: Size<Unit>) -> Self
+    where
+        Unit: crate::Unit + core::ops::Mul<Fraction, Output = Unit>,
+    {
+        Self::new(
+            self.origin - Point::new(amount.width, amount.height) * Fraction::new(1, 2),
+            self.size + amount,
+        )
+    }
+
+    /// Returns a rectangle of `size` sharing this rectangle's center.
+    #[must_use]
+    pub fn with_size_centered(self, size: Size<Unit>) -> Self
+    where
+        Unit: crate::Unit + core::ops::Mul<Fraction, Output = Unit>,
+    {
+        Self::from_center(self.center(), size)
+    }
+
     /// Expands this rect to the nearest whole number.
     ///
     /// This function will never return a smaller rectangle.
@@ -64,6 +148,65 @@ impl<Unit> Rect<Unit> {
         }
     }
 
+    /// Returns a new rectangle with the x and y axes swapped.
+    ///
+    /// Both the origin and the size are transposed, mirroring the rectangle
+    /// across the line `y = x`. This is useful when rotating a layout between
+    /// portrait and landscape orientations.
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        Self::new(self.origin.swap_axes(), self.size.transpose())
+    }
+
+    /// Returns this rectangle converted between a top-left-origin coordinate
+    /// space and a bottom-left-origin coordinate space, given the `height` of
+    /// the containing area.
+    ///
+    /// The returned rectangle covers the same area, with its origin still
+    /// naming the corner closest to the new coordinate space's origin.
+    /// Applying this conversion twice returns the original rectangle. This is
+    /// useful when interoperating with bottom-left-origin systems such as
+    /// OpenGL or PDF.
+    #[must_use]
+    pub fn flip_vertically_within(self, height: Unit) -> Self
+    where
+        Unit: Add<Output = Unit> + Sub<Output = Unit> + Copy,
+    {
+        Self::new(
+            Point::new(self.origin.x, height - (self.origin.y + self.size.height)),
+            self.size,
+        )
+    }
+
+    /// Returns this rectangle translated, and shrunk if necessary, so that
+    /// it lies within `container`.
+    ///
+    /// The rectangle is first moved the minimal distance that places it
+    /// inside of `container`. If it is larger than `container` on either
+    /// axis, that dimension is then clamped to the container's size. This is
+    /// useful for keeping popups and drag handles on-screen.
+    #[must_use]
+    pub fn clamp_within(self, container: Self) -> Self
+    where
+        Unit: Add<Output = Unit> + Sub<Output = Unit> + Ord + Copy,
+    {
+        let (top_left, bottom_right) = self.extents();
+        let (container_top_left, container_bottom_right) = container.extents();
+        let width =
+            (bottom_right.x - top_left.x).min(container_bottom_right.x - container_top_left.x);
+        let height =
+            (bottom_right.y - top_left.y).min(container_bottom_right.y - container_top_left.y);
+        let origin = Point::new(
+            top_left
+                .x
+                .clamp(container_top_left.x, container_bottom_right.x - width),
+            top_left
+                .y
+                .clamp(container_top_left.y, container_bottom_right.y - height),
+        );
+        Self::new(origin, Size::new(width, height))
+    }
+
     /// Returns a rectangle that has been inset by `amount` on all sides.
     #[must_use]
     pub fn inset(mut self, amount: impl Into<Unit>) -> Self
@@ -79,6 +222,189 @@ impl<Unit> Rect<Unit> {
         self
     }
 
+    /// Returns a rectangle that has been inset by `edges`, applying each
+    /// edge's measurement independently.
+    ///
+    /// ```rust
+    /// use figures::units::Px;
+    /// use figures::{Edges, Point, Rect, Size};
+    ///
+    /// let rect = Rect::new(
+    ///     Point::new(Px::new(0), Px::new(0)),
+    ///     Size::new(Px::new(100), Px::new(100)),
+    /// );
+    /// let padded = rect.inset_by(Edges::new(Px::new(1), Px::new(2), Px::new(3), Px::new(4)));
+    /// assert_eq!(
+    ///     padded,
+    ///     Rect::new(
+    ///         Point::new(Px::new(4), Px::new(1)),
+    ///         Size::new(Px::new(94), Px::new(96)),
+    ///     )
+    /// );
+    /// ```
+    #[must_use]
+    pub fn inset_by(mut self, edges: impl Into<crate::Edges<Unit>>) -> Self
+    where
+        Unit: Add<Unit, Output = Unit> + AddAssign<Unit> + SubAssign<Unit> + Copy,
+    {
+        let edges = edges.into();
+        self.origin.x += edges.left;
+        self.origin.y += edges.top;
+        self.size.width -= edges.left + edges.right;
+        self.size.height -= edges.top + edges.bottom;
+        self
+    }
+
+    /// Returns a rectangle of this rectangle's size, positioned within
+    /// `container` according to the provided alignments.
+    ///
+    /// If this rectangle is larger than `container` along an axis, the result
+    /// for signed units overflows `container` in the direction opposite its
+    /// alignment.
+    #[must_use]
+    pub fn align_within(self, container: Self, horizontal: Alignment, vertical: Alignment) -> Self
+    where
+        Unit: crate::Unit,
+    {
+        Self::new(
+            Point::new(
+                container.origin.x + horizontal.offset(container.size.width - self.size.width),
+                container.origin.y + vertical.offset(container.size.height - self.size.height),
+            ),
+            self.size,
+        )
+    }
+
+    /// Splits this rectangle along the horizontal axis, returning the areas
+    /// left and right of the split.
+    ///
+    /// `at` is measured from the left edge and is clamped to this rectangle's
+    /// width, so the returned rectangles always tile this rectangle exactly.
+    #[must_use]
+    pub fn split_horizontal(self, at: Unit) -> (Self, Self)
+    where
+        Unit: crate::Unit,
+    {
+        let at = at.max(Unit::ZERO).min(self.size.width);
+        (
+            Self::new(self.origin, Size::new(at, self.size.height)),
+            Self::new(
+                Point::new(self.origin.x + at, self.origin.y),
+                Size::new(self.size.width - at, self.size.height),
+            ),
+        )
+    }
+
+    /// Splits this rectangle along the vertical axis, returning the areas
+    /// above and below the split.
+    ///
+    /// `at` is measured from the top edge and is clamped to this rectangle's
+    /// height, so the returned rectangles always tile this rectangle exactly.
+    #[must_use]
+    pub fn split_vertical(self, at: Unit) -> (Self, Self)
+    where
+        Unit: crate::Unit,
+    {
+        let at = at.max(Unit::ZERO).min(self.size.height);
+        (
+            Self::new(self.origin, Size::new(self.size.width, at)),
+            Self::new(
+                Point::new(self.origin.x, self.origin.y + at),
+                Size::new(self.size.width, self.size.height - at),
+            ),
+        )
+    }
+
+    /// Returns an iterator of `count` equal-height rows that tile this
+    /// rectangle from top to bottom.
+    ///
+    /// When the height does not divide evenly, the remainder is spread
+    /// deterministically across the rows by computing each boundary as
+    /// `height * index / count`, so the row heights differ by at most one
+    /// unscaled unit and always sum to this rectangle's height.
+    pub fn rows(self, count: u32) -> impl Iterator<Item = Self>
+    where
+        Unit: crate::Unit + UnscaledUnit,
+        Unit::Representation: intentional::CastFrom<i64>,
+    {
+        let y = i64::from(self.origin.y.into_unscaled().cast_into());
+        let height = i64::from(self.size.height.into_unscaled().cast_into());
+        (0..count).map(move |index| {
+            let start = y + height * i64::from(index) / i64::from(count);
+            let end = y + height * i64::from(index + 1) / i64::from(count);
+            Self::new(
+                Point::new(self.origin.x, Unit::from_unscaled(start.cast())),
+                Size::new(self.size.width, Unit::from_unscaled((end - start).cast())),
+            )
+        })
+    }
+
+    /// Returns an iterator of `count` equal-width columns that tile this
+    /// rectangle from left to right.
+    ///
+    /// Remainders are distributed the same way as [`Rect::rows`].
+    pub fn columns(self, count: u32) -> impl Iterator<Item = Self>
+    where
+        Unit: crate::Unit + UnscaledUnit,
+        Unit::Representation: intentional::CastFrom<i64>,
+    {
+        let x = i64::from(self.origin.x.into_unscaled().cast_into());
+        let width = i64::from(self.size.width.into_unscaled().cast_into());
+        (0..count).map(move |index| {
+            let start = x + width * i64::from(index) / i64::from(count);
+            let end = x + width * i64::from(index + 1) / i64::from(count);
+            Self::new(
+                Point::new(Unit::from_unscaled(start.cast()), self.origin.y),
+                Size::new(Unit::from_unscaled((end - start).cast()), self.size.height),
+            )
+        })
+    }
+
+    /// Returns an iterator over the cells of a `columns` by `rows` grid that
+    /// tiles this rectangle, in row-major order.
+    ///
+    /// Space is distributed the same way as [`Rect::rows`] and
+    /// [`Rect::columns`]: cell sizes differ by at most one unscaled unit, and
+    /// each row of cells tiles the rectangle's width exactly.
+    ///
+    /// ```rust
+    /// use figures::units::Px;
+    /// use figures::{Point, Rect, Size};
+    ///
+    /// let rect = Rect::<Px>::new(
+    ///     Point::new(Px::new(0), Px::new(0)),
+    ///     Size::new(Px::new(100), Px::new(100)),
+    /// );
+    /// assert_eq!(rect.grid(4, 4).count(), 16);
+    /// ```
+    pub fn grid(self, columns: u32, rows: u32) -> impl Iterator<Item = Self>
+    where
+        Unit: crate::Unit + UnscaledUnit,
+        Unit::Representation: intentional::CastFrom<i64>,
+    {
+        self.rows(rows).flat_map(move |row| row.columns(columns))
+    }
+
+    /// Returns the cell at `column` and `row` of a `columns` by `rows` grid
+    /// that tiles this rectangle, or None if the cell is out of bounds.
+    ///
+    /// The returned rectangle matches the corresponding item of
+    /// [`Rect::grid`].
+    #[must_use]
+    pub fn cell(self, column: u32, row: u32, columns: u32, rows: u32) -> Option<Self>
+    where
+        Unit: crate::Unit + UnscaledUnit,
+        Unit::Representation: intentional::CastFrom<i64>,
+    {
+        if column >= columns || row >= rows {
+            return None;
+        }
+        self.rows(rows)
+            .nth(row.cast())?
+            .columns(columns)
+            .nth(column.cast())
+    }
+
     /// Converts the contents of this point to `NewUnit` using [`From`].
     pub fn cast<NewUnit>(self) -> Rect<NewUnit>
     where
@@ -115,6 +441,93 @@ impl<Unit> Rect<Unit> {
         p1.x <= point.x && p1.y <= point.y && p2.x > point.x && p2.y > point.y
     }
 
+    /// Returns true if this rect contains `point`, treating the far edges as
+    /// inclusive.
+    ///
+    /// [`Rect::contains`] excludes the right and bottom edges, which keeps
+    /// adjacent rectangles from both claiming a shared point. This variant
+    /// includes them, matching toolkits whose hit-testing is inclusive.
+    ///
+    /// ```rust
+    /// use figures::{Point, Rect, Size};
+    ///
+    /// let rect: Rect<i32> = Rect::new(Point::new(0, 0), Size::new(10, 10));
+    /// assert!(!rect.contains(Point::new(10, 10)));
+    /// assert!(rect.contains_inclusive(Point::new(10, 10)));
+    /// ```
+    pub fn contains_inclusive(&self, point: Point<Unit>) -> bool
+    where
+        Unit: crate::Unit,
+    {
+        let (p1, p2) = self.extents();
+        p1.x <= point.x && p1.y <= point.y && p2.x >= point.x && p2.y >= point.y
+    }
+
+    /// Returns true if `other` lies fully within this rect.
+    ///
+    /// The edges of `other` may touch the edges of `self`, and a rect
+    /// contains itself.
+    pub fn contains_rect(&self, other: &Self) -> bool
+    where
+        Unit: crate::Unit,
+    {
+        let (p1, p2) = self.extents();
+        let (o1, o2) = other.extents();
+        p1.x <= o1.x && p1.y <= o1.y && p2.x >= o2.x && p2.y >= o2.y
+    }
+
+    /// Returns the length of this rect along `axis`: its width for
+    /// [`Axis::X`](crate::Axis::X) and its height for
+    /// [`Axis::Y`](crate::Axis::Y).
+    #[must_use]
+    pub fn length(&self, axis: crate::Axis) -> Unit
+    where
+        Unit: Copy,
+    {
+        self.size.get(axis)
+    }
+
+    /// Returns the axis-aligned bounding box of this rect after being rotated
+    /// by `angle` around its center.
+    ///
+    /// ```rust
+    /// use figures::{Angle, Point, Rect, Size};
+    ///
+    /// let rect: Rect<i32> = Rect::new(Point::new(0, 0), Size::new(4, 2));
+    /// assert_eq!(
+    ///     rect.rotated_bounding_box(Angle::degrees(90)),
+    ///     Rect::new(Point::new(1, -1), Size::new(2, 4))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn rotated_bounding_box(self, angle: Angle) -> Self
+    where
+        Unit: crate::Unit + core::ops::Mul<Fraction, Output = Unit>,
+    {
+        let center =
+            self.origin + Point::new(self.size.width, self.size.height) * Fraction::new(1, 2);
+        self.rotated_bounding_box_around(angle, center)
+    }
+
+    /// Returns the axis-aligned bounding box of this rect after being rotated
+    /// by `angle` around `origin`.
+    #[must_use]
+    pub fn rotated_bounding_box_around(self, angle: Angle, origin: Point<Unit>) -> Self
+    where
+        Unit: crate::Unit + core::ops::Mul<Fraction, Output = Unit>,
+    {
+        let (tl, br) = self.extents();
+        let corners = [tl, Point::new(br.x, tl.y), br, Point::new(tl.x, br.y)]
+            .map(|corner| corner.rotate_around(origin, angle));
+        let mut min = corners[0];
+        let mut max = corners[0];
+        for corner in corners {
+            min = Point::new(min.x.min(corner.x), min.y.min(corner.y));
+            max = Point::new(max.x.max(corner.x), max.y.max(corner.y));
+        }
+        Self::from_extents(min, max)
+    }
+
     /// Returns true if the areas of `self` and `other` overlap.
     ///
     /// This function does not return true if the edges touch but do not overlap.
@@ -197,6 +610,519 @@ impl<Unit> Rect<Unit> {
     }
 }
 
+impl<Unit> Rect<Unit>
+where
+    Unit: FloatConversion<Float = f32> + Zero + Add<Output = Unit> + Sub<Output = Unit> + Copy,
+{
+    /// Returns this rectangle scaled to fit within `container` while
+    /// preserving its aspect ratio, positioned within the leftover space by
+    /// `horizontal` and `vertical`.
+    ///
+    /// ```rust
+    /// use figures::{Alignment, Point, Rect, Size};
+    ///
+    /// let video: Rect<i32> = Rect::new(Point::new(0, 0), Size::new(160, 90));
+    /// let screen = Rect::new(Point::new(0, 0), Size::new(320, 240));
+    /// let fitted = video.fit_inside(&screen, Alignment::Center, Alignment::Center);
+    /// assert_eq!(fitted, Rect::new(Point::new(0, 30), Size::new(320, 180)));
+    /// ```
+    #[must_use]
+    pub fn fit_inside(&self, container: &Self, horizontal: Alignment, vertical: Alignment) -> Self {
+        let scaled = self.size.scale_to_fit(container.size);
+        let origin = Point::new(
+            container.origin.x + horizontal.offset(container.size.width - scaled.width),
+            container.origin.y + vertical.offset(container.size.height - scaled.height),
+        );
+        Self::new(origin, scaled)
+    }
+}
+
+impl<Unit> Rect<Unit>
+where
+    Unit: crate::Unit,
+{
+    /// Returns the bars of this rectangle not covered by `inner`, for
+    /// clearing the letterbox or pillarbox regions around fitted content.
+    ///
+    /// For content fitted with [`fit_inside`](Self::fit_inside), this returns
+    /// zero, one, or two bars: horizontal bars span this rectangle's full
+    /// width, while vertical bars span only `inner`'s vertical range. If
+    /// `inner` is smaller than this rectangle on both axes, all four bars may
+    /// be returned, and if `inner` does not overlap this rectangle at all,
+    /// this entire rectangle is returned as a single bar.
+    ///
+    /// ```rust
+    /// use figures::{Point, Rect, Size};
+    ///
+    /// let screen: Rect<i32> = Rect::new(Point::new(0, 0), Size::new(320, 240));
+    /// let video = Rect::new(Point::new(0, 30), Size::new(320, 180));
+    /// assert_eq!(
+    ///     screen.letterbox_bars(&video),
+    ///     vec![
+    ///         Rect::new(Point::new(0, 0), Size::new(320, 30)),
+    ///         Rect::new(Point::new(0, 210), Size::new(320, 30)),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn letterbox_bars(&self, inner: &Self) -> Vec<Self> {
+        let Some(inner) = self.intersection(inner) else {
+            return vec![*self];
+        };
+        let (outer_tl, outer_br) = self.extents();
+        let (inner_tl, inner_br) = inner.extents();
+        let mut bars = Vec::new();
+        if inner_tl.y > outer_tl.y {
+            bars.push(Self::from_extents(
+                outer_tl,
+                Point::new(outer_br.x, inner_tl.y),
+            ));
+        }
+        if inner_br.y < outer_br.y {
+            bars.push(Self::from_extents(
+                Point::new(outer_tl.x, inner_br.y),
+                outer_br,
+            ));
+        }
+        if inner_tl.x > outer_tl.x {
+            bars.push(Self::from_extents(
+                Point::new(outer_tl.x, inner_tl.y),
+                Point::new(inner_tl.x, inner_br.y),
+            ));
+        }
+        if inner_br.x < outer_br.x {
+            bars.push(Self::from_extents(
+                Point::new(inner_br.x, inner_tl.y),
+                Point::new(outer_br.x, inner_br.y),
+            ));
+        }
+        bars
+    }
+
+    /// Returns the regions of this rectangle not covered by `other`.
+    ///
+    /// The difference is decomposed into at most four non-overlapping
+    /// rectangles: strips above and below `other` spanning this rectangle's
+    /// full width, and strips to the left and right of `other` spanning only
+    /// the overlapping vertical range. If `other` does not overlap this
+    /// rectangle, this entire rectangle is yielded; if `other` fully covers
+    /// this rectangle, the iterator is empty.
+    ///
+    /// ```rust
+    /// use figures::{Point, Rect, Size};
+    ///
+    /// let window: Rect<i32> = Rect::new(Point::new(0, 0), Size::new(100, 100));
+    /// let occluder = Rect::new(Point::new(50, 50), Size::new(100, 100));
+    /// assert_eq!(
+    ///     window.difference(&occluder).collect::<Vec<_>>(),
+    ///     vec![
+    ///         Rect::new(Point::new(0, 0), Size::new(100, 50)),
+    ///         Rect::new(Point::new(0, 50), Size::new(50, 50)),
+    ///     ]
+    /// );
+    /// assert_eq!(window.difference(&window).count(), 0);
+    /// ```
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let mut regions = [None; 4];
+        if let Some(overlap) = self.intersection(other) {
+            let (outer_tl, outer_br) = self.extents();
+            let (inner_tl, inner_br) = overlap.extents();
+            if inner_tl.y > outer_tl.y {
+                regions[0] = Some(Self::from_extents(
+                    outer_tl,
+                    Point::new(outer_br.x, inner_tl.y),
+                ));
+            }
+            if inner_tl.x > outer_tl.x {
+                regions[1] = Some(Self::from_extents(
+                    Point::new(outer_tl.x, inner_tl.y),
+                    Point::new(inner_tl.x, inner_br.y),
+                ));
+            }
+            if inner_br.x < outer_br.x {
+                regions[2] = Some(Self::from_extents(
+                    Point::new(inner_br.x, inner_tl.y),
+                    Point::new(outer_br.x, inner_br.y),
+                ));
+            }
+            if inner_br.y < outer_br.y {
+                regions[3] = Some(Self::from_extents(
+                    Point::new(outer_tl.x, inner_br.y),
+                    outer_br,
+                ));
+            }
+        } else {
+            regions[0] = Some(*self);
+        }
+        regions.into_iter().flatten()
+    }
+
+    /// Returns the portion of this rectangle's area that `other` covers, as
+    /// a value from zero to one.
+    ///
+    /// The ratio is computed from this rectangle's intersection with `other`
+    /// using integer math, making it deterministic across platforms. Ratios
+    /// too precise for [`Fraction`] to represent exactly are approximated by
+    /// the nearest representable fraction. If this rectangle has no area,
+    /// zero is returned.
+    ///
+    /// ```rust
+    /// use figures::units::Px;
+    /// use figures::{Fraction, Point, Rect, Size};
+    ///
+    /// let widget = Rect::new(
+    ///     Point::new(Px::new(0), Px::new(0)),
+    ///     Size::new(Px::new(100), Px::new(100)),
+    /// );
+    /// let viewport = Rect::new(
+    ///     Point::new(Px::new(50), Px::new(0)),
+    ///     Size::new(Px::new(100), Px::new(100)),
+    /// );
+    /// assert_eq!(widget.overlap_fraction(&viewport), Fraction::new(1, 2));
+    /// assert_eq!(widget.overlap_fraction(&widget), Fraction::ONE);
+    /// ```
+    #[must_use]
+    pub fn overlap_fraction(&self, other: &Self) -> Fraction
+    where
+        Unit: UnscaledUnit,
+    {
+        let denominator = unscaled_area(self);
+        let Some(overlap) = self.intersection(other) else {
+            return Fraction::ZERO;
+        };
+        if denominator == 0 {
+            return Fraction::ZERO;
+        }
+        let numerator = unscaled_area(&overlap);
+        let divisor = crate::num::gcd(numerator, denominator);
+        let numerator = numerator / divisor;
+        let denominator = denominator / divisor;
+        if let (Ok(numerator), Ok(denominator)) =
+            (i16::try_from(numerator), i16::try_from(denominator))
+        {
+            Fraction::new(numerator, denominator)
+        } else {
+            // The reduced ratio doesn't fit within a `Fraction`'s components,
+            // so approximate it with the nearest representable fraction.
+            #[allow(clippy::cast_precision_loss)]
+            Fraction::from(((numerator as f64) / (denominator as f64)).cast::<f32>())
+        }
+    }
+}
+
+/// Returns the area of `rect` in unscaled units. Because both areas in a
+/// ratio share the unit's scaling factor, it cancels out of the fraction.
+fn unscaled_area<Unit>(rect: &Rect<Unit>) -> u64
+where
+    Unit: UnscaledUnit + Copy,
+{
+    let width: i32 = rect.size.width.into_unscaled().cast_into();
+    let height: i32 = rect.size.height.into_unscaled().cast_into();
+    u64::from(width.unsigned_abs()) * u64::from(height.unsigned_abs())
+}
+
+impl Rect<Px> {
+    /// Returns the portion of a screen of `screen` size that a magnifier
+    /// should display to show this rectangle magnified by `magnification`.
+    ///
+    /// The viewport is `screen` divided by `magnification`, centered on this
+    /// rectangle and clamped so that it never extends past the screen. A
+    /// magnification of 1 or less returns the full screen.
+    ///
+    /// ```rust
+    /// use figures::units::Px;
+    /// use figures::{Fraction, Point, Rect, Size};
+    ///
+    /// let focus = Rect::new(
+    ///     Point::new(Px::new(300), Px::new(200)),
+    ///     Size::new(Px::new(40), Px::new(20)),
+    /// );
+    /// let screen = Size::new(Px::new(640), Px::new(480));
+    /// assert_eq!(
+    ///     focus.magnified_viewport(Fraction::new_whole(2), screen),
+    ///     Rect::new(
+    ///         Point::new(Px::new(160), Px::new(90)),
+    ///         Size::new(Px::new(320), Px::new(240)),
+    ///     )
+    /// );
+    /// ```
+    #[must_use]
+    pub fn magnified_viewport(&self, magnification: Fraction, screen: Size<Px>) -> Self {
+        let viewport = Size::new(
+            (screen.width / magnification).min(screen.width),
+            (screen.height / magnification).min(screen.height),
+        );
+        let center = self.origin + self.size / 2;
+        let origin = Point::new(
+            (center.x - viewport.width / 2)
+                .clamp(Px::ZERO, (screen.width - viewport.width).max(Px::ZERO)),
+            (center.y - viewport.height / 2)
+                .clamp(Px::ZERO, (screen.height - viewport.height).max(Px::ZERO)),
+        );
+        Self::new(origin, viewport)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Unit> From<euclid::Rect<f32, euclid::UnknownUnit>> for Rect<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(rect: euclid::Rect<f32, euclid::UnknownUnit>) -> Self {
+        Self::new(rect.origin.into(), rect.size.into())
+    }
+}
+#[cfg(feature = "euclid")]
+impl<Unit> From<Rect<Unit>> for euclid::Rect<f32, euclid::UnknownUnit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(rect: Rect<Unit>) -> Self {
+        Self::new(rect.origin.into(), rect.size.into())
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Unit> From<euclid::Box2D<f32, euclid::UnknownUnit>> for Rect<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(box2d: euclid::Box2D<f32, euclid::UnknownUnit>) -> Self {
+        Self::new(
+            Point::new(Unit::from_float(box2d.min.x), Unit::from_float(box2d.min.y)),
+            Size::new(
+                Unit::from_float(box2d.max.x - box2d.min.x),
+                Unit::from_float(box2d.max.y - box2d.min.y),
+            ),
+        )
+    }
+}
+#[cfg(feature = "euclid")]
+impl<Unit> From<Rect<Unit>> for euclid::Box2D<f32, euclid::UnknownUnit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(rect: Rect<Unit>) -> Self {
+        let x = rect.origin.x.into_float();
+        let y = rect.origin.y.into_float();
+        Self::new(
+            euclid::Point2D::new(x, y),
+            euclid::Point2D::new(
+                x + rect.size.width.into_float(),
+                y + rect.size.height.into_float(),
+            ),
+        )
+    }
+}
+
+// `lyon_path::math::Box2D` is a re-exported `euclid::Box2D`, so when the
+// `euclid` feature is enabled, its impls already cover lyon interop.
+#[cfg(all(feature = "lyon", not(feature = "euclid")))]
+impl<Unit> From<lyon_path::math::Box2D> for Rect<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(box2d: lyon_path::math::Box2D) -> Self {
+        Self::new(
+            Point::new(Unit::from_float(box2d.min.x), Unit::from_float(box2d.min.y)),
+            Size::new(
+                Unit::from_float(box2d.max.x - box2d.min.x),
+                Unit::from_float(box2d.max.y - box2d.min.y),
+            ),
+        )
+    }
+}
+#[cfg(all(feature = "lyon", not(feature = "euclid")))]
+impl<Unit> From<Rect<Unit>> for lyon_path::math::Box2D
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(rect: Rect<Unit>) -> Self {
+        let x = rect.origin.x.into_float();
+        let y = rect.origin.y.into_float();
+        Self::new(
+            lyon_path::math::Point::new(x, y),
+            lyon_path::math::Point::new(
+                x + rect.size.width.into_float(),
+                y + rect.size.height.into_float(),
+            ),
+        )
+    }
+}
+
+#[cfg(feature = "taffy")]
+impl<Unit> From<taffy::geometry::Rect<f32>> for Rect<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(rect: taffy::geometry::Rect<f32>) -> Self {
+        Self::new(
+            Point::new(Unit::from_float(rect.left), Unit::from_float(rect.top)),
+            Size::new(
+                Unit::from_float(rect.right - rect.left),
+                Unit::from_float(rect.bottom - rect.top),
+            ),
+        )
+    }
+}
+#[cfg(feature = "taffy")]
+impl<Unit> From<Rect<Unit>> for taffy::geometry::Rect<f32>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    fn from(rect: Rect<Unit>) -> Self {
+        let left = rect.origin.x.into_float();
+        let top = rect.origin.y.into_float();
+        Self {
+            left,
+            right: left + rect.size.width.into_float(),
+            top,
+            bottom: top + rect.size.height.into_float(),
+        }
+    }
+}
+
+#[cfg(feature = "taffy")]
+impl<Unit> Rect<Unit>
+where
+    Unit: ScreenScale<Lp = crate::units::Lp, Px = Px, UPx = UPx> + Copy + core::fmt::Debug,
+{
+    /// Converts this rectangle into device pixels using `scale` and returns
+    /// the result as a [`taffy::geometry::Rect`].
+    #[must_use]
+    pub fn to_taffy(self, scale: Fraction) -> taffy::geometry::Rect<f32> {
+        self.into_px(scale).into()
+    }
+
+    /// Converts `rect` from device pixels measured by taffy into this unit
+    /// using `scale`.
+    #[must_use]
+    pub fn from_taffy(rect: taffy::geometry::Rect<f32>, scale: Fraction) -> Self {
+        Self::from_px(Rect::from(rect), scale)
+    }
+}
+
+#[cfg(feature = "lyon")]
+impl<Unit> Rect<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    /// Returns this rectangle as a closed [`lyon_path::Path`], ready for
+    /// tessellation.
+    #[must_use]
+    pub fn to_lyon_path(self) -> lyon_path::Path {
+        let mut builder = lyon_path::Path::builder();
+        builder.add_rectangle(&self.into(), lyon_path::Winding::Positive);
+        builder.build()
+    }
+}
+
+#[cfg(feature = "wgpu")]
+impl From<Rect<UPx>> for (u32, u32, u32, u32) {
+    /// Returns `(x, y, width, height)`, the argument order expected by
+    /// `wgpu::RenderPass::set_scissor_rect`.
+    fn from(rect: Rect<UPx>) -> Self {
+        (
+            rect.origin.x.into(),
+            rect.origin.y.into(),
+            rect.size.width.into(),
+            rect.size.height.into(),
+        )
+    }
+}
+
+#[cfg(feature = "wgpu")]
+impl From<Rect<Px>> for (f32, f32, f32, f32) {
+    /// Returns `(x, y, width, height)` as floats, matching the leading
+    /// arguments of `wgpu::RenderPass::set_viewport`.
+    fn from(rect: Rect<Px>) -> Self {
+        (
+            rect.origin.x.into_float(),
+            rect.origin.y.into_float(),
+            rect.size.width.into_float(),
+            rect.size.height.into_float(),
+        )
+    }
+}
+
+impl Rect<f32> {
+    /// Returns true if all components are neither infinite nor NaN.
+    ///
+    /// Geometry predicates such as [`contains`](Self::contains) and
+    /// [`intersects`](Self::intersects) return false when NaN is involved in
+    /// the comparison, which can silently discard input. Callers processing
+    /// untrusted float math should check for NaN with these helpers first.
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.origin.is_finite() && self.size.is_finite()
+    }
+
+    /// Returns true if any component is NaN.
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.origin.is_nan() || self.size.is_nan()
+    }
+
+    /// Returns this rectangle with any NaN component replaced with `default`.
+    #[must_use]
+    pub fn replace_nan(self, default: f32) -> Self {
+        Self::new(
+            self.origin.replace_nan(default),
+            self.size.replace_nan(default),
+        )
+    }
+
+    /// Returns this rectangle's components as canonical bit patterns,
+    /// suitable for hashing.
+    ///
+    /// The bits normalize negative zero to positive zero and collapse every
+    /// NaN to one bit pattern. See [`Point::canonical_bits`] for the full
+    /// semantics.
+    #[must_use]
+    pub fn canonical_bits(&self) -> [u32; 4] {
+        let [x, y] = self.origin.canonical_bits();
+        let [width, height] = self.size.canonical_bits();
+        [x, y, width, height]
+    }
+}
+
+impl Rect<Lp> {
+    /// Converts this rectangle into device pixels using the provided `scale`
+    /// factor, returning the converted rectangle and the maximum per-component
+    /// rounding error as a [`Px`] value.
+    ///
+    /// ```rust
+    /// use figures::units::{Lp, Px};
+    /// use figures::{Fraction, Point, Rect, Size};
+    ///
+    /// let rect = Rect::<Lp>::new(
+    ///     Point::new(Lp::inches(1), Lp::inches(2)),
+    ///     Size::new(Lp::inches(3), Lp::inches(4)),
+    /// );
+    /// let (_converted, error) = rect.into_px_with_error(Fraction::new(4, 3));
+    /// assert!(error <= Px::from(0.5));
+    /// ```
+    #[must_use]
+    pub fn into_px_with_error(self, scale: crate::Fraction) -> (Rect<Px>, Px) {
+        let (origin, origin_error) = self.origin.into_px_with_error(scale);
+        let (size, size_error) = self.size.into_px_with_error(scale);
+        (Rect::new(origin, size), origin_error.max(size_error))
+    }
+}
+
+impl<Unit> Rect<Unit>
+where
+    Unit: crate::traits::ApproxEq + Copy,
+{
+    /// Returns true if each component of `self`'s origin and size differs
+    /// from `other`'s by at most `tolerance`.
+    #[must_use]
+    pub fn approx_eq_within(self, other: Self, tolerance: Unit) -> bool {
+        self.origin.approx_eq_within(other.origin, tolerance)
+            && self.size.approx_eq_within(other.size, tolerance)
+    }
+}
+
 impl<Unit> Rect<Unit>
 where
     // alternatively we could reduce the traits for `extent()`
@@ -242,6 +1168,59 @@ where
     }
 }
 
+impl<Unit> Rect<Unit>
+where
+    Unit: Add<Output = Unit> + Sub<Output = Unit> + Ord + Copy,
+{
+    /// Returns the four strips that tile this rectangle's border region,
+    /// ordered top, right, bottom, left.
+    ///
+    /// The left and right strips never cover the corner regions. When
+    /// `include_corners` is true, the corners are covered by the top and
+    /// bottom strips; otherwise, no strip covers them. In both cases, the
+    /// returned rectangles never overlap.
+    ///
+    /// ```rust
+    /// use figures::{Point, Rect, Size};
+    ///
+    /// let rect: Rect<i32> = Rect::new(Point::new(0, 0), Size::new(10, 8));
+    /// let [top, right, bottom, left] = rect.border_rects(1, true);
+    /// assert_eq!(top, Rect::new(Point::new(0, 0), Size::new(10, 1)));
+    /// assert_eq!(right, Rect::new(Point::new(9, 1), Size::new(1, 6)));
+    /// assert_eq!(bottom, Rect::new(Point::new(0, 7), Size::new(10, 1)));
+    /// assert_eq!(left, Rect::new(Point::new(0, 1), Size::new(1, 6)));
+    /// ```
+    #[must_use]
+    pub fn border_rects(&self, width: Unit, include_corners: bool) -> [Self; 4] {
+        let origin = self.origin;
+        let size = self.size;
+        let inner_height = size.height - width - width;
+        let (horizontal_x, horizontal_width) = if include_corners {
+            (origin.x, size.width)
+        } else {
+            (origin.x + width, size.width - width - width)
+        };
+        [
+            Self::new(
+                Point::new(horizontal_x, origin.y),
+                Size::new(horizontal_width, width),
+            ),
+            Self::new(
+                Point::new(origin.x + size.width - width, origin.y + width),
+                Size::new(width, inner_height),
+            ),
+            Self::new(
+                Point::new(horizontal_x, origin.y + size.height - width),
+                Size::new(horizontal_width, width),
+            ),
+            Self::new(
+                Point::new(origin.x, origin.y + width),
+                Size::new(width, inner_height),
+            ),
+        ]
+    }
+}
+
 impl<Unit> Rect<Unit>
 where
     Unit: StdNumOps + Ord + Copy,
@@ -273,6 +1252,48 @@ where
     }
 }
 
+impl<Unit> core::fmt::Display for Rect<Unit>
+where
+    Unit: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} @ {}", self.size, self.origin)
+    }
+}
+
+impl<Unit> CheckedNumOps for Rect<Unit>
+where
+    Unit: CheckedNumOps,
+{
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.origin.checked_add(other.origin)?,
+            self.size.checked_add(other.size)?,
+        ))
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.origin.checked_sub(other.origin)?,
+            self.size.checked_sub(other.size)?,
+        ))
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.origin.checked_mul(other.origin)?,
+            self.size.checked_mul(other.size)?,
+        ))
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.origin.checked_div(other.origin)?,
+            self.size.checked_div(other.size)?,
+        ))
+    }
+}
+
 impl<Unit> IntoUnsigned for Rect<Unit>
 where
     Unit: IntoUnsigned,
@@ -332,6 +1353,28 @@ where
     }
 }
 
+impl<Unit> core::ops::Mul<Fraction> for Rect<Unit>
+where
+    Unit: core::ops::Mul<Fraction, Output = Unit>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Fraction) -> Self::Output {
+        self.map(|unit| unit * rhs)
+    }
+}
+
+impl<Unit> core::ops::Div<Fraction> for Rect<Unit>
+where
+    Unit: core::ops::Div<Fraction, Output = Unit>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Fraction) -> Self::Output {
+        self.map(|unit| unit / rhs)
+    }
+}
+
 impl<Unit> Ranged for Rect<Unit>
 where
     Unit: Ranged,
@@ -354,11 +1397,130 @@ where
     }
 }
 
+impl<Unit> ScreenScale for Rect<Unit>
+where
+    Unit: ScreenScale<Lp = Lp, Px = Px, UPx = UPx>,
+{
+    type Lp = Rect<Lp>;
+    type Px = Rect<Px>;
+    type UPx = Rect<UPx>;
+
+    fn into_px(self, scale: crate::Fraction) -> Self::Px {
+        Rect {
+            origin: self.origin.into_px(scale),
+            size: self.size.into_px(scale),
+        }
+    }
+
+    fn from_px(px: Self::Px, scale: crate::Fraction) -> Self {
+        Self {
+            origin: Point::from_px(px.origin, scale),
+            size: Size::from_px(px.size, scale),
+        }
+    }
+
+    fn into_upx(self, scale: crate::Fraction) -> Self::UPx {
+        Rect {
+            origin: self.origin.into_upx(scale),
+            size: self.size.into_upx(scale),
+        }
+    }
+
+    fn from_upx(px: Self::UPx, scale: crate::Fraction) -> Self {
+        Self {
+            origin: Point::from_upx(px.origin, scale),
+            size: Size::from_upx(px.size, scale),
+        }
+    }
+
+    fn into_lp(self, scale: crate::Fraction) -> Self::Lp {
+        Rect {
+            origin: self.origin.into_lp(scale),
+            size: self.size.into_lp(scale),
+        }
+    }
+
+    fn from_lp(lp: Self::Lp, scale: crate::Fraction) -> Self {
+        Self {
+            origin: Point::from_lp(lp.origin, scale),
+            size: Size::from_lp(lp.size, scale),
+        }
+    }
+}
+
+impl<Unit, NewUnit> ConvertUnit<Rect<NewUnit>> for Rect<Unit>
+where
+    Unit: ConvertUnit<NewUnit>,
+{
+    fn convert(self, scale: crate::Fraction) -> Rect<NewUnit> {
+        Rect {
+            origin: self.origin.convert(scale),
+            size: self.size.convert(scale),
+        }
+    }
+}
+
+impl<Unit> Rect<Unit> {
+    /// Returns this rectangle converted into `NewUnit` using the provided
+    /// `scale` factor.
+    ///
+    /// This is a generic entry point to the conversions offered by
+    /// [`ScreenScale`], via [`ConvertUnit`].
+    ///
+    /// ```rust
+    /// use figures::units::{Lp, Px};
+    /// use figures::{Fraction, Point, Rect, Size};
+    ///
+    /// let rect = Rect::new(
+    ///     Point::new(Px::new(0), Px::new(0)),
+    ///     Size::new(Px::new(96), Px::new(96)),
+    /// );
+    /// assert_eq!(
+    ///     rect.convert::<Lp>(Fraction::ONE),
+    ///     Rect::new(
+    ///         Point::new(Lp::new(0), Lp::new(0)),
+    ///         Size::new(Lp::inches(1), Lp::inches(1)),
+    ///     )
+    /// );
+    /// ```
+    #[must_use]
+    pub fn convert<NewUnit>(self, scale: crate::Fraction) -> Rect<NewUnit>
+    where
+        Unit: ConvertUnit<NewUnit>,
+    {
+        Rect {
+            origin: self.origin.convert(scale),
+            size: self.size.convert(scale),
+        }
+    }
+}
+
+impl<Unit> crate::traits::FromComponents4<Unit> for Rect<Unit> {
+    fn from_components4(components: (Unit, Unit, Unit, Unit)) -> Self {
+        Self::new(
+            Point::new(components.0, components.1),
+            Size::new(components.2, components.3),
+        )
+    }
+}
+
+impl<Unit> crate::traits::IntoComponents4<Unit> for Rect<Unit> {
+    fn into_components4(self) -> (Unit, Unit, Unit, Unit) {
+        (
+            self.origin.x,
+            self.origin.y,
+            self.size.width,
+            self.size.height,
+        )
+    }
+}
+
 impl<Unit> FloatConversion for Rect<Unit>
 where
     Unit: FloatConversion,
 {
     type Float = Rect<Unit::Float>;
+    type Float64 = Rect<Unit::Float64>;
 
     fn into_float(self) -> Self::Float {
         self.map(FloatConversion::into_float)
@@ -367,6 +1529,14 @@ where
     fn from_float(float: Self::Float) -> Self {
         float.map(FloatConversion::from_float)
     }
+
+    fn into_float64(self) -> Self::Float64 {
+        self.map(FloatConversion::into_float64)
+    }
+
+    fn from_float64(float: Self::Float64) -> Self {
+        float.map(FloatConversion::from_float64)
+    }
 }
 
 #[test]
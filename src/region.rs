@@ -0,0 +1,310 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Point, Rect};
+
+/// A set of non-overlapping rectangles covering an area of the screen.
+///
+/// Regions are kept normalized: the rectangles never overlap, and adjacent
+/// rectangles that tile a larger rectangle exactly are merged. This makes a
+/// region suitable for damage tracking, where overlapping invalidations
+/// should not cause an area to be redrawn twice.
+///
+/// ```rust
+/// use figures::{Point, Rect, Region, Size};
+///
+/// let mut damage = Region::<i32>::default();
+/// damage.add(Rect::new(Point::new(0, 0), Size::new(10, 10)));
+/// damage.add(Rect::new(Point::new(10, 0), Size::new(10, 10)));
+/// // The two abutting rectangles are merged during normalization.
+/// assert_eq!(
+///     damage.iter().copied().collect::<Vec<_>>(),
+///     vec![Rect::new(Point::new(0, 0), Size::new(20, 10))]
+/// );
+///
+/// damage.subtract(Rect::new(Point::new(0, 0), Size::new(20, 10)));
+/// assert!(damage.is_empty());
+/// ```
+#[derive(Default, Clone, Eq, PartialEq, Debug)]
+pub struct Region<Unit> {
+    rects: Vec<Rect<Unit>>,
+}
+
+impl<Unit> Region<Unit>
+where
+    Unit: crate::Unit,
+{
+    /// Returns an empty region.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { rects: Vec::new() }
+    }
+
+    /// Adds `rect` to this region.
+    ///
+    /// Only the portions of `rect` not already covered by this region are
+    /// added, keeping the region's rectangles non-overlapping. Empty
+    /// rectangles are ignored.
+    pub fn add(&mut self, rect: Rect<Unit>) {
+        if rect.is_empty() {
+            return;
+        }
+        let mut pieces = vec![rect];
+        for existing in &self.rects {
+            pieces = pieces
+                .into_iter()
+                .flat_map(|piece| piece.difference(existing))
+                .collect();
+        }
+        self.rects.append(&mut pieces);
+        self.coalesce();
+    }
+
+    /// Removes `rect` from this region.
+    pub fn subtract(&mut self, rect: Rect<Unit>) {
+        self.rects = self
+            .rects
+            .iter()
+            .flat_map(|existing| existing.difference(&rect))
+            .collect();
+        self.coalesce();
+    }
+
+    /// Returns true if `rect` overlaps any part of this region.
+    #[must_use]
+    pub fn intersects(&self, rect: &Rect<Unit>) -> bool {
+        self.rects.iter().any(|existing| existing.intersects(rect))
+    }
+
+    /// Returns the smallest rectangle containing this entire region, or None
+    /// if the region is empty.
+    #[must_use]
+    pub fn bounding_rect(&self) -> Option<Rect<Unit>> {
+        let mut rects = self.rects.iter();
+        let (mut min, mut max) = rects.next()?.extents();
+        for rect in rects {
+            let (top_left, bottom_right) = rect.extents();
+            min = Point::new(min.x.min(top_left.x), min.y.min(top_left.y));
+            max = Point::new(max.x.max(bottom_right.x), max.y.max(bottom_right.y));
+        }
+        Some(Rect::from_extents(min, max))
+    }
+
+    /// Returns true if this region covers no area.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns an iterator over the non-overlapping rectangles in this
+    /// region.
+    pub fn iter(&self) -> core::slice::Iter<'_, Rect<Unit>> {
+        self.rects.iter()
+    }
+
+    /// Merges pairs of rectangles that exactly tile a larger rectangle until
+    /// no more merges are possible.
+    fn coalesce(&mut self) {
+        let mut merged = true;
+        while merged {
+            merged = false;
+            'outer: for first in 0..self.rects.len() {
+                for second in first + 1..self.rects.len() {
+                    if let Some(combined) = merge(&self.rects[first], &self.rects[second]) {
+                        self.rects[first] = combined;
+                        self.rects.swap_remove(second);
+                        merged = true;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the rectangle tiled exactly by `a` and `b`, if one exists.
+fn merge<Unit>(a: &Rect<Unit>, b: &Rect<Unit>) -> Option<Rect<Unit>>
+where
+    Unit: crate::Unit,
+{
+    let (a_tl, a_br) = a.extents();
+    let (b_tl, b_br) = b.extents();
+    if a_tl.y == b_tl.y && a_br.y == b_br.y && (a_br.x == b_tl.x || b_br.x == a_tl.x) {
+        Some(Rect::from_extents(
+            Point::new(a_tl.x.min(b_tl.x), a_tl.y),
+            Point::new(a_br.x.max(b_br.x), a_br.y),
+        ))
+    } else if a_tl.x == b_tl.x && a_br.x == b_br.x && (a_br.y == b_tl.y || b_br.y == a_tl.y) {
+        Some(Rect::from_extents(
+            Point::new(a_tl.x, a_tl.y.min(b_tl.y)),
+            Point::new(a_br.x, a_br.y.max(b_br.y)),
+        ))
+    } else {
+        None
+    }
+}
+
+impl<Unit> From<Rect<Unit>> for Region<Unit>
+where
+    Unit: crate::Unit,
+{
+    fn from(rect: Rect<Unit>) -> Self {
+        let mut region = Self::new();
+        region.add(rect);
+        region
+    }
+}
+
+impl<'a, Unit> IntoIterator for &'a Region<Unit> {
+    type IntoIter = core::slice::Iter<'a, Rect<Unit>>;
+    type Item = &'a Rect<Unit>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rects.iter()
+    }
+}
+
+impl<Unit> IntoIterator for Region<Unit> {
+    type IntoIter = alloc::vec::IntoIter<Rect<Unit>>;
+    type Item = Rect<Unit>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rects.into_iter()
+    }
+}
+
+/// The changes between two lists of rectangles, as computed by
+/// [`diff_rects`].
+#[derive(Default, Clone, Eq, PartialEq, Debug)]
+pub struct RectDiff<Unit> {
+    /// Rectangles present in the new list with no counterpart in the old
+    /// list.
+    pub added: Vec<Rect<Unit>>,
+    /// Rectangles present in the old list with no counterpart in the new
+    /// list.
+    pub removed: Vec<Rect<Unit>>,
+    /// Pairs of old and new rectangles that share an index but have
+    /// different geometry.
+    pub moved: Vec<(Rect<Unit>, Rect<Unit>)>,
+}
+
+impl<Unit> RectDiff<Unit>
+where
+    Unit: crate::Unit,
+{
+    /// Returns true if no rectangles changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+
+    /// Returns the region needing to be redrawn to reflect these changes.
+    ///
+    /// Removed rectangles and both positions of moved rectangles are
+    /// included, ensuring previously covered areas are repainted.
+    #[must_use]
+    pub fn damage(&self) -> Region<Unit> {
+        let mut damage = Region::new();
+        for rect in self.added.iter().chain(&self.removed) {
+            damage.add(*rect);
+        }
+        for (old, new) in &self.moved {
+            damage.add(*old);
+            damage.add(*new);
+        }
+        damage
+    }
+}
+
+/// Returns the changes between two lists of rectangles, pairing entries by
+/// index.
+///
+/// Entries sharing an index in both lists are compared directly: identical
+/// rectangles are unchanged, while differing rectangles are reported as
+/// moved. Indexes only present in `new` are reported as added, and indexes
+/// only present in `old` are reported as removed. This matches retained
+/// scenes that re-lay out a stable list of elements, where an element keeps
+/// its index across layouts.
+///
+/// ```rust
+/// use figures::{diff_rects, Point, Rect, Size};
+///
+/// let old = [Rect::<i32>::new(Point::new(0, 0), Size::new(10, 10))];
+/// let new = [
+///     Rect::new(Point::new(5, 0), Size::new(10, 10)),
+///     Rect::new(Point::new(0, 20), Size::new(10, 10)),
+/// ];
+/// let diff = diff_rects(&old, &new);
+/// assert_eq!(diff.moved, vec![(old[0], new[0])]);
+/// assert_eq!(diff.added, vec![new[1]]);
+/// assert!(diff.removed.is_empty());
+/// ```
+#[must_use]
+pub fn diff_rects<Unit>(old: &[Rect<Unit>], new: &[Rect<Unit>]) -> RectDiff<Unit>
+where
+    Unit: crate::Unit,
+{
+    let mut diff = RectDiff::default();
+    let shared = old.len().min(new.len());
+    for (old, new) in old[..shared].iter().zip(&new[..shared]) {
+        if old != new {
+            diff.moved.push((*old, *new));
+        }
+    }
+    diff.added.extend_from_slice(&new[shared..]);
+    diff.removed.extend_from_slice(&old[shared..]);
+    diff
+}
+
+#[test]
+fn overlapping_adds_cover_once() {
+    use crate::{Point, Size};
+    let mut region = Region::<i32>::new();
+    region.add(Rect::new(Point::new(0, 0), Size::new(10, 10)));
+    region.add(Rect::new(Point::new(5, 0), Size::new(10, 10)));
+    let area: i32 = region.iter().map(|rect| rect.size.area()).sum();
+    assert_eq!(area, 150);
+    assert_eq!(
+        region.bounding_rect(),
+        Some(Rect::new(Point::new(0, 0), Size::new(15, 10)))
+    );
+    assert!(region.intersects(&Rect::new(Point::new(14, 9), Size::new(5, 5))));
+    assert!(!region.intersects(&Rect::new(Point::new(15, 0), Size::new(5, 5))));
+}
+
+#[test]
+fn subtract_splits_rects() {
+    use crate::{Point, Size};
+    let mut region = Region::from(Rect::<i32>::new(Point::new(0, 0), Size::new(10, 10)));
+    region.subtract(Rect::new(Point::new(4, 4), Size::new(2, 2)));
+    let area: i32 = region.iter().map(|rect| rect.size.area()).sum();
+    assert_eq!(area, 96);
+    assert!(!region.intersects(&Rect::new(Point::new(4, 4), Size::new(2, 2))));
+    region.subtract(Rect::new(Point::new(-10, -10), Size::new(40, 40)));
+    assert!(region.is_empty());
+    assert_eq!(region.bounding_rect(), None);
+}
+
+#[test]
+fn rect_diffing() {
+    use crate::{diff_rects, Point, Size};
+    let old = [
+        Rect::<i32>::new(Point::new(0, 0), Size::new(10, 10)),
+        Rect::new(Point::new(20, 0), Size::new(10, 10)),
+    ];
+    assert!(diff_rects(&old, &old).is_empty());
+
+    let new = [Rect::new(Point::new(0, 5), Size::new(10, 10))];
+    let diff = diff_rects(&old, &new);
+    assert_eq!(diff.moved, vec![(old[0], new[0])]);
+    assert_eq!(diff.removed, vec![old[1]]);
+    assert!(diff.added.is_empty());
+    // The damage region covers both positions of the moved rectangle and
+    // the removed rectangle.
+    let damage = diff.damage();
+    assert!(damage.intersects(&old[0]));
+    assert!(damage.intersects(&new[0]));
+    assert!(damage.intersects(&old[1]));
+    let area: i32 = damage.iter().map(|rect| rect.size.area()).sum();
+    assert_eq!(area, 150 + 100);
+}
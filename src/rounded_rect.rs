@@ -0,0 +1,445 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::traits::{IntoSigned, IntoUnsigned, ScreenScale, Zero};
+use crate::units::{Lp, Px, UPx};
+use crate::{Point, Rect};
+
+/// A measurement of the radius of each corner of a rounded rectangle.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CornerRadii<Unit> {
+    /// The radius of the top-left corner.
+    pub top_left: Unit,
+    /// The radius of the top-right corner.
+    pub top_right: Unit,
+    /// The radius of the bottom-right corner.
+    pub bottom_right: Unit,
+    /// The radius of the bottom-left corner.
+    pub bottom_left: Unit,
+}
+
+impl<Unit> CornerRadii<Unit> {
+    /// Returns a new set of radii with the provided measurements.
+    pub const fn new(
+        top_left: Unit,
+        top_right: Unit,
+        bottom_right: Unit,
+        bottom_left: Unit,
+    ) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Returns a new set of radii using `radius` for all four corners.
+    pub fn all(radius: Unit) -> Self
+    where
+        Unit: Clone,
+    {
+        Self {
+            top_left: radius.clone(),
+            top_right: radius.clone(),
+            bottom_right: radius.clone(),
+            bottom_left: radius,
+        }
+    }
+
+    /// Maps each corner radius to `map` and returns a new value with the
+    /// mapped radii.
+    pub fn map<NewUnit>(self, mut map: impl FnMut(Unit) -> NewUnit) -> CornerRadii<NewUnit> {
+        CornerRadii {
+            top_left: map(self.top_left),
+            top_right: map(self.top_right),
+            bottom_right: map(self.bottom_right),
+            bottom_left: map(self.bottom_left),
+        }
+    }
+
+    /// Converts the contents of this set of radii to `NewUnit` using [`From`].
+    pub fn cast<NewUnit>(self) -> CornerRadii<NewUnit>
+    where
+        NewUnit: From<Unit>,
+    {
+        self.map(NewUnit::from)
+    }
+}
+
+impl<Unit> From<Unit> for CornerRadii<Unit>
+where
+    Unit: Clone,
+{
+    fn from(radius: Unit) -> Self {
+        Self::all(radius)
+    }
+}
+
+impl<Unit> Zero for CornerRadii<Unit>
+where
+    Unit: Zero,
+{
+    const ZERO: Self = Self::new(Unit::ZERO, Unit::ZERO, Unit::ZERO, Unit::ZERO);
+
+    fn is_zero(&self) -> bool {
+        self.top_left.is_zero()
+            && self.top_right.is_zero()
+            && self.bottom_right.is_zero()
+            && self.bottom_left.is_zero()
+    }
+}
+
+impl<Unit> ScreenScale for CornerRadii<Unit>
+where
+    Unit: ScreenScale<Lp = Lp, Px = Px, UPx = UPx>,
+{
+    type Lp = CornerRadii<Lp>;
+    type Px = CornerRadii<Px>;
+    type UPx = CornerRadii<UPx>;
+
+    fn into_px(self, scale: crate::Fraction) -> Self::Px {
+        self.map(|radius| radius.into_px(scale))
+    }
+
+    fn from_px(px: Self::Px, scale: crate::Fraction) -> Self {
+        px.map(|radius| Unit::from_px(radius, scale))
+    }
+
+    fn into_upx(self, scale: crate::Fraction) -> Self::UPx {
+        self.map(|radius| radius.into_upx(scale))
+    }
+
+    fn from_upx(px: Self::UPx, scale: crate::Fraction) -> Self {
+        px.map(|radius| Unit::from_upx(radius, scale))
+    }
+
+    fn into_lp(self, scale: crate::Fraction) -> Self::Lp {
+        self.map(|radius| radius.into_lp(scale))
+    }
+
+    fn from_lp(lp: Self::Lp, scale: crate::Fraction) -> Self {
+        lp.map(|radius| Unit::from_lp(radius, scale))
+    }
+}
+
+impl<Unit> IntoSigned for CornerRadii<Unit>
+where
+    Unit: IntoSigned,
+{
+    type Signed = CornerRadii<Unit::Signed>;
+
+    fn into_signed(self) -> Self::Signed {
+        self.map(Unit::into_signed)
+    }
+}
+
+impl<Unit> IntoUnsigned for CornerRadii<Unit>
+where
+    Unit: IntoUnsigned,
+{
+    type Unsigned = CornerRadii<Unit::Unsigned>;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        self.map(Unit::into_unsigned)
+    }
+}
+
+/// A 2d area expressed as a [`Rect`] with rounded corners.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RoundedRect<Unit> {
+    /// The rectangle being rounded.
+    pub rect: Rect<Unit>,
+    /// The radii of each corner.
+    pub radii: CornerRadii<Unit>,
+}
+
+impl<Unit> RoundedRect<Unit> {
+    /// Returns a new rounded rectangle.
+    pub fn new(rect: Rect<Unit>, radii: impl Into<CornerRadii<Unit>>) -> Self {
+        Self {
+            rect,
+            radii: radii.into(),
+        }
+    }
+
+    /// Returns true if this rounded rectangle contains `point`, accounting for
+    /// the corner arcs.
+    pub fn contains(&self, point: Point<Unit>) -> bool
+    where
+        Unit: crate::Unit,
+    {
+        if !self.rect.contains(point) {
+            return false;
+        }
+
+        let (top_left, bottom_right) = self.rect.extents();
+        !(outside_of_corner(
+            point,
+            Point::new(
+                top_left.x + self.radii.top_left,
+                top_left.y + self.radii.top_left,
+            ),
+            self.radii.top_left,
+            |x, cx| x < cx,
+            |y, cy| y < cy,
+        ) || outside_of_corner(
+            point,
+            Point::new(
+                bottom_right.x - self.radii.top_right,
+                top_left.y + self.radii.top_right,
+            ),
+            self.radii.top_right,
+            |x, cx| x > cx,
+            |y, cy| y < cy,
+        ) || outside_of_corner(
+            point,
+            Point::new(
+                bottom_right.x - self.radii.bottom_right,
+                bottom_right.y - self.radii.bottom_right,
+            ),
+            self.radii.bottom_right,
+            |x, cx| x > cx,
+            |y, cy| y > cy,
+        ) || outside_of_corner(
+            point,
+            Point::new(
+                top_left.x + self.radii.bottom_left,
+                bottom_right.y - self.radii.bottom_left,
+            ),
+            self.radii.bottom_left,
+            |x, cx| x < cx,
+            |y, cy| y > cy,
+        ))
+    }
+
+    /// Returns a rounded rectangle that has been inset by `amount` on all
+    /// sides.
+    ///
+    /// Each corner radius is reduced by `amount`, stopping at zero.
+    #[must_use]
+    pub fn inset(self, amount: impl Into<Unit>) -> Self
+    where
+        Unit: Add<Output = Unit> + Sub<Output = Unit> + Ord + Zero + Copy,
+    {
+        let amount = amount.into();
+        let double_amount = amount + amount;
+        let origin = Point::new(self.rect.origin.x + amount, self.rect.origin.y + amount);
+        let size = crate::Size::new(
+            self.rect.size.width - double_amount,
+            self.rect.size.height - double_amount,
+        );
+        Self {
+            rect: Rect::new(origin, size),
+            radii: self.radii.map(|radius| {
+                if radius > amount {
+                    radius - amount
+                } else {
+                    Unit::ZERO
+                }
+            }),
+        }
+    }
+}
+
+/// Returns true if `point` is within the square containing the corner arc, but
+/// outside of the arc itself.
+fn outside_of_corner<Unit>(
+    point: Point<Unit>,
+    center: Point<Unit>,
+    radius: Unit,
+    beyond_x: impl FnOnce(Unit, Unit) -> bool,
+    beyond_y: impl FnOnce(Unit, Unit) -> bool,
+) -> bool
+where
+    Unit: Copy
+        + Ord
+        + Sub<Output = Unit>
+        + Mul<Output = Unit>
+        + Add<Output = Unit>
+        + Div<Output = Unit>,
+{
+    if beyond_x(point.x, center.x) && beyond_y(point.y, center.y) {
+        let delta = Point::new(
+            if point.x > center.x {
+                point.x - center.x
+            } else {
+                center.x - point.x
+            },
+            if point.y > center.y {
+                point.y - center.y
+            } else {
+                center.y - point.y
+            },
+        );
+        delta.x * delta.x + delta.y * delta.y > radius * radius
+    } else {
+        false
+    }
+}
+
+impl<Unit> From<RoundedRect<Unit>> for Rect<Unit> {
+    fn from(rounded: RoundedRect<Unit>) -> Self {
+        rounded.rect
+    }
+}
+
+impl<Unit> From<Rect<Unit>> for RoundedRect<Unit>
+where
+    Unit: Zero,
+{
+    fn from(rect: Rect<Unit>) -> Self {
+        Self {
+            rect,
+            radii: CornerRadii::ZERO,
+        }
+    }
+}
+
+impl<Unit> ScreenScale for RoundedRect<Unit>
+where
+    Unit: ScreenScale<Lp = Lp, Px = Px, UPx = UPx>,
+{
+    type Lp = RoundedRect<Lp>;
+    type Px = RoundedRect<Px>;
+    type UPx = RoundedRect<UPx>;
+
+    fn into_px(self, scale: crate::Fraction) -> Self::Px {
+        RoundedRect {
+            rect: self.rect.into_px(scale),
+            radii: self.radii.into_px(scale),
+        }
+    }
+
+    fn from_px(px: Self::Px, scale: crate::Fraction) -> Self {
+        Self {
+            rect: Rect::from_px(px.rect, scale),
+            radii: CornerRadii::from_px(px.radii, scale),
+        }
+    }
+
+    fn into_upx(self, scale: crate::Fraction) -> Self::UPx {
+        RoundedRect {
+            rect: self.rect.into_upx(scale),
+            radii: self.radii.into_upx(scale),
+        }
+    }
+
+    fn from_upx(px: Self::UPx, scale: crate::Fraction) -> Self {
+        Self {
+            rect: Rect::from_upx(px.rect, scale),
+            radii: CornerRadii::from_upx(px.radii, scale),
+        }
+    }
+
+    fn into_lp(self, scale: crate::Fraction) -> Self::Lp {
+        RoundedRect {
+            rect: self.rect.into_lp(scale),
+            radii: self.radii.into_lp(scale),
+        }
+    }
+
+    fn from_lp(lp: Self::Lp, scale: crate::Fraction) -> Self {
+        Self {
+            rect: Rect::from_lp(lp.rect, scale),
+            radii: CornerRadii::from_lp(lp.radii, scale),
+        }
+    }
+}
+
+impl<Unit> IntoSigned for RoundedRect<Unit>
+where
+    Unit: IntoSigned,
+{
+    type Signed = RoundedRect<Unit::Signed>;
+
+    fn into_signed(self) -> Self::Signed {
+        RoundedRect {
+            rect: self.rect.into_signed(),
+            radii: self.radii.into_signed(),
+        }
+    }
+}
+
+impl<Unit> IntoUnsigned for RoundedRect<Unit>
+where
+    Unit: IntoUnsigned,
+{
+    type Unsigned = RoundedRect<Unit::Unsigned>;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        RoundedRect {
+            rect: self.rect.into_unsigned(),
+            radii: self.radii.into_unsigned(),
+        }
+    }
+}
+
+#[test]
+fn rounded_contains() {
+    use crate::Size;
+
+    let rounded = RoundedRect::new(Rect::<i32>::new(Point::new(0, 0), Size::new(100, 100)), 10);
+    // The corner pixels are outside of the arcs.
+    assert!(!rounded.contains(Point::new(0, 0)));
+    assert!(!rounded.contains(Point::new(99, 0)));
+    assert!(!rounded.contains(Point::new(99, 99)));
+    assert!(!rounded.contains(Point::new(0, 99)));
+    // The centers of each edge and the center are contained.
+    assert!(rounded.contains(Point::new(50, 0)));
+    assert!(rounded.contains(Point::new(0, 50)));
+    assert!(rounded.contains(Point::new(50, 50)));
+    // The corner arc centers are contained.
+    assert!(rounded.contains(Point::new(10, 10)));
+}
+
+#[test]
+fn rounded_inset() {
+    use crate::Size;
+
+    let rounded = RoundedRect::new(Rect::<i32>::new(Point::new(0, 0), Size::new(100, 100)), 10);
+    let inset = rounded.inset(4);
+    assert_eq!(
+        inset,
+        RoundedRect::new(Rect::new(Point::new(4, 4), Size::new(92, 92)), 6)
+    );
+    // Insetting beyond the radius flattens the corners.
+    let flattened = rounded.inset(15);
+    assert_eq!(flattened.radii, CornerRadii::ZERO);
+}
+
+#[cfg(feature = "lyon")]
+impl<Unit> RoundedRect<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    /// Returns this rounded rectangle as a closed [`lyon_path::Path`], ready
+    /// for tessellation.
+    #[must_use]
+    pub fn to_lyon_path(self) -> lyon_path::Path {
+        let mut builder = lyon_path::Path::builder();
+        builder.add_rounded_rectangle(
+            &self.rect.into(),
+            &lyon_path::builder::BorderRadii {
+                top_left: self.radii.top_left.into_float(),
+                top_right: self.radii.top_right.into_float(),
+                bottom_left: self.radii.bottom_left.into_float(),
+                bottom_right: self.radii.bottom_right.into_float(),
+            },
+            lyon_path::Winding::Positive,
+        );
+        builder.build()
+    }
+}
+
+impl<Unit> crate::Bounded<Unit> for RoundedRect<Unit>
+where
+    Unit: Copy,
+{
+    fn bounds(&self) -> Rect<Unit> {
+        self.rect
+    }
+}
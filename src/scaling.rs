@@ -0,0 +1,221 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use crate::Fraction;
+
+/// A display scale separated into OS-provided DPI scaling and user zoom.
+///
+/// The [`ScreenScale`](crate::ScreenScale) conversions accept a single [`Fraction`] combining
+/// every source of scaling. Applications commonly track two sources
+/// separately: the scaling factor reported by the operating system for the
+/// display, and a zoom level chosen by the user. This type holds both and
+/// combines them on demand.
+///
+/// ```rust
+/// use figures::units::{Lp, Px};
+/// use figures::{Fraction, Scaling, ScreenScale};
+///
+/// let scaling = Scaling::new(Fraction::new_whole(2), Fraction::new(3, 2));
+/// assert_eq!(scaling.effective(), Fraction::new_whole(3));
+/// assert_eq!(Lp::inches(1).into_px_scaled(scaling), Px::new(96 * 3));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Scaling {
+    /// The scaling factor reported by the operating system for the display.
+    pub dpi: Fraction,
+    /// The zoom level applied by the user.
+    pub zoom: Fraction,
+}
+
+impl Scaling {
+    /// No scaling: a DPI scale of 1 and a zoom of 1.
+    pub const IDENTITY: Self = Self {
+        dpi: Fraction::ONE,
+        zoom: Fraction::ONE,
+    };
+
+    /// Returns a new scale from the provided `dpi` scale and user `zoom`.
+    #[must_use]
+    pub const fn new(dpi: Fraction, zoom: Fraction) -> Self {
+        Self { dpi, zoom }
+    }
+
+    /// Returns a new scale from the provided `dpi` scale with no user zoom.
+    #[must_use]
+    pub const fn dpi(dpi: Fraction) -> Self {
+        Self::new(dpi, Fraction::ONE)
+    }
+
+    /// Returns the combined scaling factor to pass to the
+    /// [`ScreenScale`](crate::ScreenScale) conversions.
+    #[must_use]
+    pub fn effective(self) -> Fraction {
+        self.dpi * self.zoom
+    }
+
+    /// Returns `self` with `zoom` replacing the current zoom level.
+    #[must_use]
+    pub const fn with_zoom(mut self, zoom: Fraction) -> Self {
+        self.zoom = zoom;
+        self
+    }
+}
+
+impl Default for Scaling {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Fraction> for Scaling {
+    fn from(dpi: Fraction) -> Self {
+        Self::dpi(dpi)
+    }
+}
+
+impl From<Scaling> for Fraction {
+    fn from(scaling: Scaling) -> Self {
+        scaling.effective()
+    }
+}
+
+/// The display scale of a single surface, such as a window or monitor.
+///
+/// Multi-window applications often need to move geometry between surfaces
+/// with different scaling factors. [`convert_to`](Self::convert_to) performs
+/// the conversion through [`Lp`](crate::units::Lp), the device-independent
+/// unit, so a measurement keeps its physical size when moving between
+/// surfaces.
+///
+/// ```rust
+/// use figures::units::Px;
+/// use figures::{Fraction, Point, Scaling, ScalingContext};
+///
+/// let laptop = ScalingContext::new(Scaling::dpi(Fraction::new_whole(2)));
+/// let monitor = ScalingContext::new(Scaling::dpi(Fraction::ONE));
+///
+/// let point = Point::new(Px::new(100), Px::new(50));
+/// assert_eq!(
+///     laptop.convert_to(&monitor, point),
+///     Point::new(Px::new(50), Px::new(25))
+/// );
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ScalingContext {
+    /// The scale of this surface.
+    pub scale: Scaling,
+}
+
+impl ScalingContext {
+    /// Returns a new context for a surface displayed with `scale`.
+    #[must_use]
+    pub const fn new(scale: Scaling) -> Self {
+        Self { scale }
+    }
+
+    /// Converts `value` from this context's scale to `target`'s scale.
+    ///
+    /// The value passes through its [`Lp`](crate::units::Lp) representation,
+    /// preserving its physical size across the two surfaces.
+    pub fn convert_to<T>(&self, target: &Self, value: T) -> T
+    where
+        T: crate::ScreenScale,
+    {
+        T::from_lp(
+            value.into_lp(self.scale.effective()),
+            target.scale.effective(),
+        )
+    }
+}
+
+impl From<Scaling> for ScalingContext {
+    fn from(scale: Scaling) -> Self {
+        Self::new(scale)
+    }
+}
+
+/// A registry of [`ScalingContext`]s keyed by name.
+///
+/// This provides a home for per-window or per-monitor scales, letting
+/// geometry be converted between any two registered surfaces.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScalingRegistry {
+    contexts: BTreeMap<String, ScalingContext>,
+}
+
+impl ScalingRegistry {
+    /// Returns an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `scale` for the surface named `name`, replacing any
+    /// previously registered scale.
+    pub fn insert(&mut self, name: impl Into<String>, scale: impl Into<ScalingContext>) {
+        self.contexts.insert(name.into(), scale.into());
+    }
+
+    /// Removes and returns the context registered for `name`.
+    pub fn remove(&mut self, name: &str) -> Option<ScalingContext> {
+        self.contexts.remove(name)
+    }
+
+    /// Returns the context registered for `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<ScalingContext> {
+        self.contexts.get(name).copied()
+    }
+
+    /// Converts `value` from the scale registered for `from` to the scale
+    /// registered for `to`.
+    ///
+    /// Returns `None` if either name has no registered scale.
+    pub fn convert<T>(&self, from: &str, to: &str, value: T) -> Option<T>
+    where
+        T: crate::ScreenScale,
+    {
+        Some(self.get(from)?.convert_to(&self.get(to)?, value))
+    }
+}
+
+#[test]
+fn context_conversions() {
+    use crate::units::Px;
+    use crate::Point;
+
+    let mut registry = ScalingRegistry::new();
+    registry.insert("laptop", Scaling::dpi(Fraction::new_whole(2)));
+    registry.insert("monitor", ScalingContext::default());
+
+    let point = Point::new(Px::new(100), Px::new(50));
+    assert_eq!(
+        registry.convert("laptop", "monitor", point),
+        Some(Point::new(Px::new(50), Px::new(25)))
+    );
+    assert_eq!(
+        registry.convert("monitor", "laptop", point),
+        Some(Point::new(Px::new(200), Px::new(100)))
+    );
+    assert_eq!(registry.convert::<Point<Px>>("laptop", "tv", point), None);
+
+    assert!(registry.remove("laptop").is_some());
+    assert_eq!(registry.get("laptop"), None);
+}
+
+#[test]
+fn scaling_conversions() {
+    use crate::units::{Lp, Px};
+    use crate::ScreenScale;
+
+    let scaling = Scaling::dpi(Fraction::new_whole(2)).with_zoom(Fraction::new(1, 2));
+    assert_eq!(scaling.effective(), Fraction::ONE);
+    assert_eq!(Lp::inches(1).into_px_scaled(scaling), Px::new(96));
+    assert_eq!(Lp::from_px_scaled(Px::new(96), scaling), Lp::inches(1),);
+    assert_eq!(Scaling::default(), Scaling::IDENTITY);
+    assert_eq!(Fraction::from(scaling), Fraction::ONE);
+}
@@ -0,0 +1,237 @@
+//! Serialization helpers for the unit types.
+//!
+//! The [`Serialize`](serde::Serialize) implementations for the unit types
+//! write the logical value as a float in human-readable formats, so that
+//! `Px::new(3)` appears as `3.0` in a config file rather than its internal
+//! scaled representation. The [`compact`] module opts back into the raw form.
+
+use alloc::string::String;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use crate::{Point, Rect, Size};
+
+/// Implements [`Deserialize`] for a two-component type, accepting either the
+/// struct-map form or a sequence of the two components.
+macro_rules! impl_two_component_deserialize {
+    ($type:ident, $first:ident, $second:ident) => {
+        impl<'de, Unit> Deserialize<'de> for $type<Unit>
+        where
+            Unit: Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct ComponentsVisitor<Unit>(PhantomData<Unit>);
+
+                impl<'de, Unit> Visitor<'de> for ComponentsVisitor<Unit>
+                where
+                    Unit: Deserialize<'de>,
+                {
+                    type Value = $type<Unit>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(concat!(
+                            "a ",
+                            stringify!($type),
+                            " or a sequence of two values"
+                        ))
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        let $first = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                        let $second = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                        Ok($type { $first, $second })
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: MapAccess<'de>,
+                    {
+                        let mut $first = None;
+                        let mut $second = None;
+                        while let Some(key) = map.next_key::<String>()? {
+                            match key.as_str() {
+                                stringify!($first) => {
+                                    if $first.is_some() {
+                                        return Err(de::Error::duplicate_field(stringify!($first)));
+                                    }
+                                    $first = Some(map.next_value()?);
+                                }
+                                stringify!($second) => {
+                                    if $second.is_some() {
+                                        return Err(de::Error::duplicate_field(stringify!(
+                                            $second
+                                        )));
+                                    }
+                                    $second = Some(map.next_value()?);
+                                }
+                                _ => {
+                                    return Err(de::Error::unknown_field(
+                                        &key,
+                                        &[stringify!($first), stringify!($second)],
+                                    ))
+                                }
+                            }
+                        }
+                        Ok($type {
+                            $first: $first
+                                .ok_or_else(|| de::Error::missing_field(stringify!($first)))?,
+                            $second: $second
+                                .ok_or_else(|| de::Error::missing_field(stringify!($second)))?,
+                        })
+                    }
+                }
+
+                deserializer.deserialize_struct(
+                    stringify!($type),
+                    &[stringify!($first), stringify!($second)],
+                    ComponentsVisitor(PhantomData),
+                )
+            }
+        }
+    };
+}
+
+impl_two_component_deserialize!(Point, x, y);
+impl_two_component_deserialize!(Size, width, height);
+
+impl<'de, Unit> Deserialize<'de> for Rect<Unit>
+where
+    Unit: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RectVisitor<Unit>(PhantomData<Unit>);
+
+        impl<'de, Unit> Visitor<'de> for RectVisitor<Unit>
+        where
+            Unit: Deserialize<'de>,
+        {
+            type Value = Rect<Unit>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a Rect or a sequence of x, y, width, and height")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let width = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let height = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(Rect {
+                    origin: Point { x, y },
+                    size: Size { width, height },
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut origin = None;
+                let mut size = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "origin" => {
+                            if origin.is_some() {
+                                return Err(de::Error::duplicate_field("origin"));
+                            }
+                            origin = Some(map.next_value()?);
+                        }
+                        "size" => {
+                            if size.is_some() {
+                                return Err(de::Error::duplicate_field("size"));
+                            }
+                            size = Some(map.next_value()?);
+                        }
+                        _ => return Err(de::Error::unknown_field(&key, &["origin", "size"])),
+                    }
+                }
+                Ok(Rect {
+                    origin: origin.ok_or_else(|| de::Error::missing_field("origin"))?,
+                    size: size.ok_or_else(|| de::Error::missing_field("size"))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("Rect", &["origin", "size"], RectVisitor(PhantomData))
+    }
+}
+
+/// Serializes units as their raw scaled integer representation.
+///
+/// This module is designed for use with `#[serde(with = "...")]`:
+///
+/// ```rust
+/// use figures::units::Px;
+///
+/// #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+/// struct Widget {
+///     #[serde(with = "figures::serde::compact")]
+///     width: Px,
+/// }
+///
+/// let widget = Widget { width: Px::new(3) };
+/// let json = serde_json::to_string(&widget).unwrap();
+/// // The raw form is the logical value times the unit's scaling factor.
+/// assert_eq!(json, r#"{"width":12}"#);
+/// assert_eq!(serde_json::from_str::<Widget>(&json).unwrap(), widget);
+/// ```
+pub mod compact {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::traits::UnscaledUnit;
+
+    /// Serializes `unit` as its raw scaled integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying serializer.
+    pub fn serialize<Unit, S>(unit: &Unit, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        Unit: UnscaledUnit + Copy,
+        Unit::Representation: Serialize,
+        S: Serializer,
+    {
+        unit.into_unscaled().serialize(serializer)
+    }
+
+    /// Deserializes a unit from its raw scaled integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying deserializer.
+    pub fn deserialize<'de, Unit, D>(deserializer: D) -> Result<Unit, D::Error>
+    where
+        Unit: UnscaledUnit,
+        Unit::Representation: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Unit::Representation::deserialize(deserializer).map(Unit::from_unscaled)
+    }
+}
@@ -1,13 +1,19 @@
-use std::cmp::Ordering;
-use std::ops::Mul;
+use core::cmp::Ordering;
+use core::ops::{Add, Mul};
 
-use crate::traits::{IntoComponents, StdNumOps};
+#[cfg(feature = "winit")]
+use crate::traits::FloatConversion;
+use crate::traits::{CheckedNumOps, IntoComponents, StdNumOps, WideMul};
 use crate::utils::vec_ord;
-use crate::Point;
+#[cfg(feature = "winit")]
+use crate::Fraction;
+use crate::{Point, Zero};
 
 /// A width and a height measurement.
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[repr(C)]
 pub struct Size<Unit> {
     /// The width component
     pub width: Unit,
@@ -15,6 +21,14 @@ pub struct Size<Unit> {
     pub height: Unit,
 }
 
+impl<Unit> Size<Unit>
+where
+    Unit: Zero,
+{
+    /// A size with no width or height.
+    pub const EMPTY: Self = Self::new(Unit::ZERO, Unit::ZERO);
+}
+
 impl<Unit> Size<Unit> {
     /// Returns a new size of the given `width` and `height`.
     pub const fn new(width: Unit, height: Unit) -> Self {
@@ -32,6 +46,25 @@ impl<Unit> Size<Unit> {
         }
     }
 
+    /// Returns the componentwise minimum and maximum of `self` and `other` as
+    /// a `(minimum, maximum)` pair.
+    #[must_use]
+    pub fn min_max(self, other: Self) -> (Self, Self)
+    where
+        Unit: Ord + Copy,
+    {
+        (
+            Self {
+                width: self.width.min(other.width),
+                height: self.height.min(other.height),
+            },
+            Self {
+                width: self.width.max(other.width),
+                height: self.height.max(other.height),
+            },
+        )
+    }
+
     /// Returns the area of the rectangle.
     pub fn area(&self) -> <Unit as Mul>::Output
     where
@@ -40,6 +73,21 @@ impl<Unit> Size<Unit> {
         self.width * self.height
     }
 
+    /// Returns the area of the rectangle, widened so the multiplication
+    /// cannot overflow.
+    ///
+    /// [`Size::area`] returns `Unit`, which can overflow for large integer
+    /// sizes. This function returns the unit's widened type instead: `i64`
+    /// for the signed units and `u64` for [`UPx`](crate::units::UPx). For
+    /// the scaled unit types, the result is expressed in the unit's scaled
+    /// representation, matching `Size::area(..).into_unscaled()`.
+    pub fn area_wide(&self) -> <Unit as WideMul>::Wide
+    where
+        Unit: WideMul + Copy,
+    {
+        self.width.wide_mul(self.height)
+    }
+
     /// Converts the contents of this size to `NewUnit` using [`From`].
     pub fn cast<NewUnit>(self) -> Size<NewUnit>
     where
@@ -51,6 +99,12 @@ impl<Unit> Size<Unit> {
         }
     }
 
+    /// Returns a new size with the `width` and `height` components swapped.
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        Self::new(self.height, self.width)
+    }
+
     /// Maps each component to `map` and returns a new value with the mapped
     /// components.
     #[must_use]
@@ -78,9 +132,178 @@ impl<Unit> Size<Unit> {
     }
 }
 
+impl<Unit> Size<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32> + crate::Zero + Copy,
+{
+    /// Returns the largest size with this size's aspect ratio that fits
+    /// entirely within `container`.
+    ///
+    /// If either of this size's dimensions is zero, this size is returned
+    /// unchanged.
+    #[must_use]
+    pub fn scale_to_fit(self, container: Self) -> Self {
+        self.scale_by_ratio(container, f32::min)
+    }
+
+    /// Returns the smallest size with this size's aspect ratio that fully
+    /// covers `container`.
+    ///
+    /// If either of this size's dimensions is zero, this size is returned
+    /// unchanged.
+    #[must_use]
+    pub fn scale_to_fill(self, container: Self) -> Self {
+        self.scale_by_ratio(container, f32::max)
+    }
+
+    /// Returns the largest size with aspect ratio `ratio` that fits entirely
+    /// within this size.
+    #[must_use]
+    pub fn fit_aspect_ratio(self, ratio: crate::AspectRatio) -> Self
+    where
+        Unit: Mul<crate::Fraction, Output = Unit>
+            + core::ops::Div<crate::Fraction, Output = Unit>
+            + Ord,
+    {
+        ratio.constrain(self)
+    }
+
+    fn scale_by_ratio(self, container: Self, pick: impl FnOnce(f32, f32) -> f32) -> Self {
+        if self.width.is_zero() || self.height.is_zero() {
+            return self;
+        }
+        let width = self.width.into_float();
+        let height = self.height.into_float();
+        let scale = pick(
+            container.width.into_float() / width,
+            container.height.into_float() / height,
+        );
+        Self::new(
+            Unit::from_float(width * scale),
+            Unit::from_float(height * scale),
+        )
+    }
+}
+
+impl Size<f32> {
+    /// Returns true if both components are neither infinite nor NaN.
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.width.is_finite() && self.height.is_finite()
+    }
+
+    /// Returns true if either component is NaN.
+    #[must_use]
+    pub fn is_nan(self) -> bool {
+        self.width.is_nan() || self.height.is_nan()
+    }
+
+    /// Returns this size with any NaN component replaced with `default`.
+    #[must_use]
+    pub fn replace_nan(self, default: f32) -> Self {
+        self.map(|value| if value.is_nan() { default } else { value })
+    }
+
+    /// Returns this size's components as canonical bit patterns, suitable
+    /// for hashing.
+    ///
+    /// The bits normalize negative zero to positive zero and collapse every
+    /// NaN to one bit pattern. See [`Point::canonical_bits`] for the full
+    /// semantics.
+    ///
+    /// [`Point::canonical_bits`]: crate::Point::canonical_bits
+    #[must_use]
+    pub fn canonical_bits(self) -> [u32; 2] {
+        [
+            crate::utils::canonical_f32_bits(self.width),
+            crate::utils::canonical_f32_bits(self.height),
+        ]
+    }
+}
+
+impl Size<crate::units::Em> {
+    /// Resolves this font-relative size against `font_size`.
+    ///
+    /// See [`Em::resolve`](crate::units::Em::resolve) for the resolution
+    /// rules.
+    #[must_use]
+    pub fn resolve(self, font_size: crate::units::Lp) -> Size<crate::units::Lp> {
+        self.map(|component| component.resolve(font_size))
+    }
+}
+
+impl Size<crate::units::UPx> {
+    /// Returns the componentwise positive difference between `self` and
+    /// `other`.
+    ///
+    /// Unlike subtraction, this cannot overflow when a component of `other`
+    /// is larger than the matching component of `self`.
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Self {
+        Self::new(
+            self.width.abs_diff(other.width),
+            self.height.abs_diff(other.height),
+        )
+    }
+
+    /// Returns `self - other` with signed components.
+    ///
+    /// Deltas too large for [`Px`](crate::units::Px) are saturated to its
+    /// range.
+    #[must_use]
+    pub fn signed_delta(self, other: Self) -> Size<crate::units::Px> {
+        Size::new(
+            self.width.signed_delta(other.width),
+            self.height.signed_delta(other.height),
+        )
+    }
+}
+
+impl Size<crate::units::Lp> {
+    /// Converts this size into device pixels using the provided `scale`
+    /// factor, returning the converted size and the maximum per-component
+    /// rounding error as a [`Px`](crate::units::Px) value.
+    #[must_use]
+    pub fn into_px_with_error(
+        self,
+        scale: crate::Fraction,
+    ) -> (Size<crate::units::Px>, crate::units::Px) {
+        let (width, width_error) = self.width.into_px_with_error(scale);
+        let (height, height_error) = self.height.into_px_with_error(scale);
+        (Size::new(width, height), width_error.max(height_error))
+    }
+}
+
+impl<Unit> core::iter::Sum for Size<Unit>
+where
+    Unit: Zero + Add<Output = Unit>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, Unit> core::iter::Sum<&'a Size<Unit>> for Size<Unit>
+where
+    Unit: Zero + Add<Output = Unit> + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<Unit> core::fmt::Display for Size<Unit>
+where
+    Unit: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} x {}", self.width, self.height)
+    }
+}
+
 impl<Unit> Ord for Size<Unit>
 where
-    Unit: Ord + Mul<Output = Unit> + Copy,
+    Unit: Ord + WideMul + Copy,
 {
     fn cmp(&self, other: &Self) -> Ordering {
         vec_ord::<Unit>((*self).into_components(), (*other).into_components())
@@ -119,7 +342,7 @@ where
 
 impl<Unit> PartialOrd for Size<Unit>
 where
-    Unit: Ord + Mul<Output = Unit> + Copy,
+    Unit: Ord + WideMul + Copy,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -127,6 +350,9 @@ where
 }
 
 impl_2d_math!(Size, width, height);
+impl_2d_const_math!(Size, width, height, Px);
+impl_2d_const_math!(Size, width, height, UPx);
+impl_2d_const_math!(Size, width, height, Lp);
 
 impl<Unit> From<Size<Unit>> for Point<Unit> {
     fn from(value: Size<Unit>) -> Self {
@@ -140,35 +366,229 @@ impl<Unit> From<Point<Unit>> for Size<Unit> {
     }
 }
 
+#[cfg(feature = "euclid")]
+impl<Unit> From<euclid::Size2D<f32, euclid::UnknownUnit>> for Size<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(size: euclid::Size2D<f32, euclid::UnknownUnit>) -> Self {
+        Self {
+            width: Unit::from_float(size.width),
+            height: Unit::from_float(size.height),
+        }
+    }
+}
+#[cfg(feature = "euclid")]
+impl<Unit> From<Size<Unit>> for euclid::Size2D<f32, euclid::UnknownUnit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(size: Size<Unit>) -> Self {
+        Self::new(size.width.into_float(), size.height.into_float())
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<Unit> From<mint::Vector2<f32>> for Size<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(size: mint::Vector2<f32>) -> Self {
+        Self {
+            width: Unit::from_float(size.x),
+            height: Unit::from_float(size.y),
+        }
+    }
+}
+#[cfg(feature = "mint")]
+impl<Unit> From<Size<Unit>> for mint::Vector2<f32>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(size: Size<Unit>) -> Self {
+        Self {
+            x: size.width.into_float(),
+            y: size.height.into_float(),
+        }
+    }
+}
+
+#[cfg(feature = "taffy")]
+impl<Unit> From<taffy::geometry::Size<f32>> for Size<Unit>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(size: taffy::geometry::Size<f32>) -> Self {
+        Self {
+            width: Unit::from_float(size.width),
+            height: Unit::from_float(size.height),
+        }
+    }
+}
+#[cfg(feature = "taffy")]
+impl<Unit> From<Size<Unit>> for taffy::geometry::Size<f32>
+where
+    Unit: crate::traits::FloatConversion<Float = f32>,
+{
+    fn from(size: Size<Unit>) -> Self {
+        Self {
+            width: size.width.into_float(),
+            height: size.height.into_float(),
+        }
+    }
+}
+
+#[cfg(feature = "taffy")]
+impl<Unit> Size<Unit>
+where
+    Unit: crate::traits::ScreenScale<
+        Lp = crate::units::Lp,
+        Px = crate::units::Px,
+        UPx = crate::units::UPx,
+    >,
+{
+    /// Converts this size into device pixels using `scale` and returns the
+    /// result as a [`taffy::geometry::Size`].
+    #[must_use]
+    pub fn to_taffy(self, scale: crate::Fraction) -> taffy::geometry::Size<f32> {
+        use crate::traits::ScreenScale;
+        self.into_px(scale).into()
+    }
+
+    /// Converts `size` from device pixels measured by taffy into this unit
+    /// using `scale`.
+    #[must_use]
+    pub fn from_taffy(size: taffy::geometry::Size<f32>, scale: crate::Fraction) -> Self {
+        use crate::traits::ScreenScale;
+        Self::from_px(Size::from(size), scale)
+    }
+}
+
 #[cfg(feature = "wgpu")]
 impl From<Size<crate::units::UPx>> for wgpu::Extent3d {
     fn from(value: Size<crate::units::UPx>) -> Self {
-        Self {
-            width: value.width.into(),
-            height: value.height.into(),
-            depth_or_array_layers: 1,
+        value.to_extent3d(1)
+    }
+}
+
+#[cfg(feature = "wgpu")]
+impl Size<crate::units::UPx> {
+    /// Returns this size as a [`wgpu::Extent3d`] with `array_layers` layers.
+    ///
+    /// The [`From`] implementation for [`wgpu::Extent3d`] produces an extent
+    /// with a single layer.
+    #[must_use]
+    pub fn to_extent3d(self, array_layers: u32) -> wgpu::Extent3d {
+        wgpu::Extent3d {
+            width: self.width.into(),
+            height: self.height.into(),
+            depth_or_array_layers: array_layers,
         }
     }
 }
 
+/// The error returned when a winit size is too large to represent.
 #[cfg(feature = "winit")]
-impl From<winit::dpi::PhysicalSize<u32>> for Size<crate::units::UPx> {
-    fn from(value: winit::dpi::PhysicalSize<u32>) -> Self {
-        Self {
-            width: value.width.try_into().expect("width too large"),
-            height: value.height.try_into().expect("height too large"),
-        }
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct SizeOverflow;
+
+#[cfg(feature = "winit")]
+impl core::fmt::Display for SizeOverflow {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("size dimension too large to represent")
     }
 }
 
 #[cfg(feature = "winit")]
-impl From<winit::dpi::PhysicalSize<i32>> for Size<crate::units::Px> {
-    fn from(value: winit::dpi::PhysicalSize<i32>) -> Self {
+#[cfg(feature = "std")]
+impl std::error::Error for SizeOverflow {}
+
+#[cfg(feature = "winit")]
+impl TryFrom<winit::dpi::PhysicalSize<u32>> for Size<crate::units::UPx> {
+    type Error = SizeOverflow;
+
+    fn try_from(value: winit::dpi::PhysicalSize<u32>) -> Result<Self, Self::Error> {
+        Self::try_from_winit(value)
+    }
+}
+
+#[cfg(feature = "winit")]
+impl TryFrom<winit::dpi::PhysicalSize<i32>> for Size<crate::units::Px> {
+    type Error = SizeOverflow;
+
+    fn try_from(value: winit::dpi::PhysicalSize<i32>) -> Result<Self, Self::Error> {
+        Self::try_from_winit(value)
+    }
+}
+
+#[cfg(feature = "winit")]
+impl Size<crate::units::UPx> {
+    /// Returns `size` converted into unsigned pixels, or an error if either
+    /// dimension is too large to represent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeOverflow`] if either dimension cannot be represented.
+    pub fn try_from_winit(size: winit::dpi::PhysicalSize<u32>) -> Result<Self, SizeOverflow> {
+        Ok(Self {
+            width: crate::units::UPx::checked_new(size.width).ok_or(SizeOverflow)?,
+            height: crate::units::UPx::checked_new(size.height).ok_or(SizeOverflow)?,
+        })
+    }
+}
+
+#[cfg(feature = "winit")]
+impl Size<crate::units::Px> {
+    /// Returns `size` converted into signed pixels, or an error if either
+    /// dimension is too large to represent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeOverflow`] if either dimension cannot be represented.
+    pub fn try_from_winit(size: winit::dpi::PhysicalSize<i32>) -> Result<Self, SizeOverflow> {
+        Ok(Self {
+            width: crate::units::Px::checked_new(size.width).ok_or(SizeOverflow)?,
+            height: crate::units::Px::checked_new(size.height).ok_or(SizeOverflow)?,
+        })
+    }
+}
+
+#[cfg(feature = "winit")]
+impl<Unit> Size<Unit>
+where
+    Unit: crate::traits::ScreenScale<
+        Lp = crate::units::Lp,
+        Px = crate::units::Px,
+        UPx = crate::units::UPx,
+    >,
+{
+    /// Returns `size` converted into this unit using the provided `scale`
+    /// factor.
+    ///
+    /// Winit's logical units are device-independent pixels, the same size as
+    /// [`Lp`](crate::units::Lp)'s logical pixels.
+    #[must_use]
+    pub fn from_logical(size: winit::dpi::LogicalSize<f64>, scale: Fraction) -> Self {
         Self {
-            width: value.width.try_into().expect("width too large"),
-            height: value.height.try_into().expect("height too large"),
+            width: Unit::from_lp(
+                crate::units::Lp::from_float(intentional::CastFrom::from_cast(size.width)),
+                scale,
+            ),
+            height: Unit::from_lp(
+                crate::units::Lp::from_float(intentional::CastFrom::from_cast(size.height)),
+                scale,
+            ),
         }
     }
+
+    /// Returns this size converted into a winit logical size using the
+    /// provided `scale` factor.
+    pub fn to_logical(self, scale: Fraction) -> winit::dpi::LogicalSize<f64> {
+        winit::dpi::LogicalSize::new(
+            f64::from(self.width.into_lp(scale).into_float()),
+            f64::from(self.height.into_lp(scale).into_float()),
+        )
+    }
 }
 
 #[cfg(feature = "winit")]
@@ -191,6 +611,39 @@ impl From<Size<crate::units::Px>> for winit::dpi::PhysicalSize<i32> {
     }
 }
 
+impl<T> CheckedNumOps for Size<T>
+where
+    T: CheckedNumOps,
+{
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.width.checked_add(other.width)?,
+            self.height.checked_add(other.height)?,
+        ))
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.width.checked_sub(other.width)?,
+            self.height.checked_sub(other.height)?,
+        ))
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.width.checked_mul(other.width)?,
+            self.height.checked_mul(other.height)?,
+        ))
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.width.checked_div(other.width)?,
+            self.height.checked_div(other.height)?,
+        ))
+    }
+}
+
 impl<T> StdNumOps for Size<T>
 where
     T: StdNumOps,
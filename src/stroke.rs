@@ -0,0 +1,241 @@
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+
+// The test harness links `std`, which makes the inherent float methods
+// available even without the `std` feature enabled.
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::utils::FloatExt;
+use crate::Point;
+
+/// The maximum angle, in radians, covered by a single segment of a round
+/// join or cap.
+const ROUND_STEP: f32 = PI / 8.;
+/// The miter length limit, as a multiple of half the stroke width, beyond
+/// which a miter join falls back to a bevel join.
+const MITER_LIMIT: f32 = 4.;
+
+/// The style used to connect segments of a stroked polyline.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Join {
+    /// Segments are extended until their outer edges meet in a point.
+    ///
+    /// Sharp turns would produce arbitrarily long points, so joins whose
+    /// miter length exceeds four times half the stroke width fall back to
+    /// [`Join::Bevel`].
+    #[default]
+    Miter,
+    /// The corner between segments is cut off with a straight edge.
+    Bevel,
+    /// The corner between segments is rounded with an arc.
+    Round,
+}
+
+/// The style used to terminate the ends of a stroked polyline.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Cap {
+    /// The stroke stops flush with the end of the polyline.
+    #[default]
+    Butt,
+    /// The stroke extends past the end of the polyline by half the stroke
+    /// width.
+    Square,
+    /// The end of the polyline is rounded with a semicircle.
+    Round,
+}
+
+/// Returns a triangle strip covering `points` stroked with `width`.
+///
+/// The returned points alternate between the two sides of the stroke, ready
+/// to be rendered as a triangle strip. Consecutive duplicate points are
+/// skipped, and an empty strip is returned when fewer than two distinct
+/// points or a non-positive width is provided.
+///
+/// Round joins and caps are approximated with arc segments no wider than
+/// 22.5 degrees. On the inside of a round join the strip folds back over
+/// itself slightly; this is harmless for opaque strokes, but translucent
+/// strokes may show the overlap.
+#[must_use]
+pub fn stroke_polyline(points: &[Point<f32>], width: f32, join: Join, cap: Cap) -> Vec<Point<f32>> {
+    let half = width / 2.;
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() < 2 || half <= 0. {
+        return Vec::new();
+    }
+
+    if matches!(cap, Cap::Square) {
+        let start_direction = direction(points[0], points[1]);
+        points[0] -= start_direction * half;
+        let end_direction = direction(points[points.len() - 2], points[points.len() - 1]);
+        let last = points.len() - 1;
+        points[last] += end_direction * half;
+    }
+
+    let mut strip = Vec::new();
+    let start_normal = normal(direction(points[0], points[1]));
+    if matches!(cap, Cap::Round) {
+        push_fan(&mut strip, points[0], -start_normal, start_normal, half);
+    }
+    push_pair(&mut strip, points[0], start_normal, half);
+
+    for index in 1..points.len() - 1 {
+        let incoming = normal(direction(points[index - 1], points[index]));
+        let outgoing = normal(direction(points[index], points[index + 1]));
+        match join {
+            Join::Miter => {
+                let miter = (incoming + outgoing).normalized();
+                let stretch = miter.dot(incoming);
+                if stretch * MITER_LIMIT >= 1. {
+                    push_pair(&mut strip, points[index], miter / stretch, half);
+                } else {
+                    push_pair(&mut strip, points[index], incoming, half);
+                    push_pair(&mut strip, points[index], outgoing, half);
+                }
+            }
+            Join::Bevel => {
+                push_pair(&mut strip, points[index], incoming, half);
+                push_pair(&mut strip, points[index], outgoing, half);
+            }
+            Join::Round => {
+                push_arc(&mut strip, points[index], incoming, outgoing, half);
+            }
+        }
+    }
+
+    let end_normal = normal(direction(
+        points[points.len() - 2],
+        points[points.len() - 1],
+    ));
+    push_pair(&mut strip, points[points.len() - 1], end_normal, half);
+    if matches!(cap, Cap::Round) {
+        push_fan(
+            &mut strip,
+            points[points.len() - 1],
+            end_normal,
+            -end_normal,
+            half,
+        );
+    }
+    strip
+}
+
+/// Returns the normalized direction from `from` to `to`.
+fn direction(from: Point<f32>, to: Point<f32>) -> Point<f32> {
+    (to - from).normalized()
+}
+
+/// Returns `direction` rotated a quarter turn.
+fn normal(direction: Point<f32>) -> Point<f32> {
+    Point::new(-direction.y, direction.x)
+}
+
+/// Pushes the pair of points offset from `center` by `offset * half` to
+/// either side.
+fn push_pair(strip: &mut Vec<Point<f32>>, center: Point<f32>, offset: Point<f32>, half: f32) {
+    strip.push(center + offset * half);
+    strip.push(center - offset * half);
+}
+
+/// Pushes pairs sweeping both sides of the stroke from the `from` normal to
+/// the `to` normal.
+fn push_arc(
+    strip: &mut Vec<Point<f32>>,
+    center: Point<f32>,
+    from: Point<f32>,
+    to: Point<f32>,
+    half: f32,
+) {
+    for offset in arc_offsets(from, to) {
+        push_pair(strip, center, offset, half);
+    }
+}
+
+/// Pushes a fan of triangles pivoting on `center` sweeping from the `from`
+/// offset to the `to` offset, for round caps.
+fn push_fan(
+    strip: &mut Vec<Point<f32>>,
+    center: Point<f32>,
+    from: Point<f32>,
+    to: Point<f32>,
+    half: f32,
+) {
+    for offset in arc_offsets(from, to) {
+        strip.push(center + offset * half);
+        strip.push(center);
+    }
+}
+
+/// Returns unit offsets sweeping from `from` to `to` along the shorter arc,
+/// including both endpoints.
+fn arc_offsets(from: Point<f32>, to: Point<f32>) -> Vec<Point<f32>> {
+    let start = from.y.atan2(from.x);
+    let mut sweep = to.y.atan2(to.x) - start;
+    if sweep > PI {
+        sweep -= 2. * PI;
+    } else if sweep < -PI {
+        sweep += 2. * PI;
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let steps = (sweep.abs() / ROUND_STEP).ceil().max(1.) as u32;
+    (0..=steps)
+        .map(|step| {
+            #[allow(clippy::cast_precision_loss)]
+            let angle = start + sweep * step as f32 / steps as f32;
+            Point::new(angle.cos(), angle.sin())
+        })
+        .collect()
+}
+
+#[test]
+fn butt_stroke_is_a_quad() {
+    use alloc::vec;
+
+    let strip = stroke_polyline(
+        &[Point::new(0., 0.), Point::new(10., 0.)],
+        2.,
+        Join::Miter,
+        Cap::Butt,
+    );
+    assert_eq!(
+        strip,
+        vec![
+            Point::new(0., 1.),
+            Point::new(0., -1.),
+            Point::new(10., 1.),
+            Point::new(10., -1.),
+        ]
+    );
+}
+
+#[test]
+fn square_caps_extend_the_ends() {
+    let strip = stroke_polyline(
+        &[Point::new(0., 0.), Point::new(10., 0.)],
+        2.,
+        Join::Miter,
+        Cap::Square,
+    );
+    assert_eq!(strip[0], Point::new(-1., 1.));
+    assert_eq!(strip[3], Point::new(11., -1.));
+}
+
+#[test]
+fn joins() {
+    let corner = [
+        Point::new(0., 0.),
+        Point::new(10., 0.),
+        Point::new(10., 10.),
+    ];
+    // A right angle is within the miter limit, producing a single pair at
+    // the joint.
+    let mitered = stroke_polyline(&corner, 2., Join::Miter, Cap::Butt);
+    assert_eq!(mitered.len(), 6);
+    assert_eq!(mitered[2], Point::new(9., 1.));
+    assert_eq!(mitered[3], Point::new(11., -1.));
+    // A bevel cuts the corner with an extra pair.
+    let beveled = stroke_polyline(&corner, 2., Join::Bevel, Cap::Butt);
+    assert_eq!(beveled.len(), 8);
+    // A round join subdivides the quarter turn.
+    let rounded = stroke_polyline(&corner, 2., Join::Round, Cap::Butt);
+    assert!(rounded.len() > beveled.len());
+}
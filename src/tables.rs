@@ -1,9 +1,9 @@
 use crate::Fraction;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[allow(clippy::items_after_test_module)]
 mod gen_and_test {
-    use std::f32::consts::PI;
+    use core::f32::consts::PI;
 
     use crate::tables::ARCTAN_SUBDIVISIONS;
     use crate::{Angle, Fraction};
@@ -107,9 +107,28 @@ mod gen_and_test {
             // }
         }
     }
+
+    #[test]
+    fn arcsin_table() {
+        use crate::tables::ARCSIN_SUBDIVISIONS;
+        for i in 0..=ARCSIN_SUBDIVISIONS {
+            let asin = (f32::from(i) / f32::from(ARCSIN_SUBDIVISIONS)).asin();
+            let fraction = Fraction::from(asin);
+            if fraction.denominator() == 1 {
+                println!("Fraction::new_whole({}),", fraction.numerator());
+            } else {
+                println!(
+                    "Fraction::new_maybe_reduced({}, {}),",
+                    fraction.numerator(),
+                    fraction.denominator()
+                );
+            }
+        }
+    }
 }
 
 pub const ARCTAN_SUBDIVISIONS: i16 = 2 * 3 * 7 * 11;
+pub const ARCSIN_SUBDIVISIONS: i16 = 2 * 3 * 7 * 11;
 #[allow(clippy::cast_sign_loss)] // We are only doing this when looking up into the table.
 #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)] // None of the tables are large enough to cause issues with usize.
 pub fn approximate_via_lookup_table<const N: usize>(
@@ -1708,3 +1727,469 @@ pub static ARCTAN_TABLE: [Fraction; (ARCTAN_SUBDIVISIONS + 1) as usize] = [
     Fraction::new_maybe_reduced(13091, 16691),
     Fraction::new_maybe_reduced(355, 452),
 ];
+
+pub static ARCSIN_TABLE: [Fraction; (ARCSIN_SUBDIVISIONS + 1) as usize] = [
+    Fraction::new_whole(0),
+    Fraction::new_maybe_reduced(1, 462),
+    Fraction::new_maybe_reduced(1, 231),
+    Fraction::new_maybe_reduced(1, 154),
+    Fraction::new_maybe_reduced(2, 231),
+    Fraction::new_maybe_reduced(221, 20420),
+    Fraction::new_maybe_reduced(1, 77),
+    Fraction::new_maybe_reduced(395, 26069),
+    Fraction::new_maybe_reduced(87, 5024),
+    Fraction::new_maybe_reduced(103, 5287),
+    Fraction::new_maybe_reduced(443, 20465),
+    Fraction::new_maybe_reduced(252, 10583),
+    Fraction::new_maybe_reduced(462, 17785),
+    Fraction::new_maybe_reduced(607, 21569),
+    Fraction::new_maybe_reduced(198, 6533),
+    Fraction::new_maybe_reduced(628, 19339),
+    Fraction::new_maybe_reduced(130, 3753),
+    Fraction::new_maybe_reduced(681, 18503),
+    Fraction::new_maybe_reduced(821, 21067),
+    Fraction::new_maybe_reduced(806, 19593),
+    Fraction::new_maybe_reduced(97, 2240),
+    Fraction::new_maybe_reduced(1319, 29008),
+    Fraction::new_maybe_reduced(1385, 29074),
+    Fraction::new_maybe_reduced(89, 1787),
+    Fraction::new_maybe_reduced(779, 14989),
+    Fraction::new_maybe_reduced(913, 16864),
+    Fraction::new_maybe_reduced(254, 4511),
+    Fraction::new_maybe_reduced(661, 11304),
+    Fraction::new_maybe_reduced(445, 7338),
+    Fraction::new_maybe_reduced(214, 3407),
+    Fraction::new_maybe_reduced(203, 3124),
+    Fraction::new_maybe_reduced(991, 14758),
+    Fraction::new_maybe_reduced(1249, 18018),
+    Fraction::new_maybe_reduced(671, 9386),
+    Fraction::new_maybe_reduced(158, 2145),
+    Fraction::new_maybe_reduced(427, 5631),
+    Fraction::new_maybe_reduced(679, 8705),
+    Fraction::new_maybe_reduced(2492, 31083),
+    Fraction::new_maybe_reduced(2303, 27968),
+    Fraction::new_maybe_reduced(1435, 16979),
+    Fraction::new_maybe_reduced(816, 9413),
+    Fraction::new_maybe_reduced(1081, 12165),
+    Fraction::new_maybe_reduced(856, 9403),
+    Fraction::new_maybe_reduced(667, 7156),
+    Fraction::new_maybe_reduced(1351, 14164),
+    Fraction::new_maybe_reduced(2568, 26323),
+    Fraction::new_maybe_reduced(708, 7099),
+    Fraction::new_maybe_reduced(1549, 15200),
+    Fraction::new_maybe_reduced(2123, 20397),
+    Fraction::new_maybe_reduced(1587, 14935),
+    Fraction::new_maybe_reduced(347, 3200),
+    Fraction::new_maybe_reduced(2106, 19039),
+    Fraction::new_maybe_reduced(2809, 24904),
+    Fraction::new_maybe_reduced(3438, 29903),
+    Fraction::new_maybe_reduced(125, 1067),
+    Fraction::new_maybe_reduced(763, 6394),
+    Fraction::new_maybe_reduced(801, 6592),
+    Fraction::new_maybe_reduced(1761, 14237),
+    Fraction::new_maybe_reduced(1009, 8016),
+    Fraction::new_maybe_reduced(3463, 27043),
+    Fraction::new_maybe_reduced(3453, 26513),
+    Fraction::new_maybe_reduced(3504, 26461),
+    Fraction::new_maybe_reduced(2039, 15148),
+    Fraction::new_maybe_reduced(3443, 25170),
+    Fraction::new_maybe_reduced(3718, 26753),
+    Fraction::new_maybe_reduced(107, 758),
+    Fraction::new_maybe_reduced(3925, 27381),
+    Fraction::new_maybe_reduced(1219, 8376),
+    Fraction::new_maybe_reduced(1132, 7663),
+    Fraction::new_maybe_reduced(2711, 18084),
+    Fraction::new_maybe_reduced(1133, 7449),
+    Fraction::new_maybe_reduced(1147, 7434),
+    Fraction::new_maybe_reduced(274, 1751),
+    Fraction::new_maybe_reduced(847, 5338),
+    Fraction::new_maybe_reduced(1003, 6235),
+    Fraction::new_maybe_reduced(2019, 12382),
+    Fraction::new_maybe_reduced(721, 4363),
+    Fraction::new_maybe_reduced(250, 1493),
+    Fraction::new_maybe_reduced(1795, 10581),
+    Fraction::new_maybe_reduced(847, 4929),
+    Fraction::new_maybe_reduced(5171, 29712),
+    Fraction::new_maybe_reduced(838, 4755),
+    Fraction::new_maybe_reduced(1769, 9914),
+    Fraction::new_maybe_reduced(5188, 28721),
+    Fraction::new_maybe_reduced(3040, 16627),
+    Fraction::new_maybe_reduced(3094, 16721),
+    Fraction::new_maybe_reduced(1661, 8871),
+    Fraction::new_maybe_reduced(3614, 19077),
+    Fraction::new_maybe_reduced(1395, 7279),
+    Fraction::new_maybe_reduced(4667, 24075),
+    Fraction::new_maybe_reduced(3333, 17000),
+    Fraction::new_maybe_reduced(526, 2653),
+    Fraction::new_maybe_reduced(4987, 24876),
+    Fraction::new_maybe_reduced(4441, 21911),
+    Fraction::new_maybe_reduced(2219, 10830),
+    Fraction::new_maybe_reduced(274, 1323),
+    Fraction::new_maybe_reduced(4210, 20113),
+    Fraction::new_maybe_reduced(5221, 24682),
+    Fraction::new_maybe_reduced(5676, 26555),
+    Fraction::new_maybe_reduced(525, 2431),
+    Fraction::new_maybe_reduced(1654, 7581),
+    Fraction::new_maybe_reduced(5775, 26203),
+    Fraction::new_maybe_reduced(2087, 9375),
+    Fraction::new_maybe_reduced(2392, 10639),
+    Fraction::new_maybe_reduced(1133, 4990),
+    Fraction::new_maybe_reduced(3532, 15405),
+    Fraction::new_maybe_reduced(4414, 19067),
+    Fraction::new_maybe_reduced(1576, 6743),
+    Fraction::new_maybe_reduced(4723, 20017),
+    Fraction::new_maybe_reduced(700, 2939),
+    Fraction::new_maybe_reduced(2712, 11281),
+    Fraction::new_maybe_reduced(5624, 23179),
+    Fraction::new_maybe_reduced(1466, 5987),
+    Fraction::new_maybe_reduced(1425, 5767),
+    Fraction::new_maybe_reduced(3620, 14519),
+    Fraction::new_maybe_reduced(5071, 20158),
+    Fraction::new_maybe_reduced(1871, 7372),
+    Fraction::new_maybe_reduced(4338, 16943),
+    Fraction::new_maybe_reduced(2357, 9126),
+    Fraction::new_maybe_reduced(2125, 8157),
+    Fraction::new_maybe_reduced(1777, 6763),
+    Fraction::new_maybe_reduced(2558, 9653),
+    Fraction::new_maybe_reduced(7131, 26684),
+    Fraction::new_maybe_reduced(8648, 32091),
+    Fraction::new_maybe_reduced(3195, 11758),
+    Fraction::new_maybe_reduced(7590, 27703),
+    Fraction::new_maybe_reduced(3710, 13431),
+    Fraction::new_maybe_reduced(907, 3257),
+    Fraction::new_maybe_reduced(1802, 6419),
+    Fraction::new_maybe_reduced(2057, 7269),
+    Fraction::new_maybe_reduced(8032, 28159),
+    Fraction::new_maybe_reduced(6370, 22157),
+    Fraction::new_maybe_reduced(8694, 30005),
+    Fraction::new_maybe_reduced(8725, 29879),
+    Fraction::new_maybe_reduced(6838, 23237),
+    Fraction::new_maybe_reduced(3457, 11658),
+    Fraction::new_maybe_reduced(6019, 20144),
+    Fraction::new_maybe_reduced(2659, 8832),
+    Fraction::new_maybe_reduced(8941, 29476),
+    Fraction::new_maybe_reduced(1326, 4339),
+    Fraction::new_maybe_reduced(10080, 32741),
+    Fraction::new_maybe_reduced(5033, 16228),
+    Fraction::new_maybe_reduced(10087, 32287),
+    Fraction::new_maybe_reduced(7321, 23264),
+    Fraction::new_maybe_reduced(4765, 15033),
+    Fraction::new_maybe_reduced(6845, 21441),
+    Fraction::new_maybe_reduced(5847, 18185),
+    Fraction::new_maybe_reduced(8218, 25379),
+    Fraction::new_maybe_reduced(9641, 29565),
+    Fraction::new_maybe_reduced(6277, 19115),
+    Fraction::new_maybe_reduced(9058, 27393),
+    Fraction::new_maybe_reduced(2069, 6214),
+    Fraction::new_maybe_reduced(175, 522),
+    Fraction::new_maybe_reduced(9437, 27958),
+    Fraction::new_maybe_reduced(1167, 3434),
+    Fraction::new_maybe_reduced(9201, 26893),
+    Fraction::new_maybe_reduced(7541, 21894),
+    Fraction::new_maybe_reduced(3985, 11493),
+    Fraction::new_maybe_reduced(615, 1762),
+    Fraction::new_maybe_reduced(1613, 4591),
+    Fraction::new_maybe_reduced(3070, 8681),
+    Fraction::new_maybe_reduced(1925, 5408),
+    Fraction::new_maybe_reduced(9743, 27195),
+    Fraction::new_maybe_reduced(5903, 16371),
+    Fraction::new_maybe_reduced(2611, 7195),
+    Fraction::new_maybe_reduced(2811, 7697),
+    Fraction::new_maybe_reduced(9699, 26390),
+    Fraction::new_maybe_reduced(11625, 31432),
+    Fraction::new_maybe_reduced(4699, 12626),
+    Fraction::new_maybe_reduced(4895, 13071),
+    Fraction::new_maybe_reduced(5254, 13943),
+    Fraction::new_maybe_reduced(8419, 22205),
+    Fraction::new_maybe_reduced(8713, 22840),
+    Fraction::new_maybe_reduced(11813, 30778),
+    Fraction::new_maybe_reduced(6161, 15955),
+    Fraction::new_maybe_reduced(10345, 26629),
+    Fraction::new_maybe_reduced(4797, 12274),
+    Fraction::new_maybe_reduced(1715, 4362),
+    Fraction::new_maybe_reduced(2750, 6953),
+    Fraction::new_maybe_reduced(12379, 31114),
+    Fraction::new_maybe_reduced(3455, 8633),
+    Fraction::new_maybe_reduced(4183, 10391),
+    Fraction::new_maybe_reduced(3494, 8629),
+    Fraction::new_maybe_reduced(9535, 23412),
+    Fraction::new_maybe_reduced(9147, 22330),
+    Fraction::new_maybe_reduced(4749, 11527),
+    Fraction::new_maybe_reduced(11594, 27981),
+    Fraction::new_maybe_reduced(1341, 3218),
+    Fraction::new_maybe_reduced(12656, 30199),
+    Fraction::new_maybe_reduced(4985, 11828),
+    Fraction::new_maybe_reduced(4432, 10457),
+    Fraction::new_maybe_reduced(4609, 10814),
+    Fraction::new_maybe_reduced(13308, 31051),
+    Fraction::new_maybe_reduced(2288, 5309),
+    Fraction::new_maybe_reduced(9587, 22123),
+    Fraction::new_maybe_reduced(5821, 13359),
+    Fraction::new_maybe_reduced(8235, 18796),
+    Fraction::new_maybe_reduced(10605, 24074),
+    Fraction::new_maybe_reduced(11618, 26231),
+    Fraction::new_maybe_reduced(2349, 5275),
+    Fraction::new_maybe_reduced(13399, 29928),
+    Fraction::new_maybe_reduced(6523, 14492),
+    Fraction::new_maybe_reduced(1439, 3180),
+    Fraction::new_maybe_reduced(4153, 9129),
+    Fraction::new_maybe_reduced(8302, 18153),
+    Fraction::new_maybe_reduced(988, 2149),
+    Fraction::new_maybe_reduced(6407, 13863),
+    Fraction::new_maybe_reduced(9032, 19441),
+    Fraction::new_maybe_reduced(4473, 9578),
+    Fraction::new_maybe_reduced(11357, 24193),
+    Fraction::new_maybe_reduced(11093, 23509),
+    Fraction::new_maybe_reduced(5655, 11923),
+    Fraction::new_maybe_reduced(6504, 13643),
+    Fraction::new_maybe_reduced(9763, 20375),
+    Fraction::new_maybe_reduced(2998, 6225),
+    Fraction::new_maybe_reduced(12625, 26082),
+    Fraction::new_maybe_reduced(6089, 12516),
+    Fraction::new_maybe_reduced(5375, 10993),
+    Fraction::new_maybe_reduced(2943, 5989),
+    Fraction::new_maybe_reduced(201, 407),
+    Fraction::new_maybe_reduced(3841, 7739),
+    Fraction::new_maybe_reduced(409, 820),
+    Fraction::new_maybe_reduced(201, 401),
+    Fraction::new_maybe_reduced(3998, 7937),
+    Fraction::new_maybe_reduced(5847, 11551),
+    Fraction::new_maybe_reduced(14468, 28443),
+    Fraction::new_maybe_reduced(321, 628),
+    Fraction::new_maybe_reduced(358, 697),
+    Fraction::new_maybe_reduced(1361, 2637),
+    Fraction::new_maybe_reduced(10173, 19616),
+    Fraction::new_maybe_reduced(14953, 28695),
+    Fraction::new_maybe_reduced(355, 678),
+    Fraction::new_maybe_reduced(1411, 2682),
+    Fraction::new_maybe_reduced(3853, 7289),
+    Fraction::new_maybe_reduced(6188, 11651),
+    Fraction::new_maybe_reduced(12791, 23970),
+    Fraction::new_maybe_reduced(4888, 9117),
+    Fraction::new_maybe_reduced(6026, 11187),
+    Fraction::new_maybe_reduced(13607, 25143),
+    Fraction::new_maybe_reduced(3290, 6051),
+    Fraction::new_maybe_reduced(13354, 24447),
+    Fraction::new_maybe_reduced(2627, 4787),
+    Fraction::new_maybe_reduced(607, 1101),
+    Fraction::new_maybe_reduced(617, 1114),
+    Fraction::new_maybe_reduced(3413, 6134),
+    Fraction::new_maybe_reduced(14311, 25603),
+    Fraction::new_maybe_reduced(14117, 25141),
+    Fraction::new_maybe_reduced(8588, 15225),
+    Fraction::new_maybe_reduced(608, 1073),
+    Fraction::new_maybe_reduced(4129, 7254),
+    Fraction::new_maybe_reduced(17450, 30519),
+    Fraction::new_maybe_reduced(9181, 15985),
+    Fraction::new_maybe_reduced(2681, 4647),
+    Fraction::new_maybe_reduced(3808, 6571),
+    Fraction::new_maybe_reduced(2804, 4817),
+    Fraction::new_maybe_reduced(3569, 6104),
+    Fraction::new_maybe_reduced(5483, 9336),
+    Fraction::new_maybe_reduced(292, 495),
+    Fraction::new_maybe_reduced(17979, 30344),
+    Fraction::new_maybe_reduced(10458, 17573),
+    Fraction::new_maybe_reduced(211, 353),
+    Fraction::new_maybe_reduced(18626, 31025),
+    Fraction::new_maybe_reduced(1821, 3020),
+    Fraction::new_maybe_reduced(7664, 12655),
+    Fraction::new_maybe_reduced(3467, 5700),
+    Fraction::new_maybe_reduced(4815, 7882),
+    Fraction::new_maybe_reduced(6974, 11367),
+    Fraction::new_maybe_reduced(13702, 22237),
+    Fraction::new_maybe_reduced(6236, 10077),
+    Fraction::new_maybe_reduced(15625, 25141),
+    Fraction::new_maybe_reduced(8727, 13982),
+    Fraction::new_maybe_reduced(3640, 5807),
+    Fraction::new_maybe_reduced(17939, 28497),
+    Fraction::new_maybe_reduced(5543, 8768),
+    Fraction::new_maybe_reduced(10749, 16931),
+    Fraction::new_maybe_reduced(7594, 11911),
+    Fraction::new_maybe_reduced(10611, 16573),
+    Fraction::new_maybe_reduced(19175, 29823),
+    Fraction::new_maybe_reduced(18561, 28747),
+    Fraction::new_maybe_reduced(8746, 13489),
+    Fraction::new_maybe_reduced(6134, 9421),
+    Fraction::new_maybe_reduced(12845, 19646),
+    Fraction::new_maybe_reduced(18392, 28013),
+    Fraction::new_maybe_reduced(4259, 6460),
+    Fraction::new_maybe_reduced(19034, 28751),
+    Fraction::new_maybe_reduced(1055, 1587),
+    Fraction::new_maybe_reduced(12121, 18158),
+    Fraction::new_maybe_reduced(3763, 5614),
+    Fraction::new_maybe_reduced(8887, 13204),
+    Fraction::new_maybe_reduced(14562, 21547),
+    Fraction::new_maybe_reduced(6273, 9244),
+    Fraction::new_maybe_reduced(7453, 10938),
+    Fraction::new_maybe_reduced(1042, 1523),
+    Fraction::new_maybe_reduced(1139, 1658),
+    Fraction::new_maybe_reduced(16278, 23599),
+    Fraction::new_maybe_reduced(1541, 2225),
+    Fraction::new_maybe_reduced(14271, 20522),
+    Fraction::new_maybe_reduced(5185, 7426),
+    Fraction::new_maybe_reduced(22733, 32427),
+    Fraction::new_maybe_reduced(851, 1209),
+    Fraction::new_maybe_reduced(6521, 9227),
+    Fraction::new_maybe_reduced(4459, 6284),
+    Fraction::new_maybe_reduced(5339, 7494),
+    Fraction::new_maybe_reduced(3525, 4928),
+    Fraction::new_maybe_reduced(17797, 24781),
+    Fraction::new_maybe_reduced(10280, 14257),
+    Fraction::new_maybe_reduced(4298, 5937),
+    Fraction::new_maybe_reduced(5638, 7757),
+    Fraction::new_maybe_reduced(9647, 13220),
+    Fraction::new_maybe_reduced(9588, 13087),
+    Fraction::new_maybe_reduced(10703, 14551),
+    Fraction::new_maybe_reduced(6407, 8676),
+    Fraction::new_maybe_reduced(22039, 29726),
+    Fraction::new_maybe_reduced(13291, 17856),
+    Fraction::new_maybe_reduced(17651, 23620),
+    Fraction::new_maybe_reduced(5353, 7135),
+    Fraction::new_maybe_reduced(10154, 13481),
+    Fraction::new_maybe_reduced(3151, 4167),
+    Fraction::new_maybe_reduced(6175, 8134),
+    Fraction::new_maybe_reduced(14525, 19058),
+    Fraction::new_maybe_reduced(21069, 27536),
+    Fraction::new_maybe_reduced(22887, 29795),
+    Fraction::new_maybe_reduced(10868, 14093),
+    Fraction::new_maybe_reduced(16189, 20911),
+    Fraction::new_maybe_reduced(14185, 18251),
+    Fraction::new_maybe_reduced(664, 851),
+    Fraction::new_maybe_reduced(3163, 4038),
+    Fraction::new_maybe_reduced(3265, 4152),
+    Fraction::new_maybe_reduced(20253, 25655),
+    Fraction::new_maybe_reduced(25263, 31877),
+    Fraction::new_maybe_reduced(20953, 26336),
+    Fraction::new_maybe_reduced(7745, 9697),
+    Fraction::new_maybe_reduced(24921, 31081),
+    Fraction::new_maybe_reduced(25459, 31629),
+    Fraction::new_maybe_reduced(23659, 29279),
+    Fraction::new_maybe_reduced(23209, 28611),
+    Fraction::new_maybe_reduced(26361, 32371),
+    Fraction::new_maybe_reduced(10773, 13178),
+    Fraction::new_maybe_reduced(10681, 13015),
+    Fraction::new_maybe_reduced(9251, 11229),
+    Fraction::new_maybe_reduced(8411, 10170),
+    Fraction::new_maybe_reduced(15626, 18821),
+    Fraction::new_maybe_reduced(19152, 22979),
+    Fraction::new_maybe_reduced(1291, 1543),
+    Fraction::new_maybe_reduced(26381, 31409),
+    Fraction::new_maybe_reduced(14419, 17101),
+    Fraction::new_maybe_reduced(7617, 8999),
+    Fraction::new_maybe_reduced(21511, 25316),
+    Fraction::new_maybe_reduced(19721, 23120),
+    Fraction::new_maybe_reduced(7811, 9122),
+    Fraction::new_maybe_reduced(9881, 11495),
+    Fraction::new_maybe_reduced(2713, 3144),
+    Fraction::new_maybe_reduced(12856, 14841),
+    Fraction::new_maybe_reduced(27041, 31096),
+    Fraction::new_maybe_reduced(1711, 1960),
+    Fraction::new_maybe_reduced(17893, 20418),
+    Fraction::new_maybe_reduced(17064, 19397),
+    Fraction::new_maybe_reduced(23568, 26687),
+    Fraction::new_maybe_reduced(6931, 7818),
+    Fraction::new_maybe_reduced(17747, 19941),
+    Fraction::new_maybe_reduced(2431, 2721),
+    Fraction::new_maybe_reduced(21405, 23866),
+    Fraction::new_maybe_reduced(19834, 22029),
+    Fraction::new_maybe_reduced(18265, 20208),
+    Fraction::new_maybe_reduced(333, 367),
+    Fraction::new_maybe_reduced(787, 864),
+    Fraction::new_maybe_reduced(3152, 3447),
+    Fraction::new_maybe_reduced(19551, 21298),
+    Fraction::new_maybe_reduced(5415, 5876),
+    Fraction::new_maybe_reduced(173, 187),
+    Fraction::new_maybe_reduced(20110, 21653),
+    Fraction::new_maybe_reduced(15949, 17106),
+    Fraction::new_maybe_reduced(9697, 10360),
+    Fraction::new_maybe_reduced(7304, 7773),
+    Fraction::new_maybe_reduced(27488, 29139),
+    Fraction::new_maybe_reduced(25552, 26981),
+    Fraction::new_maybe_reduced(15232, 16021),
+    Fraction::new_maybe_reduced(23761, 24894),
+    Fraction::new_maybe_reduced(23934, 24977),
+    Fraction::new_maybe_reduced(23073, 23984),
+    Fraction::new_maybe_reduced(113, 117),
+    Fraction::new_maybe_reduced(28509, 29402),
+    Fraction::new_maybe_reduced(9722, 9987),
+    Fraction::new_maybe_reduced(28490, 29151),
+    Fraction::new_maybe_reduced(5221, 5321),
+    Fraction::new_maybe_reduced(8138, 8261),
+    Fraction::new_maybe_reduced(17594, 17789),
+    Fraction::new_maybe_reduced(15155, 15262),
+    Fraction::new_maybe_reduced(7553, 7576),
+    Fraction::new_maybe_reduced(30118, 30089),
+    Fraction::new_maybe_reduced(12289, 12228),
+    Fraction::new_maybe_reduced(13619, 13497),
+    Fraction::new_maybe_reduced(16453, 16240),
+    Fraction::new_maybe_reduced(6321, 6214),
+    Fraction::new_maybe_reduced(5023, 4918),
+    Fraction::new_maybe_reduced(24121, 23521),
+    Fraction::new_maybe_reduced(8287, 8048),
+    Fraction::new_maybe_reduced(1128, 1091),
+    Fraction::new_maybe_reduced(16078, 15487),
+    Fraction::new_maybe_reduced(6804, 6527),
+    Fraction::new_maybe_reduced(25369, 24236),
+    Fraction::new_maybe_reduced(17878, 17009),
+    Fraction::new_maybe_reduced(1960, 1857),
+    Fraction::new_maybe_reduced(23065, 21762),
+    Fraction::new_maybe_reduced(21611, 20305),
+    Fraction::new_maybe_reduced(11527, 10785),
+    Fraction::new_maybe_reduced(27171, 25315),
+    Fraction::new_maybe_reduced(27725, 25722),
+    Fraction::new_maybe_reduced(30020, 27733),
+    Fraction::new_maybe_reduced(12631, 11619),
+    Fraction::new_maybe_reduced(28123, 25759),
+    Fraction::new_maybe_reduced(125, 114),
+    Fraction::new_maybe_reduced(31030, 28177),
+    Fraction::new_maybe_reduced(21869, 19772),
+    Fraction::new_maybe_reduced(14273, 12848),
+    Fraction::new_maybe_reduced(11417, 10232),
+    Fraction::new_maybe_reduced(25067, 22366),
+    Fraction::new_maybe_reduced(7904, 7021),
+    Fraction::new_maybe_reduced(510, 451),
+    Fraction::new_maybe_reduced(1429, 1258),
+    Fraction::new_maybe_reduced(30465, 26698),
+    Fraction::new_maybe_reduced(7231, 6308),
+    Fraction::new_maybe_reduced(7087, 6154),
+    Fraction::new_maybe_reduced(1887, 1631),
+    Fraction::new_maybe_reduced(5462, 4699),
+    Fraction::new_maybe_reduced(8648, 7405),
+    Fraction::new_maybe_reduced(25239, 21509),
+    Fraction::new_maybe_reduced(9127, 7741),
+    Fraction::new_maybe_reduced(17859, 15074),
+    Fraction::new_maybe_reduced(25511, 21428),
+    Fraction::new_maybe_reduced(17847, 14917),
+    Fraction::new_maybe_reduced(16843, 14008),
+    Fraction::new_maybe_reduced(6012, 4975),
+    Fraction::new_maybe_reduced(14427, 11878),
+    Fraction::new_maybe_reduced(11039, 9042),
+    Fraction::new_maybe_reduced(1226, 999),
+    Fraction::new_maybe_reduced(11513, 9332),
+    Fraction::new_maybe_reduced(5605, 4519),
+    Fraction::new_maybe_reduced(4023, 3226),
+    Fraction::new_maybe_reduced(1037, 827),
+    Fraction::new_maybe_reduced(15342, 12167),
+    Fraction::new_maybe_reduced(26211, 20669),
+    Fraction::new_maybe_reduced(31850, 24971),
+    Fraction::new_maybe_reduced(10640, 8293),
+    Fraction::new_maybe_reduced(31170, 24149),
+    Fraction::new_maybe_reduced(16101, 12398),
+    Fraction::new_maybe_reduced(16235, 12423),
+    Fraction::new_maybe_reduced(26395, 20068),
+    Fraction::new_maybe_reduced(425, 321),
+    Fraction::new_maybe_reduced(1369, 1027),
+    Fraction::new_maybe_reduced(7618, 5675),
+    Fraction::new_maybe_reduced(15048, 11129),
+    Fraction::new_maybe_reduced(17227, 12645),
+    Fraction::new_maybe_reduced(15358, 11185),
+    Fraction::new_maybe_reduced(2294, 1657),
+    Fraction::new_maybe_reduced(11246, 8053),
+    Fraction::new_maybe_reduced(1401, 994),
+    Fraction::new_maybe_reduced(13340, 9371),
+    Fraction::new_maybe_reduced(10943, 7604),
+    Fraction::new_maybe_reduced(18957, 13013),
+    Fraction::new_maybe_reduced(22677, 15346),
+    Fraction::new_maybe_reduced(15232, 10121),
+    Fraction::new_maybe_reduced(355, 226),
+];
@@ -1,5 +1,8 @@
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use crate::traits::{FromComponents, IntoComponents, ScreenScale};
 use crate::units::{Lp, Px, UPx};
@@ -167,6 +170,7 @@ where
         }
     }
     sizes.sort();
+    #[cfg(feature = "std")]
     println!("Sorted: {sizes:#?}");
     for sizes in sizes.windows(2) {
         assert!(
@@ -241,3 +245,1631 @@ fn rotate() {
         Point::new(Px::ZERO, Px::new(-5))
     );
 }
+
+#[test]
+fn scale_to_fit_and_fill() {
+    let photo = Size::new(Px::new(400), Px::new(300));
+    let container = Size::new(Px::new(200), Px::new(200));
+    assert_eq!(
+        photo.scale_to_fit(container),
+        Size::new(Px::new(200), Px::new(150))
+    );
+    assert_eq!(
+        photo.scale_to_fill(container),
+        Size::new(Px::from(266.75), Px::new(200))
+    );
+}
+
+#[test]
+fn nan_guards() {
+    let point = Point::new(f32::NAN, 1.);
+    assert!(point.is_nan());
+    assert!(!point.is_finite());
+    assert_eq!(point.replace_nan(0.), Point::new(0., 1.));
+
+    let rect = crate::Rect::new(point, Size::new(2., f32::INFINITY));
+    assert!(rect.is_nan());
+    assert!(!rect.replace_nan(0.).is_finite());
+    assert!(rect.replace_nan(0.).origin.is_finite());
+}
+
+#[test]
+fn checked_math() {
+    use crate::CheckedNumOps;
+
+    let point = Point::new(Px::MAX, Px::new(1));
+    assert_eq!(point.checked_add(Point::new(Px::new(1), Px::new(1))), None);
+    assert_eq!(
+        Point::new(Px::new(1), Px::new(2)).checked_add(Point::new(Px::new(3), Px::new(4))),
+        Some(Point::new(Px::new(4), Px::new(6)))
+    );
+    assert_eq!(
+        Size::new(UPx::new(1), UPx::new(1)).checked_sub(Size::new(UPx::new(2), UPx::new(1))),
+        None
+    );
+    assert_eq!(Px::new(6).checked_div(Px::new(2)), Some(Px::new(3)));
+    assert_eq!(Px::new(6).checked_div(Px::ZERO), None);
+}
+
+#[test]
+fn summing_measurements() {
+    let heights = [Px::new(10), Px::new(20), Px::new(30)];
+    assert_eq!(heights.iter().sum::<Px>(), Px::new(60));
+    assert_eq!(heights.into_iter().sum::<Px>(), Px::new(60));
+    assert_eq!(
+        [Px::new(2), Px::new(3), Px::new(4)]
+            .into_iter()
+            .product::<Px>(),
+        Px::new(24)
+    );
+
+    let sizes = [
+        Size::new(Lp::new(1), Lp::new(2)),
+        Size::new(Lp::new(3), Lp::new(4)),
+    ];
+    assert_eq!(
+        sizes.iter().sum::<Size<Lp>>(),
+        Size::new(Lp::new(4), Lp::new(6))
+    );
+    assert_eq!(
+        sizes.into_iter().sum::<Size<Lp>>(),
+        Size::new(Lp::new(4), Lp::new(6))
+    );
+}
+
+#[test]
+fn magnified_viewport_clamps() {
+    let screen = Size::new(Px::new(640), Px::new(480));
+    // A focus near the corner clamps instead of extending off-screen.
+    let corner = crate::Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::squared(Px::new(10)),
+    );
+    assert_eq!(
+        corner.magnified_viewport(Fraction::new_whole(2), screen),
+        crate::Rect::new(
+            Point::new(Px::new(0), Px::new(0)),
+            Size::new(Px::new(320), Px::new(240))
+        )
+    );
+    // Magnification of 1 or less shows the entire screen.
+    assert_eq!(
+        corner.magnified_viewport(Fraction::new(1, 2), screen),
+        crate::Rect::new(Point::new(Px::new(0), Px::new(0)), screen)
+    );
+}
+
+#[test]
+fn empty_constants() {
+    assert_eq!(Point::<Px>::ORIGIN, Point::new(Px::ZERO, Px::ZERO));
+    assert_eq!(Size::<Px>::EMPTY, Size::new(Px::ZERO, Px::ZERO));
+    assert!(crate::Rect::<Px>::EMPTY.is_empty());
+    assert!(crate::Rect::<Px>::EMPTY.is_zero());
+    // A displaced empty rect is empty but not zero.
+    let displaced = crate::Rect::new(Point::new(Px::new(5), Px::new(5)), Size::EMPTY);
+    assert!(displaced.is_empty());
+    assert!(!displaced.is_zero());
+}
+
+#[test]
+fn midpoint_and_centroid() {
+    // Midpoints of values near the representation limits do not overflow.
+    assert_eq!(
+        Point::new(Px::MAX, Px::MAX).midpoint(Point::new(Px::MAX, Px::MAX)),
+        Point::new(Px::MAX, Px::MAX)
+    );
+    assert_eq!(
+        Point::new(Px::new(2), Px::new(10)).midpoint(Point::new(Px::new(4), Px::new(20))),
+        Point::new(Px::new(3), Px::new(15))
+    );
+
+    assert_eq!(crate::centroid::<Px>(&[]), None);
+    assert_eq!(
+        crate::centroid(&[
+            Point::new(Px::new(0), Px::new(0)),
+            Point::new(Px::new(6), Px::new(0)),
+            Point::new(Px::new(0), Px::new(6)),
+        ]),
+        Some(Point::new(Px::new(2), Px::new(2)))
+    );
+}
+
+#[test]
+fn pillarbox_bars() {
+    let screen = crate::Rect::<i32>::new(Point::new(0, 0), Size::new(320, 240));
+    let video = crate::Rect::new(Point::new(40, 0), Size::new(240, 240));
+    assert_eq!(
+        screen.letterbox_bars(&video),
+        vec![
+            crate::Rect::new(Point::new(0, 0), Size::new(40, 240)),
+            crate::Rect::new(Point::new(280, 0), Size::new(40, 240)),
+        ]
+    );
+    assert_eq!(screen.letterbox_bars(&screen), vec![]);
+    let outside = crate::Rect::new(Point::new(1000, 0), Size::new(10, 10));
+    assert_eq!(screen.letterbox_bars(&outside), vec![screen]);
+}
+
+#[test]
+fn rect_difference() {
+    let window = crate::Rect::<i32>::new(Point::new(0, 0), Size::new(100, 100));
+    // A centered occluder exposes all four surrounding strips.
+    let center = crate::Rect::new(Point::new(25, 25), Size::new(50, 50));
+    let exposed = window.difference(&center).collect::<Vec<_>>();
+    assert_eq!(
+        exposed,
+        vec![
+            crate::Rect::new(Point::new(0, 0), Size::new(100, 25)),
+            crate::Rect::new(Point::new(0, 25), Size::new(25, 50)),
+            crate::Rect::new(Point::new(75, 25), Size::new(25, 50)),
+            crate::Rect::new(Point::new(0, 75), Size::new(100, 25)),
+        ]
+    );
+    // The pieces tile the difference exactly.
+    let area: i32 = exposed.iter().map(|rect| rect.size.area()).sum();
+    assert_eq!(area, 100 * 100 - 50 * 50);
+
+    // An occluder hanging off one corner exposes two strips.
+    let corner = crate::Rect::new(Point::new(-50, -50), Size::new(100, 100));
+    assert_eq!(
+        window.difference(&corner).collect::<Vec<_>>(),
+        vec![
+            crate::Rect::new(Point::new(50, 0), Size::new(50, 50)),
+            crate::Rect::new(Point::new(0, 50), Size::new(100, 50)),
+        ]
+    );
+
+    // No overlap leaves the window fully exposed.
+    let outside = crate::Rect::new(Point::new(200, 0), Size::new(10, 10));
+    assert_eq!(
+        window.difference(&outside).collect::<Vec<_>>(),
+        vec![window]
+    );
+}
+
+#[test]
+fn batch_normalize() {
+    let mut normals = vec![
+        Point::new(3.0_f32, 4.0),
+        Point::new(0., -2.),
+        Point::new(0., 0.),
+    ];
+    crate::normalize_all(&mut normals);
+    assert_eq!(normals[0], Point::new(0.6, 0.8));
+    assert_eq!(normals[1], Point::new(0., -1.));
+    // The zero vector has no direction and is left unchanged.
+    assert_eq!(normals[2], Point::new(0., 0.));
+    for normal in &normals[..2] {
+        assert!((normal.magnitude() - 1.).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn fixed_px_precision() {
+    use crate::traits::{Round, UnscaledUnit};
+    use crate::units::FixedPx;
+    type Px64 = FixedPx<64>;
+    // 1/64 subpixel steps represent typical font metrics exactly.
+    let advance = Px64::from(10.25);
+    assert_eq!(advance.into_unscaled(), 656);
+    assert_eq!(advance.get(), 10);
+    assert_eq!(advance + Px64::new(1), Px64::from(11.25));
+    assert_eq!(advance * 2, Px64::from(20.5));
+    assert_eq!(advance.floor(), Px64::new(10));
+    assert_eq!(advance.ceil(), Px64::new(11));
+    assert_eq!(format!("{advance:?}"), "10.25px");
+
+    // Round trips through `Px` are lossless because 64 is a multiple of 4.
+    let px = Px::from(10.25);
+    assert_eq!(Px64::from(px), advance);
+    assert_eq!(Px::from(advance), px);
+}
+
+#[test]
+#[cfg(feature = "schemars")]
+fn json_schemas() {
+    // Unit types serialize their logical value as a float in human-readable
+    // formats, so their schemas are plain numbers.
+    let schema = schemars::schema_for!(Px);
+    let json = serde_json::to_value(&schema).unwrap();
+    assert_eq!(json["type"], "number");
+    // Composite types expose their components as named properties.
+    let schema = schemars::schema_for!(crate::Rect<Px>);
+    let json = serde_json::to_value(&schema).unwrap();
+    assert!(json["properties"]["origin"].is_object());
+    assert!(json["properties"]["size"].is_object());
+}
+
+#[test]
+fn line_fitting() {
+    assert_eq!(crate::fit_line::<Px>(&[]), None);
+    assert_eq!(crate::fit_line(&[Point::new(Px::new(1), Px::new(1))]), None);
+    // A diagonal swipe fits a 45 degree line through the origin.
+    let swipe = [
+        Point::new(Px::new(0), Px::new(0)),
+        Point::new(Px::new(10), Px::new(10)),
+        Point::new(Px::new(20), Px::new(20)),
+    ];
+    let (angle, offset) = crate::fit_line(&swipe).expect("enough points");
+    assert_eq!(angle, Angle::degrees(45));
+    assert_eq!(offset, Px::ZERO);
+    // A vertical line reports a signed perpendicular offset.
+    let vertical = [
+        Point::new(Px::new(5), Px::new(0)),
+        Point::new(Px::new(5), Px::new(10)),
+    ];
+    let (angle, offset) = crate::fit_line(&vertical).expect("enough points");
+    assert_eq!(angle, Angle::degrees(90));
+    assert_eq!(offset, Px::new(-5));
+}
+
+#[test]
+fn rect_overlap_fraction() {
+    let widget = crate::Rect::<Px>::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(100), Px::new(100)),
+    );
+    // A quarter of the widget is visible through the viewport's corner.
+    let viewport = crate::Rect::new(
+        Point::new(Px::new(50), Px::new(50)),
+        Size::new(Px::new(100), Px::new(100)),
+    );
+    assert_eq!(widget.overlap_fraction(&viewport), Fraction::new(1, 4));
+    // Full coverage and no coverage.
+    assert_eq!(widget.overlap_fraction(&widget), Fraction::ONE);
+    let offscreen = crate::Rect::new(
+        Point::new(Px::new(200), Px::new(0)),
+        Size::new(Px::new(100), Px::new(100)),
+    );
+    assert_eq!(widget.overlap_fraction(&offscreen), Fraction::ZERO);
+    // An empty rectangle has no area to cover.
+    assert_eq!(
+        crate::Rect::<Px>::EMPTY.overlap_fraction(&widget),
+        Fraction::ZERO
+    );
+    // Ratios too precise for i16 components are approximated.
+    let screen = crate::Rect::<Px>::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(44001), Px::new(1)),
+    );
+    let sliver = crate::Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(22000), Px::new(1)),
+    );
+    let ratio = screen.overlap_fraction(&sliver);
+    assert!((ratio.into_f32() - 22000. / 44001.).abs() < 0.001);
+}
+
+#[test]
+fn canonical_float_bits() {
+    // Values that compare equal produce equal bits.
+    assert_eq!(
+        Point::new(-0.0_f32, 1.5).canonical_bits(),
+        Point::new(0.0_f32, 1.5).canonical_bits()
+    );
+    // All NaNs collapse to one pattern, regardless of payload.
+    assert_eq!(
+        Point::new(f32::NAN, 0.).canonical_bits(),
+        Point::new(-f32::NAN, 0.).canonical_bits()
+    );
+    // Distinct values produce distinct bits.
+    assert_ne!(
+        Size::new(1.0_f32, 2.).canonical_bits(),
+        Size::new(2.0_f32, 1.).canonical_bits()
+    );
+    // Rect bits are the origin's bits followed by the size's.
+    let rect = crate::Rect::new(Point::new(1.0_f32, 2.), Size::new(3., 4.));
+    let [x, y] = rect.origin.canonical_bits();
+    let [width, height] = rect.size.canonical_bits();
+    assert_eq!(rect.canonical_bits(), [x, y, width, height]);
+    // The canonical bits can key a hash-based cache.
+    #[cfg(feature = "std")]
+    {
+        let mut cache = std::collections::HashSet::new();
+        cache.insert(rect.canonical_bits());
+        assert!(cache.contains(&rect.canonical_bits()));
+    }
+}
+
+fn generic_convert<T, U>(value: T, scale: Fraction) -> U
+where
+    T: crate::ConvertUnit<U>,
+{
+    value.convert(scale)
+}
+
+#[test]
+fn convert_unit() {
+    use crate::ConvertUnit;
+    // Scalar conversions, including identity.
+    let px: Px = Lp::inches(1).convert(Fraction::ONE);
+    assert_eq!(px, Px::new(96));
+    let lp: Lp = Px::new(96).convert(Fraction::ONE);
+    assert_eq!(lp, Lp::inches(1));
+    let same: Px = Px::new(-5).convert(Fraction::ONE);
+    assert_eq!(same, Px::new(-5));
+    let upx: UPx = Px::new(96).convert(Fraction::ONE);
+    assert_eq!(upx, UPx::new(96));
+    // Containers convert componentwise.
+    let point = Point::new(Px::new(96), Px::new(192));
+    assert_eq!(
+        point.convert::<Lp>(Fraction::ONE),
+        Point::new(Lp::inches(1), Lp::inches(2))
+    );
+    let size = Size::new(Px::new(96), Px::new(96));
+    assert_eq!(
+        size.convert::<UPx>(Fraction::ONE),
+        Size::new(UPx::new(96), UPx::new(96))
+    );
+    // The trait itself can drive generic code over any conversion.
+    let rect = crate::Rect::new(Point::new(Px::new(0), Px::new(0)), size);
+    let lp: crate::Rect<Lp> = generic_convert(rect, Fraction::ONE);
+    assert_eq!(lp.size.width, Lp::inches(1));
+}
+
+#[test]
+#[cfg(feature = "euclid")]
+fn euclid_conversions() {
+    let size: Size<Px> = euclid::Size2D::new(4., 8.).into();
+    assert_eq!(size, Size::new(Px::new(4), Px::new(8)));
+    assert_eq!(euclid::Size2D::from(size), euclid::Size2D::new(4., 8.));
+
+    let rect: crate::Rect<Px> =
+        euclid::Rect::new(euclid::Point2D::new(1., 2.), euclid::Size2D::new(3., 4.)).into();
+    assert_eq!(
+        rect,
+        crate::Rect::new(
+            Point::new(Px::new(1), Px::new(2)),
+            Size::new(Px::new(3), Px::new(4))
+        )
+    );
+    assert_eq!(crate::Rect::from(euclid::Rect::from(rect)), rect);
+
+    // Box2D converts through its extents.
+    let box2d = euclid::Box2D::from(rect);
+    assert_eq!(box2d.min, euclid::Point2D::new(1., 2.));
+    assert_eq!(box2d.max, euclid::Point2D::new(4., 6.));
+    assert_eq!(crate::Rect::from(box2d), rect);
+
+    let angle = Angle::from(euclid::Angle::degrees(90.));
+    assert_eq!(angle, Angle::degrees(90));
+    assert!((euclid::Angle::from(angle).to_degrees() - 90.).abs() < 0.01);
+}
+
+#[test]
+#[cfg(feature = "lyon")]
+fn lyon_conversions() {
+    let point = lyon_path::math::Point::from(Point::new(Px::new(3), Px::new(4)));
+    assert_eq!(point, lyon_path::math::Point::new(3., 4.));
+    assert_eq!(Point::<Px>::from(point), Point::new(Px::new(3), Px::new(4)));
+
+    let rect = crate::Rect::new(
+        Point::new(Px::new(1), Px::new(2)),
+        Size::new(Px::new(3), Px::new(4)),
+    );
+    let box2d = lyon_path::math::Box2D::from(rect);
+    assert_eq!(box2d.min, lyon_path::math::Point::new(1., 2.));
+    assert_eq!(box2d.max, lyon_path::math::Point::new(4., 6.));
+    assert_eq!(crate::Rect::<Px>::from(box2d), rect);
+
+    // A figures path replays its segments through lyon's builder.
+    let path = crate::Path::default()
+        .move_to(Point::new(Px::new(0), Px::new(0)))
+        .line_to(Point::new(Px::new(10), Px::new(0)))
+        .quad_to(
+            Point::new(Px::new(10), Px::new(10)),
+            Point::new(Px::new(0), Px::new(10)),
+        )
+        .close();
+    assert_eq!(path.to_lyon().iter().count(), 4);
+
+    // Rectangles and rounded rectangles build closed lyon paths directly.
+    assert!(rect.to_lyon_path().iter().count() > 0);
+    let rounded = crate::RoundedRect::new(rect, Px::new(1));
+    assert!(rounded.to_lyon_path().iter().count() > 0);
+}
+
+#[test]
+#[cfg(feature = "taffy")]
+fn taffy_conversions() {
+    let size: Size<Px> = taffy::geometry::Size {
+        width: 4.,
+        height: 8.,
+    }
+    .into();
+    assert_eq!(size, Size::new(Px::new(4), Px::new(8)));
+    assert_eq!(
+        taffy::geometry::Size::from(size),
+        taffy::geometry::Size {
+            width: 4.,
+            height: 8.,
+        }
+    );
+
+    // taffy rects are edge offsets; they convert through the extents.
+    let rect = crate::Rect::new(
+        Point::new(Px::new(1), Px::new(2)),
+        Size::new(Px::new(3), Px::new(4)),
+    );
+    let taffy_rect = taffy::geometry::Rect::from(rect);
+    assert_eq!(
+        taffy_rect,
+        taffy::geometry::Rect {
+            left: 1.,
+            right: 4.,
+            top: 2.,
+            bottom: 6.,
+        }
+    );
+    assert_eq!(crate::Rect::<Px>::from(taffy_rect), rect);
+
+    // Scale-aware conversions go through device pixels.
+    let size = Size::new(Lp::inches(1), Lp::inches(1));
+    let taffy_size = size.to_taffy(Fraction::ONE);
+    assert!((taffy_size.width - 96.).abs() < f32::EPSILON);
+    assert_eq!(Size::<Lp>::from_taffy(taffy_size, Fraction::ONE), size);
+
+    let rect = crate::Rect::new(Point::new(Lp::ZERO, Lp::ZERO), size);
+    let taffy_rect = rect.to_taffy(Fraction::ONE);
+    assert!((taffy_rect.right - 96.).abs() < f32::EPSILON);
+    assert_eq!(
+        crate::Rect::<Lp>::from_taffy(taffy_rect, Fraction::ONE),
+        rect
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_units() {
+    use crate::units::Em;
+
+    // Human-readable formats write the logical value, not the internal
+    // scaled integer.
+    assert_eq!(serde_json::to_string(&Px::new(3)).unwrap(), "3.0");
+    assert_eq!(serde_json::to_string(&UPx::new(3)).unwrap(), "3.0");
+    assert_eq!(serde_json::to_string(&Lp::new(3)).unwrap(), "3.0");
+    assert_eq!(serde_json::to_string(&Em::new(3)).unwrap(), "3.0");
+
+    // Fractional values round trip losslessly when the scale allows.
+    let px = Px::from(2.25);
+    let json = serde_json::to_string(&px).unwrap();
+    assert_eq!(json, "2.25");
+    assert_eq!(serde_json::from_str::<Px>(&json).unwrap(), px);
+    // Whole numbers deserialize without a decimal point.
+    assert_eq!(serde_json::from_str::<Px>("3").unwrap(), Px::new(3));
+
+    let lp = Lp::inches(1);
+    let json = serde_json::to_string(&lp).unwrap();
+    assert_eq!(serde_json::from_str::<Lp>(&json).unwrap(), lp);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_shapes() {
+    // Struct-map and array forms both deserialize.
+    let point: Point<i32> = serde_json::from_str(r#"{"x":1,"y":2}"#).unwrap();
+    assert_eq!(point, Point::new(1, 2));
+    let point: Point<i32> = serde_json::from_str("[1,2]").unwrap();
+    assert_eq!(point, Point::new(1, 2));
+
+    let size: Size<i32> = serde_json::from_str(r#"{"width":3,"height":4}"#).unwrap();
+    assert_eq!(size, Size::new(3, 4));
+    let size: Size<i32> = serde_json::from_str("[3,4]").unwrap();
+    assert_eq!(size, Size::new(3, 4));
+
+    // Rects accept a flat `[x, y, width, height]` sequence.
+    let rect: crate::Rect<i32> = serde_json::from_str(r#"{"origin":[1,2],"size":[3,4]}"#).unwrap();
+    assert_eq!(rect, crate::Rect::new(Point::new(1, 2), Size::new(3, 4)));
+    let flat: crate::Rect<i32> = serde_json::from_str("[1,2,3,4]").unwrap();
+    assert_eq!(flat, rect);
+
+    // Serialization still produces the struct-map form, and it round trips.
+    let json = serde_json::to_string(&rect).unwrap();
+    assert_eq!(
+        json,
+        r#"{"origin":{"x":1,"y":2},"size":{"width":3,"height":4}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<crate::Rect<i32>>(&json).unwrap(),
+        rect
+    );
+
+    // Unit types deserialize their logical values in either form.
+    let rect: crate::Rect<Px> = serde_json::from_str("[1,2,3,4]").unwrap();
+    assert_eq!(
+        rect,
+        crate::Rect::new(
+            Point::new(Px::new(1), Px::new(2)),
+            Size::new(Px::new(3), Px::new(4))
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn pod_casting() {
+    // Rects of pixel units cast directly to their component values, so vertex
+    // buffers of rects can be uploaded without copying.
+    let rects = [
+        crate::Rect::new(
+            Point::new(Px::new(1), Px::new(2)),
+            Size::new(Px::new(3), Px::new(4)),
+        ),
+        crate::Rect::new(
+            Point::new(Px::new(5), Px::new(6)),
+            Size::new(Px::new(7), Px::new(8)),
+        ),
+    ];
+    let raw: &[Px] = bytemuck::cast_slice(&rects);
+    assert_eq!(raw.len(), 8);
+    assert_eq!(raw[0], Px::new(1));
+    assert_eq!(raw[7], Px::new(8));
+
+    let zeroed: crate::Rect<UPx> = bytemuck::Zeroable::zeroed();
+    assert_eq!(zeroed, crate::Rect::EMPTY);
+}
+
+#[test]
+#[cfg(feature = "mint")]
+fn mint_conversions() {
+    let point = mint::Point2::from(Point::new(Px::new(3), Px::new(4)));
+    assert_eq!(point, mint::Point2 { x: 3., y: 4. });
+    assert_eq!(Point::<Px>::from(point), Point::new(Px::new(3), Px::new(4)));
+
+    let size = mint::Vector2::from(Size::new(Px::new(5), Px::new(6)));
+    assert_eq!(size, mint::Vector2 { x: 5., y: 6. });
+    assert_eq!(Size::<Px>::from(size), Size::new(Px::new(5), Px::new(6)));
+}
+
+#[test]
+fn float64_conversions() {
+    use crate::traits::FloatConversion;
+
+    // Values beyond f32's 24-bit mantissa lose precision through
+    // `into_float`, but convert losslessly through `into_float64`.
+    let large = UPx::new(16_777_217);
+    assert!((large.into_float() - 16_777_216.).abs() < f32::EPSILON);
+    assert!((large.into_float64() - 16_777_217.).abs() < f64::EPSILON);
+    assert_eq!(UPx::from_float64(large.into_float64()), large);
+
+    let point = Point::new(Px::new(16_777_217), Px::new(-16_777_217));
+    assert_eq!(Point::from_float64(point.into_float64()), point);
+    let rect = crate::Rect::new(point, Size::new(Px::new(1), Px::new(2)));
+    assert_eq!(crate::Rect::from_float64(rect.into_float64()), rect);
+}
+
+#[test]
+fn align_within() {
+    use crate::{Alignment, Edges};
+
+    let container = crate::Rect::new(
+        Point::new(Px::new(10), Px::new(10)),
+        Size::new(Px::new(100), Px::new(100)),
+    );
+    let content = crate::Rect::new(Point::ORIGIN, Size::new(Px::new(20), Px::new(10)));
+    assert_eq!(
+        content.align_within(container, Alignment::Start, Alignment::Start),
+        crate::Rect::new(Point::new(Px::new(10), Px::new(10)), content.size)
+    );
+    assert_eq!(
+        content.align_within(container, Alignment::Center, Alignment::Center),
+        crate::Rect::new(Point::new(Px::new(50), Px::new(55)), content.size)
+    );
+    assert_eq!(
+        content.align_within(container, Alignment::End, Alignment::End),
+        crate::Rect::new(Point::new(Px::new(90), Px::new(100)), content.size)
+    );
+
+    // Edge insets apply each side independently, and `all` matches `inset`.
+    assert_eq!(
+        container.inset_by(Edges::all(Px::new(5))),
+        container.inset(Px::new(5))
+    );
+    assert_eq!(Edges::all(Px::new(5)).size(), Size::squared(Px::new(10)));
+}
+
+#[test]
+fn edges_arithmetic() {
+    use crate::Edges;
+
+    let margin = Edges::new(Px::new(1), Px::new(2), Px::new(3), Px::new(4));
+    let doubled = margin + margin;
+    assert_eq!(
+        doubled,
+        Edges::new(Px::new(2), Px::new(4), Px::new(6), Px::new(8))
+    );
+    assert_eq!(doubled - margin, margin);
+
+    let rect = crate::Rect::new(
+        Point::new(Px::new(10), Px::new(10)),
+        Size::new(Px::new(100), Px::new(100)),
+    );
+    // Subtracting edges insets; adding them outsets back.
+    let inset = rect - margin;
+    assert_eq!(inset, rect.inset_by(margin));
+    assert_eq!(inset + margin, rect);
+}
+
+#[test]
+fn fraction_container_math() {
+    let size = Size::new(Px::new(30), Px::new(60));
+    let two_thirds = Fraction::new(2, 3);
+    assert_eq!(size * two_thirds, Size::new(Px::new(20), Px::new(40)));
+    assert_eq!(size / two_thirds, Size::new(Px::new(45), Px::new(90)));
+
+    let point = Point::new(Px::new(30), Px::new(60));
+    assert_eq!(point * two_thirds, Point::new(Px::new(20), Px::new(40)));
+
+    let rect = crate::Rect::new(point, size);
+    let scaled = rect * two_thirds;
+    assert_eq!(
+        scaled,
+        crate::Rect::new(
+            Point::new(Px::new(20), Px::new(40)),
+            Size::new(Px::new(20), Px::new(40))
+        )
+    );
+    assert_eq!(scaled / two_thirds, rect);
+}
+
+#[test]
+fn rect_splits() {
+    let rect = crate::Rect::new(
+        Point::new(Px::new(10), Px::new(10)),
+        Size::new(Px::new(100), Px::new(50)),
+    );
+    let (left, right) = rect.split_horizontal(Px::new(30));
+    assert_eq!(
+        left,
+        crate::Rect::new(rect.origin, Size::new(Px::new(30), Px::new(50)))
+    );
+    assert_eq!(
+        right,
+        crate::Rect::new(
+            Point::new(Px::new(40), Px::new(10)),
+            Size::new(Px::new(70), Px::new(50))
+        )
+    );
+    // The split point is clamped to the rectangle.
+    let (all, empty) = rect.split_horizontal(Px::new(500));
+    assert_eq!(all, rect);
+    assert!(empty.is_empty());
+
+    let (top, bottom) = rect.split_vertical(Px::new(20));
+    assert_eq!(top.size.height, Px::new(20));
+    assert_eq!(bottom.size.height, Px::new(30));
+    assert_eq!(bottom.origin.y, Px::new(30));
+
+    // Rows and columns tile the rectangle exactly, even with remainders.
+    let columns: Vec<_> = rect.columns(3).collect();
+    assert_eq!(columns.len(), 3);
+    assert_eq!(columns[0].origin, rect.origin);
+    let total: Px = columns.iter().map(|column| column.size.width).sum();
+    assert_eq!(total, rect.size.width);
+    for pair in columns.windows(2) {
+        assert_eq!(pair[1].origin.x, pair[0].origin.x + pair[0].size.width);
+    }
+
+    let rows: Vec<_> = rect.rows(7).collect();
+    assert_eq!(rows.len(), 7);
+    let total: Px = rows.iter().map(|row| row.size.height).sum();
+    assert_eq!(total, rect.size.height);
+    assert_eq!(rect.rows(0).count(), 0);
+}
+
+#[test]
+fn rect_grid() {
+    let rect = crate::Rect::new(
+        Point::new(Px::new(10), Px::new(10)),
+        Size::new(Px::new(100), Px::new(50)),
+    );
+    let cells: Vec<_> = rect.grid(3, 7).collect();
+    assert_eq!(cells.len(), 21);
+
+    // Cells are produced in row-major order and agree with `cell`.
+    for row in 0..7 {
+        for column in 0..3 {
+            assert_eq!(
+                rect.cell(column, row, 3, 7),
+                Some(cells[usize::try_from(row * 3 + column).expect("in range")])
+            );
+        }
+    }
+    assert_eq!(rect.cell(3, 0, 3, 7), None);
+    assert_eq!(rect.cell(0, 7, 3, 7), None);
+
+    // Each row of cells tiles the rectangle's width exactly, even with
+    // remainders.
+    for row in cells.chunks(3) {
+        let total: Px = row.iter().map(|cell| cell.size.width).sum();
+        assert_eq!(total, rect.size.width);
+    }
+    let total: Px = cells.chunks(3).map(|row| row[0].size.height).sum();
+    assert_eq!(total, rect.size.height);
+
+    assert_eq!(rect.grid(0, 7).count(), 0);
+    assert_eq!(rect.grid(3, 0).count(), 0);
+}
+
+#[test]
+fn rect_containment() {
+    let rect = crate::Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(10), Px::new(10)),
+    );
+    // The far edges are exclusive for `contains` and inclusive for
+    // `contains_inclusive`.
+    assert!(rect.contains(Point::new(Px::new(0), Px::new(0))));
+    assert!(!rect.contains(Point::new(Px::new(10), Px::new(5))));
+    assert!(rect.contains_inclusive(Point::new(Px::new(10), Px::new(5))));
+    assert!(!rect.contains_inclusive(Point::new(Px::new(11), Px::new(5))));
+
+    assert!(rect.contains_rect(&rect));
+    assert!(rect.contains_rect(&crate::Rect::new(
+        Point::new(Px::new(2), Px::new(2)),
+        Size::new(Px::new(8), Px::new(8)),
+    )));
+    assert!(!rect.contains_rect(&crate::Rect::new(
+        Point::new(Px::new(2), Px::new(2)),
+        Size::new(Px::new(9), Px::new(8)),
+    )));
+    assert!(!rect.contains_rect(&crate::Rect::new(
+        Point::new(Px::new(-1), Px::new(0)),
+        Size::new(Px::new(5), Px::new(5)),
+    )));
+}
+
+#[test]
+fn rotated_bounding_box() {
+    let rect = crate::Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(40), Px::new(20)),
+    );
+    // A 90 degree rotation swaps the extents around the center.
+    assert_eq!(
+        rect.rotated_bounding_box(Angle::degrees(90)),
+        crate::Rect::new(
+            Point::new(Px::new(10), Px::new(-10)),
+            Size::new(Px::new(20), Px::new(40))
+        )
+    );
+    // Half and full rotations leave the bounding box unchanged.
+    assert_eq!(rect.rotated_bounding_box(Angle::degrees(180)), rect);
+    assert_eq!(rect.rotated_bounding_box(Angle::degrees(0)), rect);
+
+    // Rotating around the rect's own origin keeps that corner fixed.
+    let rotated = rect.rotated_bounding_box_around(Angle::degrees(90), Point::default());
+    assert_eq!(
+        rotated,
+        crate::Rect::new(
+            Point::new(Px::new(-20), Px::new(0)),
+            Size::new(Px::new(20), Px::new(40))
+        )
+    );
+
+    // A 45 degree rotation grows the bounding box.
+    let rotated = rect.rotated_bounding_box(Angle::degrees(45));
+    assert!(rotated.size.width > rect.size.width);
+    assert!(rotated.size.height > rect.size.height);
+}
+
+#[test]
+fn point_distances() {
+    let a = Point::new(Px::new(1), Px::new(2));
+    let b = Point::new(Px::new(4), Px::new(6));
+    assert_eq!(a.distance_to(b), Px::new(5));
+    assert_eq!(b.distance_to(a), Px::new(5));
+    assert!((a.distance_squared_to(b) - 25.).abs() < f64::EPSILON);
+
+    // Large coordinates overflow a squared i32, but not the widened math.
+    let far = Point::new(100_000, 0);
+    assert_eq!(Point::new(0, 0).distance_to(far), 100_000);
+    assert!((Point::<i32>::new(0, 0).distance_squared_to(far) - 1e10).abs() < f64::EPSILON);
+}
+
+#[test]
+fn componentwise_min_max() {
+    let (min, max) =
+        Point::new(Px::new(5), Px::new(-2)).min_max(Point::new(Px::new(1), Px::new(3)));
+    assert_eq!(min, Point::new(Px::new(1), Px::new(-2)));
+    assert_eq!(max, Point::new(Px::new(5), Px::new(3)));
+    assert_eq!(
+        crate::Rect::from_extents(min, max).size,
+        Size::new(Px::new(4), Px::new(5))
+    );
+
+    let (min, max) =
+        Size::new(UPx::new(10), UPx::new(2)).min_max(Size::new(UPx::new(4), UPx::new(8)));
+    assert_eq!(min, Size::new(UPx::new(4), UPx::new(2)));
+    assert_eq!(max, Size::new(UPx::new(10), UPx::new(8)));
+}
+
+#[test]
+fn centered_rects() {
+    let rect = crate::Rect::from_center(
+        Point::new(Px::new(50), Px::new(50)),
+        Size::new(Px::new(20), Px::new(10)),
+    );
+    assert_eq!(rect.origin, Point::new(Px::new(40), Px::new(45)));
+    assert_eq!(rect.center(), Point::new(Px::new(50), Px::new(50)));
+
+    let expanded = rect.centered_expand(Size::squared(Px::new(4)));
+    assert_eq!(expanded.center(), rect.center());
+    assert_eq!(expanded.size, Size::new(Px::new(24), Px::new(14)));
+
+    let resized = rect.with_size_centered(Size::squared(Px::new(6)));
+    assert_eq!(resized.center(), rect.center());
+    assert_eq!(resized.size, Size::squared(Px::new(6)));
+}
+
+#[test]
+fn wide_area() {
+    use crate::WideMul;
+
+    // 100,000 squared overflows an i32 but not the widened area.
+    let size = Size::new(Px::new(100_000), Px::new(100_000));
+    assert_eq!(size.area_wide(), 40_000_000_000);
+    assert_eq!(Px::new(100_000).wide_mul(Px::new(100_000)), 40_000_000_000);
+    // The result is expressed in the unit's scaled representation: UPx
+    // stores four subpixels per pixel, so the area of `n x 4` is `n * 16`.
+    assert_eq!(
+        Size::new(UPx::new(u32::MAX / 4), UPx::new(4)).area_wide(),
+        u64::from(u32::MAX / 4) * 16
+    );
+
+    // The widened magnitudes keep `Ord` consistent for large vectors.
+    let large = Point::new(100_000, 100_000);
+    let larger = Point::new(100_001, 100_001);
+    assert!(large < larger);
+}
+
+#[test]
+fn round_to_step() {
+    use crate::Round;
+
+    assert_eq!(Px::new(13).round_to_nearest(Px::new(8)), Px::new(16));
+    assert_eq!(Px::new(11).round_to_nearest(Px::new(8)), Px::new(8));
+    assert_eq!(Px::new(13).floor_to(Px::new(8)), Px::new(8));
+    assert_eq!(Px::new(13).ceil_to(Px::new(8)), Px::new(16));
+    assert_eq!(Px::new(16).round_to_nearest(Px::new(8)), Px::new(16));
+
+    // Fractional values snap as well: 2.25px to a 0.5px step.
+    assert_eq!(
+        Px::from(2.25).round_to_nearest(Px::from(0.5)),
+        Px::from(2.5)
+    );
+
+    // Snapping a point to an 8x8 grid.
+    let point = Point::new(Px::new(13), Px::new(3));
+    assert_eq!(
+        point.round_to_nearest(Point::squared(Px::new(8))),
+        Point::new(Px::new(16), Px::new(0))
+    );
+    assert_eq!(
+        point.ceil_to(Point::squared(Px::new(8))),
+        Point::new(Px::new(16), Px::new(8))
+    );
+    assert_eq!(
+        point.floor_to(Point::squared(Px::new(8))),
+        Point::new(Px::new(8), Px::new(0))
+    );
+
+    assert!((5.2f32.round_to_nearest(2.) - 6.).abs() < f32::EPSILON);
+    assert!((5.2f32.floor_to(2.) - 4.).abs() < f32::EPSILON);
+}
+
+#[test]
+fn unsigned_deltas() {
+    assert_eq!(UPx::new(3).abs_diff(UPx::new(10)), UPx::new(7));
+    assert_eq!(UPx::new(10).abs_diff(UPx::new(3)), UPx::new(7));
+    assert_eq!(UPx::new(3).signed_delta(UPx::new(10)), Px::new(-7));
+    assert_eq!(UPx::new(10).signed_delta(UPx::new(3)), Px::new(7));
+    // Deltas beyond Px's range saturate instead of wrapping.
+    assert_eq!(UPx::MAX.signed_delta(UPx::ZERO), Px::MAX);
+
+    let before = Point::new(UPx::new(10), UPx::new(4));
+    let after = Point::new(UPx::new(6), UPx::new(9));
+    assert_eq!(after.abs_diff(before), Point::new(UPx::new(4), UPx::new(5)));
+    assert_eq!(
+        after.signed_delta(before),
+        Point::new(Px::new(-4), Px::new(5))
+    );
+    assert_eq!(
+        Size::new(UPx::new(1), UPx::new(2)).signed_delta(Size::new(UPx::new(2), UPx::new(1))),
+        Size::new(Px::new(-1), Px::new(1))
+    );
+}
+
+#[test]
+fn component_round_trips() {
+    use crate::{FromComponents4, IntoComponents4};
+
+    // Point and Size round-trip through arrays.
+    let point = Point::new(Px::new(1), Px::new(2));
+    let array: [Px; 2] = point.to_vec();
+    assert_eq!(array, [Px::new(1), Px::new(2)]);
+    assert_eq!(Point::from_vec(array), point);
+    assert_eq!(
+        Size::<Px>::from_vec([Px::new(3), Px::new(4)]).width,
+        Px::new(3)
+    );
+
+    // Rect round-trips through (x, y, w, h) tuples and [Unit; 4].
+    let rect = crate::Rect::new(point, Size::new(Px::new(3), Px::new(4)));
+    assert_eq!(
+        rect.into_components4(),
+        (Px::new(1), Px::new(2), Px::new(3), Px::new(4))
+    );
+    let array: [Px; 4] = rect.to_vec4();
+    assert_eq!(array, [Px::new(1), Px::new(2), Px::new(3), Px::new(4)]);
+    assert_eq!(crate::Rect::from_vec4(array), rect);
+    assert_eq!(
+        crate::Rect::from_components4((1, 2, 3, 4)),
+        crate::Rect::new(Point::new(1, 2), Size::new(3, 4))
+    );
+}
+
+#[test]
+fn axis_indexing() {
+    let mut point = Point::new(Px::new(1), Px::new(2));
+    assert_eq!(point[0], point.x);
+    assert_eq!(point[1], point.y);
+    point[1] = Px::new(5);
+    assert_eq!(point.y, Px::new(5));
+
+    let mut size = Size::new(Px::new(3), Px::new(4));
+    for axis in 0..2 {
+        size[axis] *= 2;
+    }
+    assert_eq!(size, Size::new(Px::new(6), Px::new(8)));
+
+    assert_eq!(Point::from([1, 2]), Point::new(1, 2));
+    assert_eq!(<[i32; 2]>::from(Size::new(3, 4)), [3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "two components")]
+fn axis_indexing_out_of_bounds() {
+    let point = Point::new(Px::new(1), Px::new(2));
+    let _ = point[2];
+}
+
+#[test]
+fn axis_access() {
+    use crate::Axis;
+
+    assert_eq!(Axis::X.cross(), Axis::Y);
+    assert_eq!(Axis::Y.cross(), Axis::X);
+
+    let mut point = Point::new(Px::new(1), Px::new(2));
+    assert_eq!(point.get(Axis::X), Px::new(1));
+    assert_eq!(point.get(Axis::Y), Px::new(2));
+    point.set(Axis::X, Px::new(7));
+    assert_eq!(point.x, Px::new(7));
+
+    let size = Size::new(Px::new(3), Px::new(4));
+    assert_eq!(size.get(Axis::X), Px::new(3));
+    assert_eq!(size.get(Axis::X.cross()), Px::new(4));
+
+    let rect = crate::Rect::new(point, size);
+    assert_eq!(rect.length(Axis::X), Px::new(3));
+    assert_eq!(rect.length(Axis::Y), Px::new(4));
+}
+
+#[test]
+fn one_values() {
+    use crate::{One, Ranged};
+
+    assert_eq!(Px::ONE, Px::new(1));
+    assert!(Px::new(1).is_one());
+    assert!(!Px::new(2).is_one());
+    assert_eq!(UPx::ONE, UPx::new(1));
+    assert_eq!(Lp::ONE, Lp::new(1));
+    assert_eq!(<Fraction as One>::ONE, Fraction::new_whole(1));
+    assert!(Fraction::new(2, 2).is_one());
+    assert_eq!(<Fraction as Ranged>::MAX, Fraction::MAX);
+
+    assert_eq!(Point::<Px>::ONE, Point::squared(Px::new(1)));
+    assert!(Size::new(UPx::new(1), UPx::new(1)).is_one());
+    assert!(!Size::new(UPx::new(1), UPx::new(0)).is_one());
+}
+
+#[test]
+fn percent_dimensions() {
+    use crate::units::{Dimension, Percent};
+
+    assert_eq!(Percent::new(50).of(Px::new(100)), Px::new(50));
+    assert_eq!(Percent::ONE_HUNDRED.of(Lp::inches(1)), Lp::inches(1));
+    assert_eq!(
+        Percent::from_fraction(Fraction::new(1, 4)),
+        Percent::new(25)
+    );
+    assert_eq!(Percent::new(50).to_string(), "50%");
+
+    let absolute = Dimension::Absolute(Px::new(10));
+    assert_eq!(absolute.resolve(Px::new(100)), Px::new(10));
+    let relative = Dimension::<Px>::from(Percent::new(30));
+    assert_eq!(relative.resolve(Px::new(100)), Px::new(30));
+    assert_eq!(Dimension::<Px>::default(), Dimension::Absolute(Px::ZERO));
+}
+
+#[test]
+fn auto_dimension() {
+    use crate::units::Dimension;
+
+    let auto = Dimension::<Px>::Auto;
+    assert!(auto.is_auto());
+    assert_eq!(auto.resolve(Px::new(100)), Px::new(100));
+    assert_eq!(auto.resolve_or(Px::new(100), Px::new(42)), Px::new(42));
+    assert!(!Dimension::Absolute(Px::new(1)).is_auto());
+}
+
+#[test]
+fn em_container_resolution() {
+    use crate::units::Em;
+
+    let font_size = Lp::points(12);
+    assert_eq!(Em::new(2).resolve(font_size), Lp::points(24));
+    assert_eq!(
+        Point::new(Em::new(1), Em::new(2)).resolve(font_size),
+        Point::new(Lp::points(12), Lp::points(24))
+    );
+    assert_eq!(
+        Size::new(Em::from(0.5), Em::new(1)).resolve(font_size),
+        Size::new(Lp::points(6), Lp::points(12))
+    );
+}
+
+#[test]
+fn lp_physical_units() {
+    assert_eq!(Lp::picas(6), Lp::inches(1));
+    assert_eq!(Lp::twips(1440), Lp::inches(1));
+    assert_eq!(Lp::css_px(96), Lp::inches(1));
+    assert_eq!(Lp::twips(20), Lp::points(1));
+    assert_eq!(Lp::picas_f(6.), Lp::inches_f(1.));
+    assert_eq!(Lp::twips_f(1440.), Lp::inches_f(1.));
+    assert_eq!(Lp::css_px_f(96.), Lp::inches_f(1.));
+
+    let two_inches = Lp::inches(2);
+    assert!((two_inches.into_inches_f() - 2.).abs() < f32::EPSILON);
+    assert!((two_inches.into_cm_f() - 5.08).abs() < 1e-5);
+    assert!((two_inches.into_mm_f() - 50.8).abs() < 1e-4);
+    assert!((two_inches.into_points_f() - 144.).abs() < f32::EPSILON);
+    assert!((two_inches.into_picas_f() - 12.).abs() < f32::EPSILON);
+    assert!((two_inches.into_css_px_f() - 192.).abs() < f32::EPSILON);
+    assert!((two_inches.into_twips_f() - 2880.).abs() < 1e-2);
+}
+
+#[test]
+fn px_fractional_accessors() {
+    use crate::Fraction64;
+
+    let px = Px::new(2) + Px::from(0.75);
+    assert_eq!(px.whole(), 2);
+    assert_eq!(px.fract(), Fraction::new(3, 4));
+    assert_eq!(px.into_fraction(), Fraction64::new(11, 4));
+
+    let negative = -px;
+    assert_eq!(negative.whole(), -2);
+    assert_eq!(negative.fract(), Fraction::new(-3, 4));
+    assert_eq!(negative.into_fraction(), Fraction64::new(-11, 4));
+}
+
+#[test]
+fn axis_flips() {
+    let point = Point::new(Px::new(3), Px::new(-7));
+    assert_eq!(point.swap_axes(), Point::new(Px::new(-7), Px::new(3)));
+    assert_eq!(point.flip_x(), Point::new(Px::new(-3), Px::new(-7)));
+    assert_eq!(point.flip_y(), Point::new(Px::new(3), Px::new(7)));
+
+    assert_eq!(
+        Size::new(Px::new(4), Px::new(9)).transpose(),
+        Size::new(Px::new(9), Px::new(4))
+    );
+    assert_eq!(
+        crate::Rect::new(point, Size::new(Px::new(4), Px::new(9))).transpose(),
+        crate::Rect::new(point.swap_axes(), Size::new(Px::new(9), Px::new(4)))
+    );
+}
+
+#[test]
+fn vertical_coordinate_flips() {
+    let height = Px::new(100);
+    let point = Point::new(Px::new(10), Px::new(30));
+    let flipped = point.flip_vertically_within(height);
+    assert_eq!(flipped, Point::new(Px::new(10), Px::new(70)));
+    assert_eq!(flipped.flip_vertically_within(height), point);
+
+    let rect = crate::Rect::new(point, Size::new(Px::new(20), Px::new(40)));
+    let flipped = rect.flip_vertically_within(height);
+    assert_eq!(
+        flipped,
+        crate::Rect::new(Point::new(Px::new(10), Px::new(30)), rect.size)
+    );
+    assert_eq!(flipped.flip_vertically_within(height), rect);
+}
+
+#[test]
+fn screen_scale_composites() {
+    let scale = Fraction::ONE;
+    let (length, angle) = (Lp::inches(1), Angle::degrees(45)).into_px(scale);
+    assert_eq!(length, Px::new(96));
+    assert_eq!(angle, Angle::degrees(45));
+    assert_eq!(
+        <(Lp, Angle)>::from_px((length, angle), scale),
+        (Lp::inches(1), Angle::degrees(45))
+    );
+
+    assert_eq!(
+        [Lp::inches(1), Lp::inches(2)].into_px(scale),
+        [Px::new(96), Px::new(192)]
+    );
+    assert_eq!(Fraction::new(1, 2).into_px(scale), Fraction::new(1, 2));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_screen_scale() {
+    #[derive(figures::ScreenScale, Clone, Copy, Eq, PartialEq, Debug)]
+    struct Layout<Unit> {
+        bounds: crate::Rect<Unit>,
+        gap: Unit,
+    }
+
+    let layout = Layout {
+        bounds: crate::Rect::new(
+            Point::new(Lp::inches(1), Lp::inches(2)),
+            Size::squared(Lp::inches(1)),
+        ),
+        gap: Lp::inches(1),
+    };
+    let scaled = layout.into_px(Fraction::new_whole(2));
+    assert_eq!(scaled.gap, Px::new(192));
+    assert_eq!(scaled.bounds.origin.x, Px::new(192));
+    assert_eq!(Layout::from_px(scaled, Fraction::new_whole(2)), layout);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_components_and_consts() {
+    use crate::Ranged;
+
+    #[derive(
+        figures::IntoComponents,
+        figures::FromComponents,
+        figures::Zero,
+        figures::Ranged,
+        Clone,
+        Copy,
+        Eq,
+        PartialEq,
+        Debug,
+    )]
+    struct Spacing<Unit> {
+        horizontal: Unit,
+        vertical: Unit,
+    }
+
+    let spacing = Spacing {
+        horizontal: Px::new(4),
+        vertical: Px::new(8),
+    };
+    assert_eq!(spacing.into_components(), (Px::new(4), Px::new(8)));
+    assert_eq!(Spacing::from_components((Px::new(4), Px::new(8))), spacing);
+    assert_eq!(
+        spacing.to_vec::<Point<Px>>(),
+        Point::new(Px::new(4), Px::new(8))
+    );
+
+    assert!(Spacing::<Px>::ZERO.is_zero());
+    assert!(!spacing.is_zero());
+    assert_eq!(Spacing::<Px>::MAX.horizontal, Px::MAX);
+    assert_eq!(Spacing::<Px>::MIN.vertical, Px::MIN);
+}
+
+#[cfg(feature = "winit")]
+#[test]
+fn winit_logical_conversions() {
+    let scale = Fraction::new_whole(2);
+    let logical = winit::dpi::LogicalPosition::new(10.0, 20.0);
+    let point = Point::<Px>::from_logical(logical, scale);
+    assert_eq!(point, Point::new(Px::new(20), Px::new(40)));
+    assert_eq!(point.to_logical(scale), logical);
+
+    let size = Size::<UPx>::from_logical(winit::dpi::LogicalSize::new(30.0, 40.0), scale);
+    assert_eq!(size, Size::new(UPx::new(60), UPx::new(80)));
+    assert_eq!(
+        size.to_logical(scale),
+        winit::dpi::LogicalSize::new(30.0, 40.0)
+    );
+}
+
+#[cfg(feature = "winit")]
+#[test]
+fn winit_size_conversion_errors() {
+    assert_eq!(
+        Size::<UPx>::try_from_winit(winit::dpi::PhysicalSize::new(1920, 1080)),
+        Ok(Size::new(UPx::new(1920), UPx::new(1080)))
+    );
+    assert_eq!(
+        Size::<UPx>::try_from_winit(winit::dpi::PhysicalSize::new(u32::MAX, 1080)),
+        Err(crate::SizeOverflow)
+    );
+    assert_eq!(
+        Size::<Px>::try_from(winit::dpi::PhysicalSize::new(i32::MAX, 0)),
+        Err(crate::SizeOverflow)
+    );
+}
+
+#[test]
+fn cross_signed_comparisons() {
+    use core::cmp::Ordering;
+
+    assert_eq!(Px::new(-1).cmp_unsigned(UPx::new(0)), Ordering::Less);
+    assert_eq!(Px::new(1).cmp_unsigned(UPx::new(1)), Ordering::Equal);
+    assert_eq!(Px::MAX.cmp_unsigned(UPx::MAX), Ordering::Less);
+    assert_eq!(UPx::new(0).cmp_signed(Px::new(-1)), Ordering::Greater);
+    assert_eq!(UPx::MAX.cmp_signed(Px::MAX), Ordering::Greater);
+
+    // The `PartialOrd` impls agree with the explicit methods.
+    assert!(Px::new(-1) < UPx::new(0));
+    assert!(UPx::new(0) > Px::new(-1));
+    assert!(UPx::MAX > Px::MAX);
+
+    assert_eq!(Px::new(-5).saturating_into_unsigned(), UPx::new(0));
+    assert_eq!(UPx::MAX.saturating_into_signed(), Px::MAX);
+    assert_eq!(UPx::new(7).saturating_into_signed(), Px::new(7));
+}
+
+#[test]
+fn units64() {
+    use crate::units::{Px64, UPx64};
+    use crate::IntoUnsigned;
+
+    // Values that overflow `Px` are representable losslessly.
+    let large = Px64::new(1) * i64::from(i32::MAX);
+    assert_eq!(Px64::from(Px::new(100)), Px64::new(100));
+    assert!(large > Px64::from(Px::MAX));
+    assert!(Px::try_from(large).is_err());
+    assert_eq!(Px::try_from(Px64::new(100)), Ok(Px::new(100)));
+
+    assert_eq!(UPx64::from(UPx::new(7)), UPx64::new(7));
+    assert_eq!(UPx::try_from(UPx64::new(7)), Ok(UPx::new(7)));
+    assert!(UPx::try_from(UPx64::MAX).is_err());
+
+    assert_eq!(Px64::new(-3).into_unsigned(), UPx64::ZERO);
+    assert_eq!(Px64::new(6) * Px64::new(7), Px64::new(42));
+    assert_eq!(format!("{}", Px64::new(3) + Px64::from(0.5)), "3.5px");
+}
+
+#[test]
+fn uv_coordinates() {
+    use crate::units::Texel;
+    use crate::{Rect, UvCoord};
+
+    let texture_size = Size::new(UPx::new(256), UPx::new(128));
+    assert_eq!(
+        Point::new(UPx::new(128), UPx::new(64)).to_uv(texture_size),
+        UvCoord::new(0.5, 0.5)
+    );
+    assert_eq!(
+        UvCoord::new(0.5, 0.5).to_point(texture_size),
+        Point::new(UPx::new(128), UPx::new(64))
+    );
+    let rect = Rect::new(
+        Point::new(UPx::new(64), UPx::new(0)),
+        Size::new(UPx::new(64), UPx::new(64)),
+    );
+    assert_eq!(
+        rect.to_uv(texture_size),
+        Rect::new(Point::new(0.25, 0.), Size::new(0.25, 0.5))
+    );
+    // The inset variant pulls each edge in by half a texel.
+    let inset = rect.to_uv_inset(texture_size);
+    assert_eq!(inset.origin, Point::new(0.25 + 0.5 / 256., 0.5 / 128.));
+    assert_eq!(inset.size, Size::new(0.25 - 1. / 256., 0.5 - 1. / 128.));
+    assert_eq!(UvCoord::new(-0.5, 1.5).clamped(), UvCoord::new(0., 1.));
+
+    // `Texel` measures whole texture pixels, and converts to UV coordinates
+    // the same way `UPx` does.
+    assert_eq!(Texel::new(2) * 2, Texel::new(4));
+    assert_eq!(
+        Point::new(Texel::new(32), Texel::new(96))
+            .to_uv(Size::new(Texel::new(128), Texel::new(128))),
+        UvCoord::new(0.25, 0.75)
+    );
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn batch_transforms() {
+    use crate::batch::{scale_points, transform_points, translate_points};
+    use crate::TransformStack;
+
+    // A length that isn't a multiple of the chunk size exercises the
+    // remainder path.
+    let original: Vec<_> = (0..37)
+        .map(|i| Point::new(Px::new(i), Px::new(i * 3 - 20)))
+        .collect();
+
+    let mut points = original.clone();
+    let delta = Point::new(Px::new(5), Px::new(-7));
+    translate_points(&mut points, delta);
+    for (point, original) in points.iter().zip(&original) {
+        assert_eq!(*point, *original + delta);
+    }
+
+    let mut points = original.clone();
+    let scale = Fraction::new(3, 2);
+    scale_points(&mut points, scale);
+    for (point, original) in points.iter().zip(&original) {
+        assert_eq!(*point, *original * scale);
+    }
+
+    let mut points = original.clone();
+    let mut transform = TransformStack::new();
+    transform.push(Point::new(Px::new(10), Px::new(20)), Fraction::new(2, 1));
+    transform_points(&mut points, &transform);
+    for (point, original) in points.iter().zip(&original) {
+        assert_eq!(*point, transform.apply(*original));
+    }
+}
+
+#[test]
+fn const_arithmetic() {
+    const MARGIN: Point<Px> = Point::new(Px::new(5), Px::new(5));
+    const ORIGIN: Point<Px> = Point::new(Px::new(10), Px::new(20)).const_add(MARGIN);
+    const SIZE: Size<Px> = Size::new(Px::new(100), Px::new(50)).const_sub(Size::new(
+        MARGIN.x.const_add(MARGIN.x),
+        MARGIN.y.const_add(MARGIN.y),
+    ));
+    const RECT: crate::Rect<Px> = crate::Rect::new(ORIGIN, SIZE);
+
+    assert_eq!(RECT.origin, Point::new(Px::new(15), Px::new(25)));
+    assert_eq!(RECT.size, Size::new(Px::new(90), Px::new(40)));
+    assert_eq!(Px::new(3).const_sub(Px::new(7)), Px::new(-4));
+    assert_eq!(
+        Lp::points(10).const_add(Lp::points(2)),
+        Lp::points(10) + Lp::points(2)
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_geometry() {
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(7);
+    let rect = crate::Rect::new(
+        Point::new(Px::new(-10), Px::new(5)),
+        Size::new(Px::new(20), Px::new(10)),
+    );
+    for _ in 0..1000 {
+        let point = rect.random_point_inside(&mut rng);
+        assert!(point.x >= Px::new(-10) && point.x <= Px::new(10));
+        assert!(point.y >= Px::new(5) && point.y <= Px::new(15));
+    }
+
+    let angle: Angle = rng.gen();
+    let degrees = angle.into_degrees::<f32>();
+    assert!((0. ..360.).contains(&degrees));
+    // Points and sizes sample each component independently.
+    let _point: Point<Px> = rng.gen();
+    let _size: Size<UPx> = rng.gen();
+    let _lp: Point<Lp> = rng.gen();
+}
+
+#[cfg(feature = "proptest")]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::traits::{IntoSigned, IntoUnsigned};
+    use crate::Rect;
+
+    proptest! {
+        #[test]
+        fn extents_are_ordered(
+            // Adding extreme origins and sizes overflows `i32`, which panics
+            // in debug builds, so constrain the components.
+            rect in any_with::<Rect<Px>>(Some(Px::new(-100_000)..=Px::new(100_000))),
+        ) {
+            let (top_left, bottom_right) = rect.extents();
+            prop_assert!(top_left.x <= bottom_right.x);
+            prop_assert!(top_left.y <= bottom_right.y);
+        }
+
+        #[test]
+        fn signed_conversions_saturate(px: Px, upx: UPx) {
+            prop_assert!(px.into_unsigned() >= UPx::ZERO);
+            prop_assert!(upx.into_signed() >= Px::ZERO);
+        }
+
+        #[test]
+        fn constrained_units_stay_in_range(
+            px in any_with::<Px>(Some(Px::new(-10)..=Px::new(10))),
+        ) {
+            prop_assert!(px >= Px::new(-10));
+            prop_assert!(px <= Px::new(10));
+        }
+
+        #[test]
+        fn angles_are_normalized(angle: Angle) {
+            let degrees = angle.into_degrees::<f32>();
+            prop_assert!((0. ..360.).contains(&degrees));
+        }
+    }
+}
+
+#[test]
+fn decimal_constructors() {
+    use crate::traits::FloatConversion;
+
+    assert_eq!(Px::from_decimal(1, 25), Px::from_float(1.25));
+    assert_eq!(Px::from_decimal(-1, 50), Px::from_float(-1.5));
+    assert_eq!(Px::from_decimal(10, 0), Px::new(10));
+    assert_eq!(Lp::from_decimal(2, 0), Lp::new(2));
+    assert_eq!(
+        Lp::from_decimal(0, 20),
+        Lp::from_float(0.2),
+        "hundredths that divide the scale evenly are exact"
+    );
+    assert_eq!(Lp::from_decimal(-3, 20), Lp::from_float(-3.2));
+
+    assert_eq!(Px::try_from("1.25px"), Ok(Px::from_decimal(1, 25)));
+    assert_eq!(UPx::try_from("3"), Ok(UPx::new(3)));
+    assert_eq!(Lp::try_from("2pt"), Ok(Lp::points(2)));
+    assert!(Px::try_from("1.25em").is_err());
+}
+
+#[test]
+fn approximate_comparisons() {
+    use crate::traits::ApproxEq;
+    use crate::Rect;
+
+    // One subpixel step apart.
+    let a = Px::from_decimal(10, 25);
+    let b = Px::from_decimal(10, 50);
+    assert!(a.approx_eq(b, Px::from_decimal(0, 25)));
+    assert!(!a.approx_eq(b, Px::new(0)));
+    assert!(UPx::new(5).approx_eq(UPx::new(5), UPx::new(0)));
+
+    let point = Point::new(a, a);
+    assert!(point.approx_eq_within(Point::new(b, a), Px::from_decimal(0, 25)));
+    assert!(!point.approx_eq_within(Point::new(b, a), Px::new(0)));
+    assert!(Size::new(a, b).approx_eq_within(Size::new(b, a), Px::from_decimal(0, 25)));
+
+    let rect = Rect::new(point, Size::new(b, b));
+    assert!(rect.approx_eq_within(
+        Rect::new(Point::new(b, b), Size::new(a, a)),
+        Px::from_decimal(0, 25)
+    ));
+}
+
+#[test]
+fn clamping_within_rects() {
+    use crate::Rect;
+
+    let screen = Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(100), Px::new(100)),
+    );
+    assert_eq!(
+        Point::new(Px::new(-5), Px::new(120)).clamp_within(screen),
+        Point::new(Px::new(0), Px::new(100))
+    );
+    assert_eq!(
+        Point::new(Px::new(50), Px::new(50)).clamp_within(screen),
+        Point::new(Px::new(50), Px::new(50))
+    );
+
+    // A popup partially off the right edge is translated back on-screen.
+    let popup = Rect::new(
+        Point::new(Px::new(90), Px::new(-10)),
+        Size::new(Px::new(30), Px::new(20)),
+    );
+    assert_eq!(
+        popup.clamp_within(screen),
+        Rect::new(
+            Point::new(Px::new(70), Px::new(0)),
+            Size::new(Px::new(30), Px::new(20)),
+        )
+    );
+
+    // A rectangle larger than the container shrinks to fit.
+    let oversized = Rect::new(
+        Point::new(Px::new(-20), Px::new(10)),
+        Size::new(Px::new(200), Px::new(50)),
+    );
+    assert_eq!(
+        oversized.clamp_within(screen),
+        Rect::new(
+            Point::new(Px::new(0), Px::new(10)),
+            Size::new(Px::new(100), Px::new(50)),
+        )
+    );
+}
+
+#[test]
+fn velocity_integration() {
+    use core::time::Duration;
+
+    use crate::{Acceleration, Velocity};
+
+    let velocity = Velocity::new(Px::new(100));
+    assert_eq!(velocity * Duration::from_secs(2), Px::new(200));
+    assert_eq!(velocity * Duration::from_millis(250), Px::new(25));
+
+    // Gravity-style acceleration increases the velocity over time.
+    let gravity = Acceleration::new(Px::new(10));
+    assert_eq!(
+        velocity.accelerate(gravity, Duration::from_secs(3)),
+        Velocity::new(Px::new(130))
+    );
+    assert_eq!(gravity * Duration::from_secs(2), Velocity::new(Px::new(20)));
+
+    let start = Point::new(Px::new(10), Px::new(20));
+    let moved = start.integrate(
+        Point::new(Velocity::new(Px::new(40)), Velocity::new(Px::new(-8))),
+        Duration::from_millis(500),
+    );
+    assert_eq!(moved, Point::new(Px::new(30), Px::new(16)));
+
+    assert_eq!(
+        Velocity::new(Px::new(3)) + Velocity::new(Px::new(4)),
+        Velocity::new(Px::new(7))
+    );
+    assert_eq!(-Velocity::new(Px::new(3)), Velocity::new(Px::new(-3)));
+    assert!(Velocity::<Px>::ZERO.is_zero());
+}
+
+#[test]
+fn angular_velocity() {
+    use core::time::Duration;
+
+    use crate::AngularVelocity;
+
+    let spinner = AngularVelocity::new(Angle::degrees(45));
+    assert_eq!(spinner * Duration::from_secs(2), Angle::degrees(90));
+
+    // One revolution per second covers 180° in half a second.
+    assert_eq!(
+        AngularVelocity::turns_per_second(1) * Duration::from_millis(500),
+        Angle::degrees(180)
+    );
+    // 60 rpm is one revolution per second.
+    assert_eq!(
+        AngularVelocity::rpm(60),
+        AngularVelocity::turns_per_second(1)
+    );
+    assert_eq!(
+        AngularVelocity::rpm(30) * Duration::from_secs(1),
+        Angle::degrees(180)
+    );
+}
+
+#[test]
+fn angle_canonical_equality() {
+    // A full rotation is the same angle as no rotation.
+    assert_eq!(Angle::degrees(360), Angle::degrees(0));
+    assert_eq!(Angle::degrees(360).normalized(), Angle::degrees(0));
+    // Unreduced fractions name the same angle.
+    assert_eq!(
+        Angle::degrees_fraction(Fraction::new(180, 2)),
+        Angle::degrees(90)
+    );
+
+    // Hashing follows the canonical form, so angles can key a cache.
+    #[cfg(feature = "std")]
+    {
+        let mut cache = std::collections::HashMap::new();
+        cache.insert(Angle::degrees(0), "sprite");
+        assert_eq!(cache.get(&Angle::degrees(360)), Some(&"sprite"));
+        assert_eq!(cache.get(&Angle::degrees(90)), None);
+    }
+
+    // Ordering remains consistent with the canonical equality.
+    assert!(Angle::degrees(360) <= Angle::degrees(0));
+    assert!(Angle::degrees(359) > Angle::degrees(0));
+}
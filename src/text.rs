@@ -0,0 +1,76 @@
+use crate::units::Px;
+use crate::Zero;
+
+/// The direction a caret leans when a location is between two caret
+/// positions.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Affinity {
+    /// Resolve to the caret position at or before the location.
+    Before,
+    /// Resolve to the caret position at or after the location.
+    After,
+}
+
+/// Returns the caret index for a hit at `x`.
+///
+/// `boundaries` contains the x position of each caret index in ascending
+/// order, typically starting at zero and ending at the total advance width of
+/// the text. When `x` falls exactly on a boundary, that boundary's index is
+/// returned regardless of `affinity`; otherwise, `affinity` picks which of
+/// the two surrounding indexes is returned.
+///
+/// Locations outside of `boundaries` clamp to the first or last index. An
+/// empty slice always returns 0.
+///
+/// ```rust
+/// use figures::text::{hit_to_index, Affinity};
+/// use figures::units::Px;
+///
+/// let boundaries = [Px::new(0), Px::new(8), Px::new(20)];
+/// assert_eq!(hit_to_index(Px::new(8), &boundaries, Affinity::Before), 1);
+/// assert_eq!(hit_to_index(Px::new(10), &boundaries, Affinity::Before), 1);
+/// assert_eq!(hit_to_index(Px::new(10), &boundaries, Affinity::After), 2);
+/// assert_eq!(hit_to_index(Px::new(100), &boundaries, Affinity::After), 2);
+/// ```
+#[must_use]
+pub fn hit_to_index(x: Px, boundaries: &[Px], affinity: Affinity) -> usize {
+    match boundaries.binary_search(&x) {
+        Ok(index) => index,
+        Err(insertion) => match affinity {
+            Affinity::Before => insertion.saturating_sub(1),
+            Affinity::After => insertion.min(boundaries.len().saturating_sub(1)),
+        },
+    }
+}
+
+/// Returns the x position of the caret at `index`.
+///
+/// This is the inverse of [`hit_to_index`]. Indexes beyond the end of
+/// `boundaries` clamp to the final boundary, and an empty slice returns zero.
+#[must_use]
+pub fn index_to_x(index: usize, boundaries: &[Px]) -> Px {
+    boundaries.last().map_or(Px::ZERO, |last| {
+        boundaries.get(index).copied().unwrap_or(*last)
+    })
+}
+
+#[test]
+fn hit_round_trip() {
+    let boundaries = [Px::new(0), Px::new(8), Px::new(20), Px::new(24)];
+    for (index, x) in boundaries.iter().enumerate() {
+        assert_eq!(hit_to_index(*x, &boundaries, Affinity::Before), index);
+        assert_eq!(hit_to_index(*x, &boundaries, Affinity::After), index);
+        assert_eq!(index_to_x(index, &boundaries), *x);
+    }
+}
+
+#[test]
+fn hit_edge_cases() {
+    assert_eq!(hit_to_index(Px::new(5), &[], Affinity::Before), 0);
+    assert_eq!(hit_to_index(Px::new(5), &[], Affinity::After), 0);
+    assert_eq!(index_to_x(3, &[]), Px::ZERO);
+
+    let boundaries = [Px::new(10), Px::new(20)];
+    assert_eq!(hit_to_index(Px::new(0), &boundaries, Affinity::Before), 0);
+    assert_eq!(index_to_x(10, &boundaries), Px::new(20));
+}
@@ -1,10 +1,11 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
-use std::time::Duration;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use core::time::Duration;
 
 use intentional::{Cast, CastInto};
 
 use crate::units::{Lp, Px, UPx, ARBITRARY_SCALE};
-use crate::Fraction;
+use crate::utils::FloatExt;
+use crate::{Fraction, Scaling};
 
 /// Converts a type to its floating point representation.
 ///
@@ -13,14 +14,25 @@ pub trait FloatConversion {
     /// The type that represents this type in floating point form.
     type Float;
 
+    /// The type that represents this type in 64-bit floating point form.
+    type Float64;
+
     /// Returns this value in floating point form.
     fn into_float(self) -> Self::Float;
     /// Converts from floating point to this form.
     fn from_float(float: Self::Float) -> Self;
+    /// Returns this value in 64-bit floating point form.
+    ///
+    /// Unlike [`into_float`](Self::into_float), this conversion is lossless
+    /// for every representable unit value.
+    fn into_float64(self) -> Self::Float64;
+    /// Converts from 64-bit floating point to this form.
+    fn from_float64(float: Self::Float64) -> Self;
 }
 
 impl FloatConversion for u32 {
     type Float = f32;
+    type Float64 = f64;
 
     #[allow(clippy::cast_precision_loss)] // precision loss desired to best approximate the value
     fn into_float(self) -> Self::Float {
@@ -33,10 +45,22 @@ impl FloatConversion for u32 {
         assert!(float.is_sign_positive());
         float as u32
     }
+
+    fn into_float64(self) -> Self::Float64 {
+        f64::from(self)
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // truncation desired
+    #[allow(clippy::cast_sign_loss)] // sign loss is asserted
+    fn from_float64(float: Self::Float64) -> Self {
+        assert!(float.is_sign_positive());
+        float as u32
+    }
 }
 
 impl FloatConversion for i32 {
     type Float = f32;
+    type Float64 = f64;
 
     #[allow(clippy::cast_precision_loss)] // precision loss desired to best approximate the value
     fn into_float(self) -> Self::Float {
@@ -48,6 +72,15 @@ impl FloatConversion for i32 {
     fn from_float(float: Self::Float) -> Self {
         float as i32
     }
+
+    fn into_float64(self) -> Self::Float64 {
+        f64::from(self)
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // truncation desired
+    fn from_float64(float: Self::Float64) -> Self {
+        float as i32
+    }
 }
 
 /// A type that can represent a zero-value.
@@ -84,6 +117,40 @@ impl_int_zero!(u64);
 impl_int_zero!(u128);
 impl_int_zero!(usize);
 
+/// A type that can represent a value of one.
+pub trait One {
+    /// The value equivalent to `1` for this type.
+    const ONE: Self;
+
+    /// Returns true if `self` represents `1`.
+    fn is_one(&self) -> bool;
+}
+
+macro_rules! impl_int_one {
+    ($type:ident) => {
+        impl One for $type {
+            const ONE: Self = 1;
+
+            fn is_one(&self) -> bool {
+                *self == 1
+            }
+        }
+    };
+}
+
+impl_int_one!(i8);
+impl_int_one!(i16);
+impl_int_one!(i32);
+impl_int_one!(i64);
+impl_int_one!(i128);
+impl_int_one!(isize);
+impl_int_one!(u8);
+impl_int_one!(u16);
+impl_int_one!(u32);
+impl_int_one!(u64);
+impl_int_one!(u128);
+impl_int_one!(usize);
+
 /// A type that can have its absolute difference from zero calculated.
 pub trait Abs {
     /// Returns the positive difference between this value and 0.
@@ -149,7 +216,7 @@ impl_int_pow!(usize);
 
 impl Pow for f32 {
     fn pow(&self, exp: u32) -> Self {
-        self.powf(exp.cast())
+        FloatExt::powf(*self, exp.cast())
     }
 }
 
@@ -308,6 +375,103 @@ where
     }
 }
 
+impl<Unit> FromComponents<Unit> for [Unit; 2] {
+    fn from_components(components: (Unit, Unit)) -> Self {
+        [components.0, components.1]
+    }
+}
+
+impl<Unit> IntoComponents<Unit> for [Unit; 2] {
+    fn into_components(self) -> (Unit, Unit) {
+        let [x, y] = self;
+        (x, y)
+    }
+}
+
+/// Converts from a 2d rectangle in tuple form.
+pub trait FromComponents4<Unit>: Sized {
+    /// Returns a new instance from the rectangle components provided in
+    /// `(x, y, width, height)` order.
+    fn from_components4(components: (Unit, Unit, Unit, Unit)) -> Self;
+
+    /// Converts this type to another type using [`FromComponents4`] and
+    /// [`IntoComponents4`].
+    fn from_vec4<Type>(other: Type) -> Self
+    where
+        Type: IntoComponents4<Unit>,
+    {
+        Self::from_components4(other.into_components4())
+    }
+}
+
+/// Converts to a 2d rectangle in tuple form.
+pub trait IntoComponents4<Unit>: Sized {
+    /// Extracts this type's rectangle components in `(x, y, width, height)`
+    /// order.
+    fn into_components4(self) -> (Unit, Unit, Unit, Unit);
+
+    /// Converts this type to another type using [`FromComponents4`] and
+    /// [`IntoComponents4`].
+    fn to_vec4<Type>(self) -> Type
+    where
+        Type: FromComponents4<Unit>,
+    {
+        Type::from_vec4(self)
+    }
+}
+
+impl<Unit> FromComponents4<Unit> for (Unit, Unit, Unit, Unit) {
+    fn from_components4(components: Self) -> Self {
+        components
+    }
+}
+
+impl<Unit> IntoComponents4<Unit> for (Unit, Unit, Unit, Unit) {
+    fn into_components4(self) -> Self {
+        self
+    }
+}
+
+impl<Unit> FromComponents4<Unit> for [Unit; 4] {
+    fn from_components4(components: (Unit, Unit, Unit, Unit)) -> Self {
+        [components.0, components.1, components.2, components.3]
+    }
+}
+
+impl<Unit> IntoComponents4<Unit> for [Unit; 4] {
+    fn into_components4(self) -> (Unit, Unit, Unit, Unit) {
+        let [x, y, width, height] = self;
+        (x, y, width, height)
+    }
+}
+
+/// A shape that can report the axis-aligned rectangle that fully contains it.
+///
+/// This trait unifies bounding-box queries across the crate's shape types,
+/// allowing generic culling and damage-tracking code to accept any shape.
+pub trait Bounded<Unit> {
+    /// Returns the axis-aligned bounding rectangle of this shape.
+    fn bounds(&self) -> crate::Rect<Unit>;
+}
+
+impl<Unit> Bounded<Unit> for crate::Rect<Unit>
+where
+    Unit: Copy,
+{
+    fn bounds(&self) -> crate::Rect<Unit> {
+        *self
+    }
+}
+
+impl<Unit> Bounded<Unit> for crate::Point<Unit>
+where
+    Unit: Copy + Zero,
+{
+    fn bounds(&self) -> crate::Rect<Unit> {
+        crate::Rect::new(*self, crate::Size::new(Unit::ZERO, Unit::ZERO))
+    }
+}
+
 /// Converts this type into its measurement in [`Px`](crate::units::Px) and [`Lp`](crate::units::Lp).
 pub trait ScreenScale {
     /// This type when measuring with [`Px`](crate::units::Px).
@@ -334,6 +498,149 @@ pub trait ScreenScale {
     fn into_lp(self, scale: Fraction) -> Self::Lp;
     /// Converts from [`Lp`](crate::units::Lp) into this type, using the provided `scale` factor.
     fn from_lp(lp: Self::Lp, scale: Fraction) -> Self;
+
+    /// Converts this value into device pixels ([`Px`](crate::units::Px))
+    /// using the combined factor of `scale`.
+    fn into_px_scaled(self, scale: Scaling) -> Self::Px
+    where
+        Self: Sized,
+    {
+        self.into_px(scale.effective())
+    }
+
+    /// Converts from pixels into this type, using the combined factor of
+    /// `scale`.
+    fn from_px_scaled(px: Self::Px, scale: Scaling) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_px(px, scale.effective())
+    }
+
+    /// Converts this value into device pixels ([`UPx`](crate::units::UPx))
+    /// using the combined factor of `scale`.
+    fn into_upx_scaled(self, scale: Scaling) -> Self::UPx
+    where
+        Self: Sized,
+    {
+        self.into_upx(scale.effective())
+    }
+
+    /// Converts from unsigned pixels into this type, using the combined
+    /// factor of `scale`.
+    fn from_upx_scaled(px: Self::UPx, scale: Scaling) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_upx(px, scale.effective())
+    }
+
+    /// Converts this value into device independent pixels
+    /// ([`Lp`](crate::units::Lp)) using the combined factor of `scale`.
+    fn into_lp_scaled(self, scale: Scaling) -> Self::Lp
+    where
+        Self: Sized,
+    {
+        self.into_lp(scale.effective())
+    }
+
+    /// Converts from [`Lp`](crate::units::Lp) into this type, using the
+    /// combined factor of `scale`.
+    fn from_lp_scaled(lp: Self::Lp, scale: Scaling) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_lp(lp, scale.effective())
+    }
+}
+
+macro_rules! impl_screen_scale_for_tuple {
+    ($($type:ident $field:tt),+) => {
+        impl<$($type),+> ScreenScale for ($($type,)+)
+        where
+            $($type: ScreenScale),+
+        {
+            type Px = ($($type::Px,)+);
+            type UPx = ($($type::UPx,)+);
+            type Lp = ($($type::Lp,)+);
+
+            fn into_px(self, scale: Fraction) -> Self::Px {
+                ($(self.$field.into_px(scale),)+)
+            }
+
+            fn from_px(px: Self::Px, scale: Fraction) -> Self {
+                ($($type::from_px(px.$field, scale),)+)
+            }
+
+            fn into_upx(self, scale: Fraction) -> Self::UPx {
+                ($(self.$field.into_upx(scale),)+)
+            }
+
+            fn from_upx(px: Self::UPx, scale: Fraction) -> Self {
+                ($($type::from_upx(px.$field, scale),)+)
+            }
+
+            fn into_lp(self, scale: Fraction) -> Self::Lp {
+                ($(self.$field.into_lp(scale),)+)
+            }
+
+            fn from_lp(lp: Self::Lp, scale: Fraction) -> Self {
+                ($($type::from_lp(lp.$field, scale),)+)
+            }
+        }
+    };
+}
+
+impl_screen_scale_for_tuple!(T0 0);
+impl_screen_scale_for_tuple!(T0 0, T1 1);
+impl_screen_scale_for_tuple!(T0 0, T1 1, T2 2);
+impl_screen_scale_for_tuple!(T0 0, T1 1, T2 2, T3 3);
+
+impl<T, const N: usize> ScreenScale for [T; N]
+where
+    T: ScreenScale,
+{
+    type Lp = [T::Lp; N];
+    type Px = [T::Px; N];
+    type UPx = [T::UPx; N];
+
+    fn into_px(self, scale: Fraction) -> Self::Px {
+        self.map(|value| value.into_px(scale))
+    }
+
+    fn from_px(px: Self::Px, scale: Fraction) -> Self {
+        px.map(|value| T::from_px(value, scale))
+    }
+
+    fn into_upx(self, scale: Fraction) -> Self::UPx {
+        self.map(|value| value.into_upx(scale))
+    }
+
+    fn from_upx(px: Self::UPx, scale: Fraction) -> Self {
+        px.map(|value| T::from_upx(value, scale))
+    }
+
+    fn into_lp(self, scale: Fraction) -> Self::Lp {
+        self.map(|value| value.into_lp(scale))
+    }
+
+    fn from_lp(lp: Self::Lp, scale: Fraction) -> Self {
+        lp.map(|value| T::from_lp(value, scale))
+    }
+}
+
+/// Converts a value into `Target`'s unit using a display scale factor.
+///
+/// This trait complements [`ScreenScale`]'s fixed set of conversions with a
+/// single generic entry point: `rect.convert::<Lp>(scale)` converts a
+/// rectangle into any unit implementing the conversion, including identity
+/// conversions. User-defined units can implement this trait to participate
+/// in the same generic code paths as the built-in units.
+pub trait ConvertUnit<Target> {
+    /// Returns this value converted into `Target`'s unit using the provided
+    /// `scale` factor.
+    #[must_use]
+    fn convert(self, scale: Fraction) -> Target;
 }
 
 /// Converts a value into its signed representation, clamping negative numbers
@@ -413,16 +720,60 @@ pub trait Unit:
     + RemAssign
     + Zero
     + Ord
+    + WideMul
     + Eq
     + Copy
     + Default
-    + std::fmt::Debug
+    + core::fmt::Debug
     + IntoSigned
     + TryInto<i32>
     + 'static
 {
 }
 
+/// Multiplication that widens its result to avoid overflow.
+pub trait WideMul {
+    /// The widened type produced by the multiplication.
+    type Wide: Ord;
+
+    /// Multiplies `self` and `other`, returning the result in a type wide
+    /// enough that the multiplication cannot overflow.
+    #[must_use]
+    fn wide_mul(self, other: Self) -> Self::Wide;
+}
+
+impl WideMul for i32 {
+    type Wide = i64;
+
+    fn wide_mul(self, other: Self) -> Self::Wide {
+        i64::from(self) * i64::from(other)
+    }
+}
+
+impl WideMul for u32 {
+    type Wide = u64;
+
+    fn wide_mul(self, other: Self) -> Self::Wide {
+        u64::from(self) * u64::from(other)
+    }
+}
+
+impl WideMul for i64 {
+    type Wide = i128;
+
+    fn wide_mul(self, other: Self) -> Self::Wide {
+        i128::from(self) * i128::from(other)
+    }
+}
+
+impl WideMul for u64 {
+    type Wide = u128;
+
+    fn wide_mul(self, other: Self) -> Self::Wide {
+        u128::from(self) * u128::from(other)
+    }
+}
+
 /// Common number operations available on number types in Rust that aren't
 /// available as traits.
 pub trait StdNumOps {
@@ -464,6 +815,52 @@ macro_rules! impl_std_num_ops {
 
 impl_std_num_ops!(u8);
 
+/// Math operations that return `None` instead of overflowing.
+pub trait CheckedNumOps: Sized {
+    /// Adds `self` and `other`, returning `None` if the calculation
+    /// overflows.
+    #[must_use]
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Subtracts `other` from `self`, returning `None` if the calculation
+    /// overflows.
+    #[must_use]
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Multiplies `self` and `other`, returning `None` if the calculation
+    /// overflows.
+    #[must_use]
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Divides `self` by `other`, returning `None` if `other` is zero or the
+    /// calculation overflows.
+    #[must_use]
+    fn checked_div(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_num_ops {
+    ($type:ident) => {
+        impl CheckedNumOps for $type {
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                self.checked_sub(other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.checked_mul(other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                self.checked_div(other)
+            }
+        }
+    };
+}
+
+impl_checked_num_ops!(u8);
+impl_checked_num_ops!(i32);
+impl_checked_num_ops!(u32);
+
 impl<T> Unit for T where
     T: FloatConversion<Float = f32>
         + Add<Output = Self>
@@ -478,10 +875,10 @@ impl<T> Unit for T where
         + RemAssign
         + Zero
         + Ord
-        + Eq
+        + WideMul
         + Copy
         + Default
-        + std::fmt::Debug
+        + core::fmt::Debug
         + IntoSigned
         + TryInto<i32>
         + 'static
@@ -569,6 +966,20 @@ pub trait UnscaledUnit {
     fn into_unscaled(self) -> Self::Representation;
 }
 
+/// Comparison within a tolerance.
+///
+/// Fixed-point conversions that take different paths can produce values whose
+/// internal representations differ by a subpixel step even though they
+/// describe the same logical measurement. This trait compares values while
+/// allowing for such differences.
+pub trait ApproxEq: Sized {
+    /// Returns true if `self` and `other` differ by at most `tolerance`.
+    ///
+    /// The sign of `tolerance` is ignored.
+    #[must_use]
+    fn approx_eq(self, other: Self, tolerance: Self) -> bool;
+}
+
 /// Functionality for rounding values to whole numbers.
 pub trait Round {
     /// Returns `self` rounded to the nearest whole number.
@@ -580,19 +991,43 @@ pub trait Round {
     /// Returns `self` lowered to the next whole number closer to 0.
     #[must_use]
     fn floor(self) -> Self;
+    /// Returns `self` rounded to the nearest multiple of `step`.
+    ///
+    /// This is useful for snapping positions to a grid.
+    #[must_use]
+    fn round_to_nearest(self, step: Self) -> Self;
+    /// Returns `self` raised to the next multiple of `step` further away
+    /// from 0.
+    #[must_use]
+    fn ceil_to(self, step: Self) -> Self;
+    /// Returns `self` lowered to the next multiple of `step` closer to 0.
+    #[must_use]
+    fn floor_to(self, step: Self) -> Self;
 }
 
 impl Round for f32 {
     fn round(self) -> Self {
-        self.round()
+        FloatExt::round(self)
     }
 
     fn ceil(self) -> Self {
-        self.ceil()
+        FloatExt::ceil(self)
+    }
+
+    fn round_to_nearest(self, step: Self) -> Self {
+        FloatExt::round(self / step) * step
+    }
+
+    fn ceil_to(self, step: Self) -> Self {
+        FloatExt::ceil(self / step) * step
+    }
+
+    fn floor_to(self, step: Self) -> Self {
+        FloatExt::floor(self / step) * step
     }
 
     fn floor(self) -> Self {
-        self.floor()
+        FloatExt::floor(self)
     }
 }
 
@@ -609,10 +1044,10 @@ pub trait Roots {
 
 impl Roots for f32 {
     fn sqrt(self) -> Self {
-        self.sqrt()
+        FloatExt::sqrt(self)
     }
 
     fn cbrt(self) -> Self {
-        self.cbrt()
+        FloatExt::cbrt(self)
     }
 }
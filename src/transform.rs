@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+
+use crate::units::Px;
+use crate::{Fraction, Point, Rect, Size, Zero};
+
+/// A stack of composed translation and scale transforms.
+///
+/// Nested scroll areas and zoomable surfaces need to map points between a
+/// local coordinate space and the device coordinate space. Each pushed entry
+/// composes its translation and scale with the entries below it, keeping the
+/// math in this crate's exact [`Fraction`]-based arithmetic instead of
+/// accumulating floating point drift.
+///
+/// An empty stack is the identity transform.
+#[derive(Default, Clone, Eq, PartialEq, Debug)]
+pub struct TransformStack {
+    entries: Vec<Entry>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+struct Entry {
+    translation: Point<Px>,
+    scale: Fraction,
+    clip: Option<Rect<Px>>,
+}
+
+impl TransformStack {
+    /// Returns an empty stack, representing the identity transform.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes a transform that translates by `translation` and scales by
+    /// `scale`, composed with the current transform.
+    pub fn push(&mut self, translation: Point<Px>, scale: Fraction) {
+        let entry = Entry {
+            translation: self.apply(translation),
+            scale: self.scale() * scale,
+            clip: self.current_clip(),
+        };
+        self.entries.push(entry);
+    }
+
+    /// Pushes a clip region, expressed in the current local coordinate space.
+    ///
+    /// The clip is intersected with any previously pushed clips. The
+    /// translation and scale are unchanged.
+    pub fn push_clip(&mut self, clip: Rect<Px>) {
+        let clip = self.apply_rect(clip);
+        let entry = Entry {
+            translation: self.translation(),
+            scale: self.scale(),
+            clip: match self.current_clip() {
+                Some(current) => Some(
+                    current
+                        .intersection(&clip)
+                        .unwrap_or(Rect::new(clip.origin, Size::default())),
+                ),
+                None => Some(clip),
+            },
+        };
+        self.entries.push(entry);
+    }
+
+    /// Pops the most recently pushed entry.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if called without a matching
+    /// [`push`](Self::push)/[`push_clip`](Self::push_clip).
+    pub fn pop(&mut self) {
+        self.entries
+            .pop()
+            .expect("pop() called without a matching push()");
+    }
+
+    /// Maps `point` from the current local coordinate space to the device
+    /// coordinate space.
+    #[must_use]
+    pub fn apply(&self, point: Point<Px>) -> Point<Px> {
+        let scale = self.scale();
+        let translation = self.translation();
+        Point::new(
+            point.x * scale + translation.x,
+            point.y * scale + translation.y,
+        )
+    }
+
+    /// Maps `point` from the device coordinate space back to the current
+    /// local coordinate space.
+    ///
+    /// This is the inverse of [`apply`](Self::apply), up to the rounding of
+    /// the fixed-point [`Px`] representation.
+    #[must_use]
+    pub fn unapply(&self, point: Point<Px>) -> Point<Px> {
+        let scale = self.scale();
+        let translation = self.translation();
+        Point::new(
+            (point.x - translation.x) / scale,
+            (point.y - translation.y) / scale,
+        )
+    }
+
+    /// Maps `rect` from the current local coordinate space to the device
+    /// coordinate space.
+    #[must_use]
+    pub fn apply_rect(&self, rect: Rect<Px>) -> Rect<Px> {
+        let scale = self.scale();
+        Rect::new(
+            self.apply(rect.origin),
+            Size::new(rect.size.width * scale, rect.size.height * scale),
+        )
+    }
+
+    /// Returns the composed clip region in device coordinates, or None if no
+    /// clip has been pushed.
+    #[must_use]
+    pub fn current_clip(&self) -> Option<Rect<Px>> {
+        self.entries.last().and_then(|entry| entry.clip)
+    }
+
+    /// Returns the composed translation of the stack.
+    #[must_use]
+    pub fn translation(&self) -> Point<Px> {
+        self.entries
+            .last()
+            .map_or(Point::ZERO, |entry| entry.translation)
+    }
+
+    /// Returns the composed scale of the stack.
+    #[must_use]
+    pub fn scale(&self) -> Fraction {
+        self.entries
+            .last()
+            .map_or(Fraction::ONE, |entry| entry.scale)
+    }
+}
+
+#[test]
+fn composed_apply_unapply() {
+    let mut stack = TransformStack::new();
+    stack.push(Point::new(Px::new(10), Px::new(20)), Fraction::new(2, 1));
+    stack.push(Point::new(Px::new(5), Px::new(5)), Fraction::new(1, 2));
+
+    // The inner translation is scaled by the outer transform.
+    assert_eq!(stack.translation(), Point::new(Px::new(20), Px::new(30)));
+    assert_eq!(stack.scale(), Fraction::ONE);
+
+    let local = Point::new(Px::new(7), Px::new(3));
+    let device = stack.apply(local);
+    assert_eq!(device, Point::new(Px::new(27), Px::new(33)));
+    assert_eq!(stack.unapply(device), local);
+
+    stack.pop();
+    stack.pop();
+    assert_eq!(stack.apply(local), local);
+}
+
+#[test]
+fn clip_composition() {
+    let mut stack = TransformStack::new();
+    assert_eq!(stack.current_clip(), None);
+    stack.push_clip(Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(100), Px::new(100)),
+    ));
+    stack.push(Point::new(Px::new(50), Px::new(50)), Fraction::ONE);
+    stack.push_clip(Rect::new(
+        Point::new(Px::new(0), Px::new(0)),
+        Size::new(Px::new(100), Px::new(100)),
+    ));
+    assert_eq!(
+        stack.current_clip(),
+        Some(Rect::new(
+            Point::new(Px::new(50), Px::new(50)),
+            Size::new(Px::new(50), Px::new(50)),
+        ))
+    );
+}
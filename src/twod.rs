@@ -1,14 +1,28 @@
 macro_rules! impl_2d_math {
     ($type:ident, $x:ident, $y:ident) => {
         mod twodmath {
-            use std::ops::Neg;
+            use core::ops::Neg;
 
             use super::$type;
             use crate::traits::{
-                FloatConversion, FromComponents, IntoComponents, IntoSigned, IntoUnsigned, Ranged,
-                Round, ScreenScale, Zero, Abs, Pow,
+                Abs, ApproxEq, FloatConversion, FromComponents, IntoComponents, IntoSigned,
+                IntoUnsigned, One, Pow, Ranged, Round, ScreenScale, Zero,
             };
             use crate::units::{Lp, Px, UPx};
+            use crate::Fraction;
+
+            impl<Unit> $type<Unit>
+            where
+                Unit: ApproxEq + Copy,
+            {
+                /// Returns true if each component of `self` and `other`
+                /// differs by at most `tolerance`.
+                #[must_use]
+                pub fn approx_eq_within(self, other: Self, tolerance: Unit) -> bool {
+                    self.$x.approx_eq(other.$x, tolerance)
+                        && self.$y.approx_eq(other.$y, tolerance)
+                }
+            }
 
             impl<Unit> Zero for $type<Unit>
             where
@@ -21,6 +35,17 @@ macro_rules! impl_2d_math {
                 }
             }
 
+            impl<Unit> One for $type<Unit>
+            where
+                Unit: One,
+            {
+                const ONE: Self = Self::new(Unit::ONE, Unit::ONE);
+
+                fn is_one(&self) -> bool {
+                    self.$x.is_one() && self.$y.is_one()
+                }
+            }
+
             impl<Unit> Pow for $type<Unit>
             where
                 Unit: Pow,
@@ -93,6 +118,27 @@ macro_rules! impl_2d_math {
                 fn floor(self) -> Self {
                     self.map(Unit::floor)
                 }
+
+                fn round_to_nearest(self, step: Self) -> Self {
+                    Self {
+                        $x: self.$x.round_to_nearest(step.$x),
+                        $y: self.$y.round_to_nearest(step.$y),
+                    }
+                }
+
+                fn ceil_to(self, step: Self) -> Self {
+                    Self {
+                        $x: self.$x.ceil_to(step.$x),
+                        $y: self.$y.ceil_to(step.$y),
+                    }
+                }
+
+                fn floor_to(self, step: Self) -> Self {
+                    Self {
+                        $x: self.$x.floor_to(step.$x),
+                        $y: self.$y.floor_to(step.$y),
+                    }
+                }
             }
 
             impl<Unit> ScreenScale for $type<Unit>
@@ -146,11 +192,60 @@ macro_rules! impl_2d_math {
                 }
             }
 
+            impl<Unit, NewUnit> crate::traits::ConvertUnit<$type<NewUnit>> for $type<Unit>
+            where
+                Unit: crate::traits::ConvertUnit<NewUnit>,
+            {
+                fn convert(self, scale: crate::Fraction) -> $type<NewUnit> {
+                    $type {
+                        $x: self.$x.convert(scale),
+                        $y: self.$y.convert(scale),
+                    }
+                }
+            }
+
+            impl<Unit> $type<Unit> {
+                /// Returns the component along `axis`.
+                #[must_use]
+                pub fn get(self, axis: crate::Axis) -> Unit {
+                    match axis {
+                        crate::Axis::X => self.$x,
+                        crate::Axis::Y => self.$y,
+                    }
+                }
+
+                /// Sets the component along `axis` to `value`.
+                pub fn set(&mut self, axis: crate::Axis, value: Unit) {
+                    match axis {
+                        crate::Axis::X => self.$x = value,
+                        crate::Axis::Y => self.$y = value,
+                    }
+                }
+
+                /// Returns this value converted into `NewUnit` using the
+                /// provided `scale` factor.
+                ///
+                /// This is a generic entry point to the conversions offered
+                /// by [`ScreenScale`], via
+                /// [`ConvertUnit`](crate::traits::ConvertUnit).
+                #[must_use]
+                pub fn convert<NewUnit>(self, scale: crate::Fraction) -> $type<NewUnit>
+                where
+                    Unit: crate::traits::ConvertUnit<NewUnit>,
+                {
+                    $type {
+                        $x: self.$x.convert(scale),
+                        $y: self.$y.convert(scale),
+                    }
+                }
+            }
+
             impl<T> FloatConversion for $type<T>
             where
                 T: FloatConversion,
             {
                 type Float = $type<T::Float>;
+                type Float64 = $type<T::Float64>;
 
                 fn into_float(self) -> Self::Float {
                     $type {
@@ -165,6 +260,55 @@ macro_rules! impl_2d_math {
                         $y: T::from_float(float.$y),
                     }
                 }
+
+                fn into_float64(self) -> Self::Float64 {
+                    $type {
+                        $x: self.$x.into_float64(),
+                        $y: self.$y.into_float64(),
+                    }
+                }
+
+                fn from_float64(float: Self::Float64) -> Self {
+                    $type {
+                        $x: T::from_float64(float.$x),
+                        $y: T::from_float64(float.$y),
+                    }
+                }
+            }
+
+            impl<Unit> core::ops::Index<usize> for $type<Unit> {
+                type Output = Unit;
+
+                fn index(&self, index: usize) -> &Unit {
+                    match index {
+                        0 => &self.$x,
+                        1 => &self.$y,
+                        _ => panic!("2d types have two components, but the index is {index}"),
+                    }
+                }
+            }
+
+            impl<Unit> core::ops::IndexMut<usize> for $type<Unit> {
+                fn index_mut(&mut self, index: usize) -> &mut Unit {
+                    match index {
+                        0 => &mut self.$x,
+                        1 => &mut self.$y,
+                        _ => panic!("2d types have two components, but the index is {index}"),
+                    }
+                }
+            }
+
+            impl<Unit> From<[Unit; 2]> for $type<Unit> {
+                fn from(components: [Unit; 2]) -> Self {
+                    let [$x, $y] = components;
+                    Self { $x, $y }
+                }
+            }
+
+            impl<Unit> From<$type<Unit>> for [Unit; 2] {
+                fn from(value: $type<Unit>) -> Self {
+                    [value.$x, value.$y]
+                }
             }
 
             impl<Unit> IntoComponents<Unit> for $type<Unit> {
@@ -225,7 +369,7 @@ macro_rules! impl_2d_math {
         }
     };
     (binary, $trait:ident, $method:ident, $type:ident, $x:ident, $y:ident) => {
-        use std::ops::$trait;
+        use core::ops::$trait;
 
         impl_2d_math!(binary i32, $trait, $method, $type, $x, $y);
         impl_2d_math!(binary f32, $trait, $method, $type, $x, $y);
@@ -233,6 +377,7 @@ macro_rules! impl_2d_math {
         impl_2d_math!(binary UPx, $trait, $method, $type, $x, $y);
         impl_2d_math!(binary Px, $trait, $method, $type, $x, $y);
         impl_2d_math!(binary Lp, $trait, $method, $type, $x, $y);
+        impl_2d_math!(binary Fraction, $trait, $method, $type, $x, $y);
 
         impl<T, Unit> $trait<crate::Point<T>> for $type<Unit>
         where
@@ -264,7 +409,7 @@ macro_rules! impl_2d_math {
     };
 
     (assign, $trait:ident, $method:ident, $type:ident, $x:ident, $y:ident) => {
-        use std::ops::$trait;
+        use core::ops::$trait;
 
         impl<Unit> $trait<Unit> for $type<Unit>
         where
@@ -297,3 +442,34 @@ macro_rules! impl_2d_math {
         }
     };
 }
+
+/// Implements `const` component-wise math for a 2d type with a concrete unit.
+///
+/// Trait-based operators cannot be called in `const` contexts on stable Rust,
+/// so these inherent methods mirror the `+` and `-` operators for each unit
+/// type, allowing static geometry tables to be built at compile time.
+macro_rules! impl_2d_const_math {
+    ($type:ident, $x:ident, $y:ident, $unit:ident) => {
+        impl $type<crate::units::$unit> {
+            /// Returns the result of adding each component of `other` to
+            /// `self`.
+            ///
+            /// Unlike the `+` operator, this function can be used in `const`
+            /// contexts.
+            #[must_use]
+            pub const fn const_add(self, other: Self) -> Self {
+                Self::new(self.$x.const_add(other.$x), self.$y.const_add(other.$y))
+            }
+
+            /// Returns the result of subtracting each component of `other`
+            /// from `self`.
+            ///
+            /// Unlike the `-` operator, this function can be used in `const`
+            /// contexts.
+            #[must_use]
+            pub const fn const_sub(self, other: Self) -> Self {
+                Self::new(self.$x.const_sub(other.$x), self.$y.const_sub(other.$y))
+            }
+        }
+    };
+}
@@ -1,15 +1,16 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::num::TryFromIntError;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use core::cmp::Ordering;
+use core::fmt;
+use core::num::TryFromIntError;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
 use intentional::{Cast, CastFrom};
 
 use crate::traits::{
-    Abs, FloatConversion, IntoComponents, IntoSigned, IntoUnsigned, Pow, Roots, Round, ScreenScale,
-    StdNumOps, UnscaledUnit, Zero,
+    Abs, CheckedNumOps, FloatConversion, IntoComponents, IntoSigned, IntoUnsigned, Pow, Roots,
+    Round, ScreenScale, StdNumOps, UnscaledUnit, Zero,
 };
-use crate::Fraction;
+use crate::utils::FloatExt;
+use crate::{Fraction, Fraction64};
 
 pub(crate) const ARBITRARY_SCALE: u16 = 1905;
 const ARBITRARY_SCALE_I32: i32 = ARBITRARY_SCALE as i32;
@@ -21,7 +22,6 @@ macro_rules! define_integer_type {
     ($name:ident, $inner:ty, $docs_file:literal, $scale:literal) => {
         #[derive(Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
         #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
-        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         #[doc = include_str!($docs_file)]
         #[repr(C)]
         pub struct $name($inner);
@@ -38,6 +38,33 @@ macro_rules! define_integer_type {
                 Self(value * $scale)
             }
 
+            /// Returns a new wrapped value for this unit, or `None` if
+            /// `value` cannot be represented.
+            #[must_use]
+            pub const fn checked_new(value: $inner) -> Option<Self> {
+                match value.checked_mul($scale) {
+                    Some(scaled) => Some(Self(scaled)),
+                    None => None,
+                }
+            }
+
+            /// Returns a new wrapped value for this unit from a floating
+            /// point number, rounded to the nearest representable value.
+            ///
+            /// Unlike [`FloatConversion::from_float`], this function can be
+            /// used in `const` contexts.
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // the cast saturates at the representable range
+            pub const fn from_float_const(value: f64) -> Self {
+                let scaled = value * $scale as f64;
+                let rounded = if scaled >= 0.0 {
+                    scaled + 0.5
+                } else {
+                    scaled - 0.5
+                };
+                Self(rounded as $inner)
+            }
+
             /// Returns the contained value, rounded if applicable.
             #[must_use]
             pub const fn get(self) -> $inner {
@@ -48,6 +75,26 @@ macro_rules! define_integer_type {
                 }
             }
 
+            /// Returns the result of adding `self` and `other`.
+            ///
+            /// Unlike the `+` operator, this function can be used in `const`
+            /// contexts. Overflow behaves the same as the underlying integer
+            /// addition.
+            #[must_use]
+            pub const fn const_add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+
+            /// Returns the result of subtracting `other` from `self`.
+            ///
+            /// Unlike the `-` operator, this function can be used in `const`
+            /// contexts. Overflow behaves the same as the underlying integer
+            /// subtraction.
+            #[must_use]
+            pub const fn const_sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+
             /// Returns the result of subtracting `other` from `self`. If the
             /// calculation overflows, the value will be limited to
             /// [`Self::MIN`]/[`Self::MAX`].
@@ -79,10 +126,84 @@ macro_rules! define_integer_type {
             pub const fn saturating_div(self, other: Self) -> Self {
                 Self::new(self.0.saturating_div(other.0))
             }
+
+            /// Returns the result of adding `self` and `other`, or `None` if
+            /// the calculation overflows.
+            #[must_use]
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                match self.0.checked_add(other.0) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Returns the result of subtracting `other` from `self`, or
+            /// `None` if the calculation overflows.
+            #[must_use]
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                match self.0.checked_sub(other.0) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Returns the result of multiplying `self` and `other`, or
+            /// `None` if the calculation overflows.
+            #[must_use]
+            pub const fn checked_mul(self, other: Self) -> Option<Self> {
+                match self.0.checked_mul(other.0) {
+                    Some(value) => Some(Self(value / $scale)),
+                    None => None,
+                }
+            }
+
+            /// Returns the result of dividing `self` by `other`, or `None`
+            /// if `other` is zero or the calculation overflows.
+            #[must_use]
+            pub const fn checked_div(self, other: Self) -> Option<Self> {
+                match self.0.checked_div(other.0) {
+                    Some(value) => match value.checked_mul($scale) {
+                        Some(scaled) => Some(Self(scaled)),
+                        None => None,
+                    },
+                    None => None,
+                }
+            }
+        }
+
+        impl core::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self(0), Add::add)
+            }
+        }
+
+        impl<'a> core::iter::Sum<&'a $name> for $name {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl core::iter::Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::new(1), Mul::mul)
+            }
+        }
+
+        impl<'a> core::iter::Product<&'a $name> for $name {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+
+        impl crate::traits::ApproxEq for $name {
+            fn approx_eq(self, other: Self, tolerance: Self) -> bool {
+                self.0.abs_diff(other.0) <= tolerance.0.abs_diff(0)
+            }
         }
 
         impl FloatConversion for $name {
             type Float = f32;
+            type Float64 = f64;
 
             #[allow(clippy::cast_precision_loss)] // precision loss desired to best approximate the value
             fn into_float(self) -> Self::Float {
@@ -90,7 +211,62 @@ macro_rules! define_integer_type {
             }
 
             fn from_float(float: Self::Float) -> Self {
-                Self((float * $scale.cast::<f32>()).round().cast())
+                Self(FloatExt::round(float * $scale.cast::<f32>()).cast())
+            }
+
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)] // casts are used so the macro supports 32-bit and 64-bit inners
+            fn into_float64(self) -> Self::Float64 {
+                self.0 as f64 / f64::from($scale)
+            }
+
+            fn from_float64(float: Self::Float64) -> Self {
+                Self(FloatExt::round(float * f64::from($scale)).cast())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            /// Serializes the logical value of this unit as a float for
+            /// human-readable formats, and the raw scaled integer otherwise.
+            ///
+            /// The [`crate::serde::compact`] module can be used to always
+            /// serialize the raw form.
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                if serializer.is_human_readable() {
+                    serializer.serialize_f32(self.into_float())
+                } else {
+                    serde::Serialize::serialize(&self.0, serializer)
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                if deserializer.is_human_readable() {
+                    <f32 as serde::Deserialize>::deserialize(deserializer).map(Self::from_float)
+                } else {
+                    <$inner as serde::Deserialize>::deserialize(deserializer).map(Self)
+                }
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $name {
+            /// Describes this unit as a number, matching the human-readable
+            /// serialization format.
+            fn schema_name() -> String {
+                String::from(stringify!($name))
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                <f32 as schemars::JsonSchema>::json_schema(gen)
             }
         }
 
@@ -125,7 +301,7 @@ macro_rules! define_integer_type {
         }
 
         impl PartialOrd<$inner> for $name {
-            fn partial_cmp(&self, other: &$inner) -> Option<std::cmp::Ordering> {
+            fn partial_cmp(&self, other: &$inner) -> Option<core::cmp::Ordering> {
                 self.partial_cmp(&Self::new(*other))
             }
         }
@@ -150,7 +326,7 @@ macro_rules! define_integer_type {
             type Output = Self;
 
             fn div(self, rhs: f32) -> Self::Output {
-                Self::from((self.into_float() / rhs))
+                Self::from(self.into_float() / rhs)
             }
         }
 
@@ -158,7 +334,7 @@ macro_rules! define_integer_type {
             type Output = Self;
 
             fn div(self, rhs: Fraction) -> Self::Output {
-                Self::from_unscaled((self.into_unscaled() / rhs))
+                Self::from_unscaled(self.into_unscaled() / rhs)
             }
         }
 
@@ -206,7 +382,7 @@ macro_rules! define_integer_type {
             type Output = Self;
 
             fn rem(self, rhs: f32) -> Self::Output {
-                Self::from((self.into_float() % rhs).round())
+                Self::from(FloatExt::round(self.into_float() % rhs))
             }
         }
 
@@ -218,6 +394,24 @@ macro_rules! define_integer_type {
             }
         }
 
+        impl crate::traits::One for $name {
+            const ONE: Self = Self($scale);
+
+            fn is_one(&self) -> bool {
+                self.0 == $scale
+            }
+        }
+
+        impl crate::traits::WideMul for $name {
+            type Wide = <$inner as crate::traits::WideMul>::Wide;
+
+            fn wide_mul(self, other: Self) -> Self::Wide {
+                let scale: $inner = $scale;
+                crate::traits::WideMul::wide_mul(self.0, other.0)
+                    / <<$inner as crate::traits::WideMul>::Wide>::from(scale)
+            }
+        }
+
         impl Mul<$inner> for $name {
             type Output = Self;
 
@@ -230,7 +424,7 @@ macro_rules! define_integer_type {
             type Output = Self;
 
             fn mul(self, rhs: f32) -> Self::Output {
-                Self::from((self.into_float() * rhs))
+                Self::from(self.into_float() * rhs)
             }
         }
 
@@ -238,7 +432,7 @@ macro_rules! define_integer_type {
             type Output = Self;
 
             fn mul(self, rhs: Fraction) -> Self::Output {
-                Self::from_unscaled((self.into_unscaled() * rhs))
+                Self::from_unscaled(self.into_unscaled() * rhs)
             }
         }
 
@@ -342,15 +536,47 @@ macro_rules! define_integer_type {
             fn floor(self) -> Self {
                 Self(self.0 / $scale * $scale)
             }
+
+            fn round_to_nearest(self, step: Self) -> Self {
+                Self((self.0 + step.0 / 2) / step.0 * step.0)
+            }
+
+            fn ceil_to(self, step: Self) -> Self {
+                Self((self.0 + step.0 - 1) / step.0 * step.0)
+            }
+
+            fn floor_to(self, step: Self) -> Self {
+                Self(self.0 / step.0 * step.0)
+            }
         }
 
         impl Roots for $name {
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)] // casts are used so the macro supports 32-bit and 64-bit inners
             fn sqrt(self) -> Self {
-                Self(f64::from(self.0).sqrt().cast())
+                Self((self.0 as f64).sqrt().cast())
             }
 
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)] // casts are used so the macro supports 32-bit and 64-bit inners
             fn cbrt(self) -> Self {
-                Self(f64::from(self.0).cbrt().cast())
+                Self((self.0 as f64).cbrt().cast())
+            }
+        }
+
+        impl CheckedNumOps for $name {
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                self.checked_sub(other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.checked_mul(other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                self.checked_div(other)
             }
         }
 
@@ -431,7 +657,7 @@ impl ScreenScale for Lp {
     }
 }
 
-impl std::ops::Neg for Lp {
+impl core::ops::Neg for Lp {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -448,6 +674,23 @@ impl TryFrom<u32> for Lp {
 }
 
 impl Lp {
+    /// Returns a value equal to `whole` plus `hundredths` hundredths, rounded
+    /// to the nearest representable value.
+    ///
+    /// Unlike [`FloatConversion::from_float`], the math is performed entirely
+    /// with integers, so the result is deterministic and never subject to
+    /// floating point rounding. The fractional portion is applied in the same
+    /// direction as `whole`'s sign.
+    #[must_use]
+    pub const fn from_decimal(whole: i32, hundredths: u8) -> Self {
+        let fraction = (ARBITRARY_SCALE_I32 * hundredths as i32 + 50) / 100;
+        if whole < 0 {
+            Self(whole * ARBITRARY_SCALE_I32 - fraction)
+        } else {
+            Self(whole * ARBITRARY_SCALE_I32 + fraction)
+        }
+    }
+
     /// Returns a value equivalent to the number of `points` provided. One
     /// [point](https://en.wikipedia.org/wiki/Point_(typography)) is 1/72 of an
     /// inch.
@@ -499,6 +742,111 @@ impl Lp {
     pub fn inches_f(inches: f32) -> Self {
         Self((inches * ARBITRARY_SCALE_F32 * 96.).cast())
     }
+
+    /// Returns a value equivalent to the number of `picas` provided. One
+    /// [pica](https://en.wikipedia.org/wiki/Pica_(typography)) is 1/6 of an
+    /// inch.
+    #[must_use]
+    pub const fn picas(picas: i32) -> Self {
+        Self(picas * ARBITRARY_SCALE_I32 * 16)
+    }
+
+    /// Returns a value equivalent to the number of `picas` provided. One
+    /// [pica](https://en.wikipedia.org/wiki/Pica_(typography)) is 1/6 of an
+    /// inch.
+    #[must_use]
+    pub fn picas_f(picas: f32) -> Self {
+        Self((picas * ARBITRARY_SCALE_F32 * 16.).cast())
+    }
+
+    /// Returns a value equivalent to the number of CSS pixels provided. One
+    /// CSS pixel is 1/96 of an inch, the same size as one [`Lp`].
+    #[must_use]
+    pub const fn css_px(pixels: i32) -> Self {
+        Self(pixels * ARBITRARY_SCALE_I32)
+    }
+
+    /// Returns a value equivalent to the number of CSS pixels provided. One
+    /// CSS pixel is 1/96 of an inch, the same size as one [`Lp`].
+    #[must_use]
+    pub fn css_px_f(pixels: f32) -> Self {
+        Self((pixels * ARBITRARY_SCALE_F32).cast())
+    }
+
+    /// Returns a value equivalent to the number of `twips` provided. One
+    /// [twip](https://en.wikipedia.org/wiki/Twip) is 1/20 of a point, or
+    /// 1/1440 of an inch.
+    #[must_use]
+    pub const fn twips(twips: i32) -> Self {
+        Self(twips * ARBITRARY_SCALE_I32 / 15)
+    }
+
+    /// Returns a value equivalent to the number of `twips` provided. One
+    /// [twip](https://en.wikipedia.org/wiki/Twip) is 1/20 of a point, or
+    /// 1/1440 of an inch.
+    #[must_use]
+    pub fn twips_f(twips: f32) -> Self {
+        Self((twips * ARBITRARY_SCALE_F32 / 15.).cast())
+    }
+
+    /// Returns this measurement in inches.
+    #[must_use]
+    pub fn into_inches_f(self) -> f32 {
+        self.0.cast::<f32>() / (ARBITRARY_SCALE_F32 * 96.)
+    }
+
+    /// Returns this measurement in centimeters.
+    #[must_use]
+    pub fn into_cm_f(self) -> f32 {
+        self.into_inches_f() * 2.54
+    }
+
+    /// Returns this measurement in millimeters.
+    #[must_use]
+    pub fn into_mm_f(self) -> f32 {
+        self.into_inches_f() * 25.4
+    }
+
+    /// Returns this measurement in typographic points (1/72 of an inch).
+    #[must_use]
+    pub fn into_points_f(self) -> f32 {
+        self.into_inches_f() * 72.
+    }
+
+    /// Returns this measurement in picas (1/6 of an inch).
+    #[must_use]
+    pub fn into_picas_f(self) -> f32 {
+        self.into_inches_f() * 6.
+    }
+
+    /// Returns this measurement in CSS pixels (1/96 of an inch).
+    #[must_use]
+    pub fn into_css_px_f(self) -> f32 {
+        self.0.cast::<f32>() / ARBITRARY_SCALE_F32
+    }
+
+    /// Returns this measurement in twips (1/1440 of an inch).
+    #[must_use]
+    pub fn into_twips_f(self) -> f32 {
+        self.into_inches_f() * 1440.
+    }
+
+    /// Converts this value into device pixels using the provided `scale`
+    /// factor, returning the converted value and the rounding error introduced
+    /// by the conversion.
+    ///
+    /// The error is reported as a non-negative [`Px`] value, rounded to the
+    /// nearest representable subpixel amount. This allows pixel-perfect tests
+    /// to assert that a conversion stayed within a tolerance such as half of a
+    /// device pixel.
+    #[must_use]
+    pub fn into_px_with_error(self, scale: Fraction) -> (Px, Px) {
+        let converted = self.into_px(scale);
+        let exact = f64::from(self.0) * f64::from(scale.numerator()) * 4.
+            / (f64::from(ARBITRARY_SCALE_I32) * f64::from(scale.denominator()));
+        let error = (f64::from(converted.into_unscaled()) - exact).abs() / 4.;
+        (converted, Px::from_float(error.cast()))
+    }
 }
 
 impl Pow for Lp {
@@ -543,6 +891,77 @@ impl fmt::Display for Lp {
 
 define_integer_type!(Px, i32, "docs/px.md", 4);
 
+impl Px {
+    /// Returns a value equal to `whole` plus `hundredths` hundredths of a
+    /// pixel, rounded to the nearest representable value.
+    ///
+    /// Unlike [`FloatConversion::from_float`], the math is performed entirely
+    /// with integers, so the result is deterministic and never subject to
+    /// floating point rounding. The fractional portion is applied in the same
+    /// direction as `whole`'s sign.
+    ///
+    /// ```rust
+    /// use figures::units::Px;
+    ///
+    /// assert_eq!(
+    ///     Px::from_decimal(1, 25),
+    ///     Px::new(1) + Px::from_decimal(0, 25)
+    /// );
+    /// assert_eq!(Px::from_decimal(-1, 50), -Px::from_decimal(1, 50));
+    /// ```
+    #[must_use]
+    pub const fn from_decimal(whole: i32, hundredths: u8) -> Self {
+        let fraction = (4 * hundredths as i32 + 50) / 100;
+        if whole < 0 {
+            Self(whole * 4 - fraction)
+        } else {
+            Self(whole * 4 + fraction)
+        }
+    }
+
+    /// Returns this value as an exact fraction of device pixels.
+    ///
+    /// [`Px`] is stored in quarters of a pixel, so the result always has a
+    /// denominator of at most 4.
+    #[must_use]
+    pub fn into_fraction(self) -> Fraction64 {
+        Fraction64::new(self.0, 4)
+    }
+
+    /// Returns the number of whole device pixels in this value, truncating
+    /// towards zero.
+    #[must_use]
+    pub const fn whole(self) -> i32 {
+        self.0 / 4
+    }
+
+    /// Returns the fractional component of this value as a fraction of a
+    /// device pixel.
+    ///
+    /// For negative values, the result is negative, such that
+    /// [`whole()`](Self::whole) plus `fract()` equals the original value.
+    #[must_use]
+    pub fn fract(self) -> Fraction {
+        Fraction::new((self.0 % 4).cast(), 4)
+    }
+
+    /// Returns the ordering between `self` and `other`.
+    ///
+    /// The comparison is widened so that it is exact: negative values order
+    /// before every unsigned value.
+    #[must_use]
+    pub fn cmp_unsigned(self, other: UPx) -> Ordering {
+        i64::from(self.0).cmp(&i64::from(other.0))
+    }
+
+    /// Returns this value converted to unsigned pixels, saturating negative
+    /// values to zero.
+    #[must_use]
+    pub fn saturating_into_unsigned(self) -> UPx {
+        self.into_unsigned()
+    }
+}
+
 impl Pow for Px {
     fn pow(&self, exp: u32) -> Self {
         Self(self.0.saturating_pow(exp) / 4_i32.pow(exp.saturating_sub(1)))
@@ -619,10 +1038,10 @@ impl fmt::Debug for Px {
         let whole = self.0 >> 2;
         let remainder = self.0 & 0b11;
         match remainder {
-            1 => write!(f, "{whole}.25px",),
-            2 => write!(f, "{whole}.5px",),
-            3 => write!(f, "{whole}.75px",),
-            _ => write!(f, "{whole}px",),
+            1 => write!(f, "{whole}.25px"),
+            2 => write!(f, "{whole}.5px"),
+            3 => write!(f, "{whole}.75px"),
+            _ => write!(f, "{whole}px"),
         }
     }
 }
@@ -633,7 +1052,7 @@ impl fmt::Display for Px {
     }
 }
 
-impl std::ops::Neg for Px {
+impl core::ops::Neg for Px {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -661,11 +1080,7 @@ impl PartialEq<UPx> for Px {
 
 impl PartialOrd<UPx> for Px {
     fn partial_cmp(&self, other: &UPx) -> Option<Ordering> {
-        if let Ok(unsigned) = UPx::try_from(*self) {
-            Some(unsigned.cmp(other))
-        } else {
-            Some(Ordering::Less)
-        }
+        Some(self.cmp_unsigned(*other))
     }
 }
 
@@ -693,6 +1108,42 @@ impl IntoUnsigned for UPx {
     }
 }
 
+impl UPx {
+    /// Returns the ordering between `self` and `other`.
+    ///
+    /// The comparison is widened so that it is exact: this value orders
+    /// after every negative signed value.
+    #[must_use]
+    pub fn cmp_signed(self, other: Px) -> Ordering {
+        i64::from(self.0).cmp(&i64::from(other.0))
+    }
+
+    /// Returns this value converted to signed pixels, saturating values
+    /// larger than [`Px::MAX`] to [`Px::MAX`].
+    #[must_use]
+    pub fn saturating_into_signed(self) -> Px {
+        self.into_signed()
+    }
+
+    /// Returns the positive difference between `self` and `other`.
+    ///
+    /// Unlike subtraction, this cannot overflow when `other` is larger than
+    /// `self`.
+    #[must_use]
+    pub const fn abs_diff(self, other: Self) -> Self {
+        Self(self.0.abs_diff(other.0))
+    }
+
+    /// Returns `self - other` as a signed [`Px`] value.
+    ///
+    /// Deltas too large for [`Px`] are saturated to its range.
+    #[must_use]
+    pub fn signed_delta(self, other: Self) -> Px {
+        let delta = i64::from(self.0) - i64::from(other.0);
+        Px(delta.clamp(i64::from(i32::MIN), i64::from(i32::MAX)).cast())
+    }
+}
+
 impl ScreenScale for UPx {
     type Lp = Lp;
     type Px = Px;
@@ -725,6 +1176,26 @@ impl ScreenScale for UPx {
     }
 }
 
+macro_rules! impl_convert_unit {
+    ($from:ty, $to:ty, $method:ident) => {
+        impl crate::traits::ConvertUnit<$to> for $from {
+            fn convert(self, scale: Fraction) -> $to {
+                self.$method(scale)
+            }
+        }
+    };
+}
+
+impl_convert_unit!(Px, Px, into_px);
+impl_convert_unit!(Px, UPx, into_upx);
+impl_convert_unit!(Px, Lp, into_lp);
+impl_convert_unit!(UPx, Px, into_px);
+impl_convert_unit!(UPx, UPx, into_upx);
+impl_convert_unit!(UPx, Lp, into_lp);
+impl_convert_unit!(Lp, Px, into_px);
+impl_convert_unit!(Lp, UPx, into_upx);
+impl_convert_unit!(Lp, Lp, into_lp);
+
 impl IntoComponents<UPx> for u32 {
     fn into_components(self) -> (UPx, UPx) {
         (UPx::new(self), UPx::new(self))
@@ -743,10 +1214,10 @@ impl fmt::Debug for UPx {
         let whole = self.0 / 4;
         let remainder = self.0 % 4;
         match remainder {
-            1 => write!(f, "{whole}.25px",),
-            2 => write!(f, "{whole}.5px",),
-            3 => write!(f, "{whole}.75px",),
-            _ => write!(f, "{whole}px",),
+            1 => write!(f, "{whole}.25px"),
+            2 => write!(f, "{whole}.5px"),
+            3 => write!(f, "{whole}.75px"),
+            _ => write!(f, "{whole}px"),
         }
     }
 }
@@ -801,10 +1272,781 @@ impl PartialEq<Px> for UPx {
 
 impl PartialOrd<Px> for UPx {
     fn partial_cmp(&self, other: &Px) -> Option<Ordering> {
-        if let Ok(unsigned) = UPx::try_from(*other) {
-            Some(unsigned.cmp(self))
-        } else {
-            Some(Ordering::Less)
+        Some(self.cmp_signed(*other))
+    }
+}
+
+define_integer_type!(Px64, i64, "docs/px64.md", 4);
+
+impl Pow for Px64 {
+    fn pow(&self, exp: u32) -> Self {
+        Self(self.0.saturating_pow(exp) / 4_i64.pow(exp.saturating_sub(1)))
+    }
+}
+
+impl Abs for Px64 {
+    fn abs(&self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+}
+
+impl core::ops::Neg for Px64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl IntoUnsigned for Px64 {
+    type Unsigned = UPx64;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        UPx64(self.0.try_into().unwrap_or(0))
+    }
+}
+
+impl IntoSigned for Px64 {
+    type Signed = Self;
+
+    fn into_signed(self) -> Self::Signed {
+        self
+    }
+}
+
+impl From<Px> for Px64 {
+    fn from(value: Px) -> Self {
+        Self(i64::from(value.0))
+    }
+}
+
+impl TryFrom<Px64> for Px {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Px64) -> Result<Self, Self::Error> {
+        value.0.try_into().map(Self)
+    }
+}
+
+impl fmt::Debug for Px64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let whole = self.0 >> 2;
+        let remainder = self.0 & 0b11;
+        match remainder {
+            1 => write!(f, "{whole}.25px"),
+            2 => write!(f, "{whole}.5px"),
+            3 => write!(f, "{whole}.75px"),
+            _ => write!(f, "{whole}px"),
         }
     }
 }
+
+impl fmt::Display for Px64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+define_integer_type!(UPx64, u64, "docs/upx64.md", 4);
+
+impl Pow for UPx64 {
+    fn pow(&self, exp: u32) -> Self {
+        Self(self.0.saturating_pow(exp) / 4_u64.pow(exp.saturating_sub(1)))
+    }
+}
+
+impl IntoSigned for UPx64 {
+    type Signed = Px64;
+
+    fn into_signed(self) -> Self::Signed {
+        Px64(self.0.try_into().unwrap_or(i64::MAX))
+    }
+}
+
+impl IntoUnsigned for UPx64 {
+    type Unsigned = Self;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        self
+    }
+}
+
+impl From<UPx> for UPx64 {
+    fn from(value: UPx) -> Self {
+        Self(u64::from(value.0))
+    }
+}
+
+impl TryFrom<UPx64> for UPx {
+    type Error = TryFromIntError;
+
+    fn try_from(value: UPx64) -> Result<Self, Self::Error> {
+        value.0.try_into().map(Self)
+    }
+}
+
+impl fmt::Debug for UPx64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let whole = self.0 >> 2;
+        let remainder = self.0 & 0b11;
+        match remainder {
+            1 => write!(f, "{whole}.25px"),
+            2 => write!(f, "{whole}.5px"),
+            3 => write!(f, "{whole}.75px"),
+            _ => write!(f, "{whole}px"),
+        }
+    }
+}
+
+impl fmt::Display for UPx64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+define_integer_type!(Em, i32, "docs/em.md", 3810);
+
+const EM_SCALE_I32: i32 = 3810;
+
+impl Em {
+    /// Resolves this font-relative measurement against `font_size`.
+    ///
+    /// One em is equal to `font_size`, so `Em::new(2)` resolves to twice the
+    /// font size. The math is performed with 64-bit integers, making the
+    /// result exact whenever the product is representable.
+    #[must_use]
+    pub fn resolve(self, font_size: Lp) -> Lp {
+        Lp((i64::from(self.0) * i64::from(font_size.0) / i64::from(EM_SCALE_I32)).cast())
+    }
+}
+
+impl IntoComponents<Em> for i32 {
+    fn into_components(self) -> (Em, Em) {
+        (Em(self), Em(self))
+    }
+}
+
+impl IntoComponents<Em> for f32 {
+    fn into_components(self) -> (Em, Em) {
+        let value = Em::from_float(self);
+        (value, value)
+    }
+}
+
+impl core::ops::Neg for Em {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl TryFrom<u32> for Em {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        value.try_into().map(Self)
+    }
+}
+
+impl Pow for Em {
+    fn pow(&self, exp: u32) -> Self {
+        Self(self.0.saturating_pow(exp))
+    }
+}
+
+impl Abs for Em {
+    fn abs(&self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+}
+
+impl IntoSigned for Em {
+    type Signed = Self;
+
+    fn into_signed(self) -> Self::Signed {
+        self
+    }
+}
+
+impl fmt::Debug for Em {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fractional = self.0 % EM_SCALE_I32;
+        let whole = self.0 / EM_SCALE_I32;
+        if fractional == 0 {
+            write!(f, "{whole}em")
+        } else {
+            let as_float = f64::from(whole) + f64::from(fractional) / f64::from(EM_SCALE_I32);
+            write!(f, "{as_float}em")
+        }
+    }
+}
+
+impl fmt::Display for Em {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+define_integer_type!(Texel, u32, "docs/texel.md", 1);
+
+impl Pow for Texel {
+    fn pow(&self, exp: u32) -> Self {
+        Self(self.0.saturating_pow(exp))
+    }
+}
+
+impl IntoUnsigned for Texel {
+    type Unsigned = Self;
+
+    fn into_unsigned(self) -> Self::Unsigned {
+        self
+    }
+}
+
+impl IntoComponents<Texel> for u32 {
+    fn into_components(self) -> (Texel, Texel) {
+        (Texel(self), Texel(self))
+    }
+}
+
+impl IntoComponents<Texel> for f32 {
+    fn into_components(self) -> (Texel, Texel) {
+        let value = Texel::from_float(self);
+        (value, value)
+    }
+}
+
+impl fmt::Debug for Texel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}tx", self.0)
+    }
+}
+
+impl fmt::Display for Texel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A fixed-point pixel measurement with a configurable number of subpixel
+/// steps.
+///
+/// [`Px`] always uses 4 subpixel steps. `FixedPx` exposes the same
+/// fixed-point representation with a precision chosen through the `SCALE`
+/// parameter, allowing workloads like font rasterization to measure with
+/// 1/64 pixel precision without losing the predictability of integer math:
+///
+/// ```rust
+/// use figures::units::{FixedPx, Px};
+///
+/// let advance = FixedPx::<64>::from(10.25);
+/// assert_eq!(advance.get(), 10);
+///
+/// // Conversions to and from `Px` rescale the stored value, and are
+/// // lossless when `SCALE` is a multiple of `Px`'s 4 subpixel steps.
+/// assert_eq!(Px::from(advance), Px::from(10.25));
+/// assert_eq!(FixedPx::<64>::from(Px::from(10.25)), advance);
+/// ```
+#[derive(Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[repr(C)]
+pub struct FixedPx<const SCALE: u32>(i32);
+
+impl<const SCALE: u32> FixedPx<SCALE> {
+    /// The maximum value for this type.
+    pub const MAX: Self = Self(i32::MAX);
+    /// The minimum value for this type.
+    pub const MIN: Self = Self(i32::MIN);
+    #[allow(clippy::cast_possible_wrap)]
+    const SCALE_I32: i32 = SCALE as i32;
+
+    /// Returns a new wrapped value for this unit.
+    #[must_use]
+    pub const fn new(value: i32) -> Self {
+        Self(value * Self::SCALE_I32)
+    }
+
+    /// Returns a new wrapped value for this unit from a floating point
+    /// number, rounded to the nearest representable value.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // the cast saturates at the representable range
+    pub const fn from_float_const(value: f64) -> Self {
+        let scaled = value * SCALE as f64;
+        let rounded = if scaled >= 0.0 {
+            scaled + 0.5
+        } else {
+            scaled - 0.5
+        };
+        Self(rounded as i32)
+    }
+
+    /// Returns the contained value, rounded to the nearest whole pixel.
+    #[must_use]
+    pub const fn get(self) -> i32 {
+        (self.0 + Self::SCALE_I32 / 2) / Self::SCALE_I32
+    }
+
+    /// Returns the result of adding `self` and `other`. If the calculation
+    /// overflows, the value will be limited to [`Self::MIN`]/[`Self::MAX`].
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Returns the result of subtracting `other` from `self`. If the
+    /// calculation overflows, the value will be limited to
+    /// [`Self::MIN`]/[`Self::MAX`].
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Returns the result of adding `self` and `other`, or `None` if the
+    /// calculation overflows.
+    #[must_use]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Returns the result of subtracting `other` from `self`, or `None` if
+    /// the calculation overflows.
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+}
+
+impl<const SCALE: u32> FloatConversion for FixedPx<SCALE> {
+    type Float = f32;
+    type Float64 = f64;
+
+    #[allow(clippy::cast_precision_loss)] // precision loss desired to best approximate the value
+    fn into_float(self) -> Self::Float {
+        self.0.cast::<f32>() / Self::SCALE_I32.cast::<f32>()
+    }
+
+    fn from_float(float: Self::Float) -> Self {
+        Self(FloatExt::round(float * Self::SCALE_I32.cast::<f32>()).cast())
+    }
+
+    fn into_float64(self) -> Self::Float64 {
+        f64::from(self.0) / f64::from(Self::SCALE_I32)
+    }
+
+    fn from_float64(float: Self::Float64) -> Self {
+        Self((float * f64::from(Self::SCALE_I32)).round().cast())
+    }
+}
+
+impl<const SCALE: u32> From<f32> for FixedPx<SCALE> {
+    fn from(value: f32) -> Self {
+        Self::from_float(value)
+    }
+}
+
+impl<const SCALE: u32> From<FixedPx<SCALE>> for f32 {
+    fn from(value: FixedPx<SCALE>) -> Self {
+        value.into_float()
+    }
+}
+
+impl<const SCALE: u32> From<i32> for FixedPx<SCALE> {
+    fn from(value: i32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const SCALE: u32> From<FixedPx<SCALE>> for i32 {
+    fn from(value: FixedPx<SCALE>) -> Self {
+        value.get()
+    }
+}
+
+impl<const SCALE: u32> From<Px> for FixedPx<SCALE> {
+    fn from(value: Px) -> Self {
+        Self((i64::from(value.0) * i64::from(Self::SCALE_I32) / 4).cast())
+    }
+}
+
+impl<const SCALE: u32> From<FixedPx<SCALE>> for Px {
+    fn from(value: FixedPx<SCALE>) -> Self {
+        Self((i64::from(value.0) * 4 / i64::from(FixedPx::<SCALE>::SCALE_I32)).cast())
+    }
+}
+
+impl<const SCALE: u32> Zero for FixedPx<SCALE> {
+    const ZERO: Self = Self(0);
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const SCALE: u32> UnscaledUnit for FixedPx<SCALE> {
+    type Representation = i32;
+
+    fn from_unscaled(unscaled: Self::Representation) -> Self {
+        Self(unscaled)
+    }
+
+    fn into_unscaled(self) -> Self::Representation {
+        self.0
+    }
+}
+
+impl<const SCALE: u32> Round for FixedPx<SCALE> {
+    fn round(self) -> Self {
+        Self((self.0 + Self::SCALE_I32 / 2) / Self::SCALE_I32 * Self::SCALE_I32)
+    }
+
+    fn ceil(self) -> Self {
+        Self((self.0 + Self::SCALE_I32 - 1) / Self::SCALE_I32 * Self::SCALE_I32)
+    }
+
+    fn floor(self) -> Self {
+        Self(self.0 / Self::SCALE_I32 * Self::SCALE_I32)
+    }
+
+    fn round_to_nearest(self, step: Self) -> Self {
+        Self((self.0 + step.0 / 2) / step.0 * step.0)
+    }
+
+    fn ceil_to(self, step: Self) -> Self {
+        Self((self.0 + step.0 - 1) / step.0 * step.0)
+    }
+
+    fn floor_to(self, step: Self) -> Self {
+        Self(self.0 / step.0 * step.0)
+    }
+}
+
+impl<const SCALE: u32> Add for FixedPx<SCALE> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<const SCALE: u32> AddAssign for FixedPx<SCALE> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<const SCALE: u32> Sub for FixedPx<SCALE> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<const SCALE: u32> SubAssign for FixedPx<SCALE> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<const SCALE: u32> core::ops::Neg for FixedPx<SCALE> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<const SCALE: u32> Mul<i32> for FixedPx<SCALE> {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<const SCALE: u32> Div<i32> for FixedPx<SCALE> {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl<const SCALE: u32> Mul<Fraction> for FixedPx<SCALE> {
+    type Output = Self;
+
+    fn mul(self, rhs: Fraction) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<const SCALE: u32> crate::traits::One for FixedPx<SCALE> {
+    const ONE: Self = Self(Self::SCALE_I32);
+
+    fn is_one(&self) -> bool {
+        self.0 == Self::SCALE_I32
+    }
+}
+
+impl<const SCALE: u32> crate::traits::WideMul for FixedPx<SCALE> {
+    type Wide = i64;
+
+    fn wide_mul(self, other: Self) -> Self::Wide {
+        i64::from(self.0) * i64::from(other.0) / i64::from(SCALE)
+    }
+}
+
+impl<const SCALE: u32> Div<Fraction> for FixedPx<SCALE> {
+    type Output = Self;
+
+    fn div(self, rhs: Fraction) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl<const SCALE: u32> fmt::Debug for FixedPx<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fractional = self.0 % Self::SCALE_I32;
+        let whole = self.0 / Self::SCALE_I32;
+        if fractional == 0 {
+            write!(f, "{whole}px")
+        } else {
+            let as_float = f64::from(whole) + f64::from(fractional) / f64::from(Self::SCALE_I32);
+            write!(f, "{as_float}px")
+        }
+    }
+}
+
+impl<const SCALE: u32> fmt::Display for FixedPx<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A percentage of some other measurement.
+///
+/// Percentages are stored as a [`Fraction`], so `Percent::new(50)` is exactly
+/// one half rather than a floating point approximation.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Percent(Fraction);
+
+impl Default for Percent {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Percent {
+    /// 100%.
+    pub const ONE_HUNDRED: Self = Self(Fraction::ONE);
+    /// 0%.
+    pub const ZERO: Self = Self(Fraction::ZERO);
+
+    /// Returns a new percentage. `Percent::new(50)` is 50%.
+    #[must_use]
+    pub fn new(percent: i16) -> Self {
+        Self(Fraction::new(percent, 100))
+    }
+
+    /// Returns a new percentage from a fraction of the whole:
+    /// `Fraction::new(1, 2)` is 50%.
+    #[must_use]
+    pub const fn from_fraction(fraction: Fraction) -> Self {
+        Self(fraction)
+    }
+
+    /// Returns this percentage as a fraction of the whole.
+    #[must_use]
+    pub const fn fraction(self) -> Fraction {
+        self.0
+    }
+
+    /// Returns this percentage of `value`.
+    ///
+    /// ```rust
+    /// use figures::units::{Percent, Px};
+    /// use figures::Size;
+    ///
+    /// assert_eq!(Percent::new(50).of(Px::new(100)), Px::new(50));
+    /// assert_eq!(
+    ///     Percent::new(25).of(Size::new(Px::new(100), Px::new(200))),
+    ///     Size::new(Px::new(25), Px::new(50))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn of<T>(self, value: T) -> T
+    where
+        T: Mul<Fraction, Output = T>,
+    {
+        value * self.0
+    }
+}
+
+impl From<Fraction> for Percent {
+    fn from(fraction: Fraction) -> Self {
+        Self::from_fraction(fraction)
+    }
+}
+
+impl From<Percent> for Fraction {
+    fn from(percent: Percent) -> Self {
+        percent.fraction()
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hundredths = self.0 * Fraction::new_whole(100);
+        if hundredths.denominator() == 1 {
+            write!(f, "{}%", hundredths.numerator())
+        } else {
+            write!(f, "{}%", hundredths.into_f32())
+        }
+    }
+}
+
+/// A measurement that is either absolute or relative to the available space.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Dimension<Unit> {
+    /// An absolute measurement.
+    Absolute(Unit),
+    /// A percentage of the available space.
+    Percent(Percent),
+    /// No explicit measurement: the layout decides.
+    Auto,
+}
+
+impl<Unit> Dimension<Unit> {
+    /// Resolves this dimension against the space `available` to it.
+    ///
+    /// [`Dimension::Auto`] resolves to the full available space; use
+    /// [`Dimension::resolve_or`] to substitute a computed measurement
+    /// instead.
+    pub fn resolve(self, available: Unit) -> Unit
+    where
+        Unit: Mul<Fraction, Output = Unit> + Copy,
+    {
+        self.resolve_or(available, available)
+    }
+
+    /// Resolves this dimension against the space `available` to it, using
+    /// `auto` for [`Dimension::Auto`].
+    pub fn resolve_or(self, available: Unit, auto: Unit) -> Unit
+    where
+        Unit: Mul<Fraction, Output = Unit>,
+    {
+        match self {
+            Self::Absolute(value) => value,
+            Self::Percent(percent) => percent.of(available),
+            Self::Auto => auto,
+        }
+    }
+
+    /// Returns true if this dimension is [`Dimension::Auto`].
+    #[must_use]
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+}
+
+impl<Unit> Default for Dimension<Unit>
+where
+    Unit: Zero,
+{
+    fn default() -> Self {
+        Self::Absolute(Unit::ZERO)
+    }
+}
+
+impl<Unit> From<Percent> for Dimension<Unit> {
+    fn from(percent: Percent) -> Self {
+        Self::Percent(percent)
+    }
+}
+
+/// Constructs a [`Px`] value, usable in `const` contexts.
+///
+/// Fractional values are rounded to the nearest quarter pixel, the precision
+/// of the [`Px`] type.
+///
+/// ```rust
+/// use figures::px;
+/// use figures::units::Px;
+///
+/// const LINE_WIDTH: Px = px!(2.5);
+/// assert_eq!(LINE_WIDTH, Px::from(2.5));
+/// assert_eq!(px!(3), Px::new(3));
+/// ```
+#[macro_export]
+macro_rules! px {
+    ($value:literal) => {
+        $crate::units::Px::from_float_const($value as f64)
+    };
+}
+
+/// Constructs a [`UPx`] value, usable in `const` contexts.
+///
+/// Fractional values are rounded to the nearest quarter pixel, the precision
+/// of the [`UPx`] type.
+///
+/// ```rust
+/// use figures::units::UPx;
+/// use figures::upx;
+///
+/// const TEXTURE_SIZE: UPx = upx!(1024);
+/// assert_eq!(TEXTURE_SIZE, UPx::new(1024));
+/// assert_eq!(upx!(0.25), UPx::from(0.25));
+/// ```
+#[macro_export]
+macro_rules! upx {
+    ($value:literal) => {
+        $crate::units::UPx::from_float_const($value as f64)
+    };
+}
+
+/// Constructs an [`Lp`] value, usable in `const` contexts.
+///
+/// A bare number is interpreted as logical pixels. A number followed by
+/// `in`, `cm`, `mm`, or `pt` is interpreted in the corresponding physical
+/// unit.
+///
+/// ```rust
+/// use figures::lp;
+/// use figures::units::Lp;
+///
+/// const MARGIN: Lp = lp!(1 in);
+/// assert_eq!(MARGIN, Lp::inches(1));
+/// assert_eq!(lp!(10 mm), Lp::mm(10));
+/// assert_eq!(lp!(12 pt), Lp::points(12));
+/// assert_eq!(lp!(2.5), Lp::from(2.5));
+/// ```
+#[macro_export]
+macro_rules! lp {
+    ($value:literal in) => {
+        $crate::units::Lp::inches($value)
+    };
+    ($value:literal cm) => {
+        $crate::units::Lp::cm($value)
+    };
+    ($value:literal mm) => {
+        $crate::units::Lp::mm($value)
+    };
+    ($value:literal pt) => {
+        $crate::units::Lp::points($value)
+    };
+    ($value:literal) => {
+        $crate::units::Lp::from_float_const($value as f64)
+    };
+}
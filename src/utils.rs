@@ -1,5 +1,6 @@
-use std::cmp::Ordering;
-use std::ops::Mul;
+use core::cmp::Ordering;
+
+use crate::traits::WideMul;
 
 /// Orders two vectors by their magnitude, then by their individual component
 /// values. E.g., this list is ordered consistently with the results of this
@@ -19,15 +20,16 @@ use std::ops::Mul;
 /// either `Ordering::Less` or `Ordering::Greater` are returned.
 pub(crate) fn vec_ord<Unit>(this: (Unit, Unit), other: (Unit, Unit)) -> Ordering
 where
-    Unit: Ord + Copy + Mul<Output = Unit>,
+    Unit: Ord + Copy + WideMul,
 {
     // Goal: Sort so that vectors are ordered by their magnitude. This isn't
     // good enough, however, as Ordering::Equal will be returned for items that
     // Eq does not return true for. To ensure that Ordering::Equal is only
     // returned for vecs that Eq returns true for, we further sort by the
-    // smallest component.
-    let this_magnitude = this.0 * this.1;
-    let other_magnitude = other.0 * other.1;
+    // smallest component. The magnitudes are compared widened so that large
+    // vectors do not overflow into an incorrect ordering.
+    let this_magnitude = this.0.wide_mul(this.1);
+    let other_magnitude = other.0.wide_mul(other.1);
     match this_magnitude.cmp(&other_magnitude) {
         Ordering::Equal => {
             match (this.0.cmp(&other.0), this.1.cmp(&other.1)) {
@@ -69,3 +71,191 @@ where
         Ordering::Greater => Ordering::Less,
     }
 }
+
+/// The bit pattern every NaN is normalized to by [`canonical_f32_bits`].
+const CANONICAL_NAN: u32 = 0x7fc0_0000;
+
+/// Returns a canonical bit pattern for `value`, suitable for hashing.
+///
+/// Negative zero is normalized to positive zero and every NaN is normalized
+/// to a single bit pattern, so values that compare equal produce equal bits.
+pub(crate) fn canonical_f32_bits(value: f32) -> u32 {
+    if value.is_nan() {
+        CANONICAL_NAN
+    } else if value == 0. {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Float methods that `core` lacks.
+///
+/// When `std` is enabled, these delegate to the standard library's inherent
+/// float methods. In `no_std` builds they are backed by `libm` instead. Call
+/// sites where another trait (such as [`Round`](crate::traits::Round)) also
+/// supplies the method must use fully-qualified syntax to disambiguate.
+pub(crate) trait FloatExt: Sized {
+    fn round(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    #[cfg(all(not(feature = "std"), not(test)))]
+    fn hypot(self, other: Self) -> Self;
+    #[cfg(all(not(feature = "std"), not(test)))]
+    fn atan2(self, other: Self) -> Self;
+    #[cfg(all(not(feature = "std"), not(test)))]
+    fn sin(self) -> Self;
+    #[cfg(all(not(feature = "std"), not(test)))]
+    fn cos(self) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+impl FloatExt for f32 {
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
+
+    fn ceil(self) -> Self {
+        libm::ceilf(self)
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    fn cbrt(self) -> Self {
+        libm::cbrtf(self)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        libm::powf(self, n)
+    }
+
+    #[cfg(not(test))]
+    fn hypot(self, other: Self) -> Self {
+        libm::hypotf(self, other)
+    }
+
+    #[cfg(not(test))]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2f(self, other)
+    }
+
+    #[cfg(not(test))]
+    fn sin(self) -> Self {
+        libm::sinf(self)
+    }
+
+    #[cfg(not(test))]
+    fn cos(self) -> Self {
+        libm::cosf(self)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl FloatExt for f64 {
+    fn round(self) -> Self {
+        libm::round(self)
+    }
+
+    fn floor(self) -> Self {
+        libm::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        libm::ceil(self)
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    fn cbrt(self) -> Self {
+        libm::cbrt(self)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        libm::pow(self, n)
+    }
+
+    #[cfg(not(test))]
+    fn hypot(self, other: Self) -> Self {
+        libm::hypot(self, other)
+    }
+
+    #[cfg(not(test))]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2(self, other)
+    }
+
+    #[cfg(not(test))]
+    fn sin(self) -> Self {
+        libm::sin(self)
+    }
+
+    #[cfg(not(test))]
+    fn cos(self) -> Self {
+        libm::cos(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FloatExt for f32 {
+    fn round(self) -> Self {
+        self.round()
+    }
+
+    fn floor(self) -> Self {
+        self.floor()
+    }
+
+    fn ceil(self) -> Self {
+        self.ceil()
+    }
+
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    fn cbrt(self) -> Self {
+        self.cbrt()
+    }
+
+    fn powf(self, n: Self) -> Self {
+        self.powf(n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FloatExt for f64 {
+    fn round(self) -> Self {
+        self.round()
+    }
+
+    fn floor(self) -> Self {
+        self.floor()
+    }
+
+    fn ceil(self) -> Self {
+        self.ceil()
+    }
+
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    fn cbrt(self) -> Self {
+        self.cbrt()
+    }
+
+    fn powf(self, n: Self) -> Self {
+        self.powf(n)
+    }
+}
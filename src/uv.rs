@@ -0,0 +1,122 @@
+use core::ops::Add;
+
+use crate::traits::FloatConversion;
+use crate::{Point, Rect, Size};
+
+/// A normalized texture coordinate.
+///
+/// Graphics APIs sample textures with coordinates in the range `0.0..=1.0`
+/// regardless of the texture's dimensions. `UvCoord` stores one of these
+/// normalized coordinates, and [`Point::to_uv`] converts a pixel location
+/// within a texture into the coordinate a sampler expects:
+///
+/// ```rust
+/// use figures::units::UPx;
+/// use figures::{Point, Size, UvCoord};
+///
+/// let texture_size = Size::new(UPx::new(256), UPx::new(128));
+/// let uv = Point::new(UPx::new(64), UPx::new(32)).to_uv(texture_size);
+/// assert_eq!(uv, UvCoord::new(0.25, 0.25));
+/// assert_eq!(
+///     uv.to_point(texture_size),
+///     Point::new(UPx::new(64), UPx::new(32))
+/// );
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UvCoord {
+    /// The horizontal component, where 0.0 is the left edge of the texture
+    /// and 1.0 is the right edge.
+    pub u: f32,
+    /// The vertical component, where 0.0 is the top edge of the texture and
+    /// 1.0 is the bottom edge.
+    pub v: f32,
+}
+
+impl UvCoord {
+    /// The bottom-right corner of a texture.
+    pub const ONE: Self = Self::new(1., 1.);
+    /// The top-left corner of a texture.
+    pub const ZERO: Self = Self::new(0., 0.);
+
+    /// Returns a new coordinate with the given components.
+    #[must_use]
+    pub const fn new(u: f32, v: f32) -> Self {
+        Self { u, v }
+    }
+
+    /// Returns this coordinate with both components clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn clamped(self) -> Self {
+        Self::new(self.u.clamp(0., 1.), self.v.clamp(0., 1.))
+    }
+
+    /// Returns the location this coordinate samples within a texture of
+    /// `texture_size`.
+    #[must_use]
+    pub fn to_point<Unit>(self, texture_size: Size<Unit>) -> Point<Unit>
+    where
+        Unit: FloatConversion<Float = f32>,
+    {
+        Point::new(
+            Unit::from_float(self.u * texture_size.width.into_float()),
+            Unit::from_float(self.v * texture_size.height.into_float()),
+        )
+    }
+}
+
+impl<Unit> Point<Unit>
+where
+    Unit: FloatConversion<Float = f32>,
+{
+    /// Returns this point's normalized texture coordinate within a texture of
+    /// `texture_size`.
+    ///
+    /// The result is not clamped: points outside of the texture produce
+    /// coordinates outside of `0.0..=1.0`.
+    #[must_use]
+    pub fn to_uv(self, texture_size: Size<Unit>) -> UvCoord {
+        UvCoord::new(
+            self.x.into_float() / texture_size.width.into_float(),
+            self.y.into_float() / texture_size.height.into_float(),
+        )
+    }
+}
+
+impl<Unit> Rect<Unit>
+where
+    Unit: FloatConversion<Float = f32> + Add<Output = Unit> + Ord + Copy,
+{
+    /// Returns this rectangle's normalized texture coordinates within a
+    /// texture of `texture_size`.
+    #[must_use]
+    pub fn to_uv(self, texture_size: Size<Unit>) -> Rect<f32> {
+        let (top_left, bottom_right) = self.extents();
+        let top_left = top_left.to_uv(texture_size);
+        let bottom_right = bottom_right.to_uv(texture_size);
+        Rect::new(
+            Point::new(top_left.u, top_left.v),
+            Size::new(bottom_right.u - top_left.u, bottom_right.v - top_left.v),
+        )
+    }
+
+    /// Returns this rectangle's normalized texture coordinates within a
+    /// texture of `texture_size`, inset by half a texel on each edge.
+    ///
+    /// When sampling an entry from a texture atlas with linear filtering,
+    /// sampling all the way to the entry's edges blends in texels from the
+    /// neighboring entries. Insetting the coordinates by half a texel keeps
+    /// the samples inside the entry.
+    #[must_use]
+    pub fn to_uv_inset(self, texture_size: Size<Unit>) -> Rect<f32> {
+        let uv = self.to_uv(texture_size);
+        let half_texel = Point::new(
+            0.5 / texture_size.width.into_float(),
+            0.5 / texture_size.height.into_float(),
+        );
+        Rect::new(
+            uv.origin + half_texel,
+            uv.size - Size::new(half_texel.x * 2., half_texel.y * 2.),
+        )
+    }
+}